@@ -6,7 +6,7 @@ use std::{
 
 use bitcoin::{util::{
     base58,
-    bip32::{ChainCode, ChildNumber, ExtendedPubKey, Fingerprint},
+    bip32::{ChainCode, ChildNumber, ExtendedPrivKey, ExtendedPubKey, Fingerprint},
 }, Network, OutPoint, PublicKey, TxOut, Address};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use hdpath::{StandardHDPath, Purpose, AccountHDPath};
@@ -80,6 +80,13 @@ pub struct XPub {
     pub address_type: AddressType,
 }
 
+/// Extended private key with the address type, to serialize with a SLIP-132 prefix (xprv, yprv, zprv, etc)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct XPrv {
+    pub value: ExtendedPrivKey,
+    pub address_type: AddressType,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Copy)]
 pub enum AddressType {
     P2PKH,
@@ -323,6 +330,31 @@ impl ToString for XPub {
     }
 }
 
+impl XPrv {
+    /// Extended public key for the same path
+    pub fn xpub(&self) -> XPub {
+        XPub {
+            value: ExtendedPubKey::from_private(&DEFAULT_SECP256K1, &self.value),
+            address_type: self.address_type,
+        }
+    }
+}
+
+impl ToString for XPrv {
+    fn to_string(&self) -> String {
+        let mut data: Vec<u8> = Vec::with_capacity(78);
+        let version = self.address_type.xprv_version(&self.value.network);
+        data.write_u32::<BigEndian>(version).expect("Failed to write version");
+        data.push(self.value.depth);
+        data.extend_from_slice(self.value.parent_fingerprint.as_bytes());
+        data.write_u32::<BigEndian>(self.value.child_number.into()).expect("Failed to write child_number");
+        data.extend_from_slice(self.value.chain_code.as_bytes());
+        data.push(0);
+        data.extend_from_slice(&self.value.private_key.key[..]);
+        base58::check_encode_slice(data.as_slice())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -387,6 +419,25 @@ mod tests {
         assert_eq!(act, "xpub6DfEZhR1ZBu33KzKqHPA1GCfKPpdB9HWFu5UsA54kB5VL3VN34JogQxYHWtSgrippZHp8s9hL9KrAfdYX1sU6cYRXMhGYuvwepFUooGAef5");
     }
 
+    #[test]
+    fn round_trip_slip132_prefixes() {
+        // same keys encoded with different SLIP-132 versions
+        let all = vec![
+            ("xpub6DfEZhR1ZBu33KzKqHPA1GCfKPpdB9HWFu5UsA54kB5VL3VN34JogQxYHWtSgrippZHp8s9hL9KrAfdYX1sU6cYRXMhGYuvwepFUooGAef5", Network::Bitcoin, AddressType::P2PKH),
+            ("ypub6YVVsN5vhsSWtdBSfeAnDMJAVMy57mH1B1bheYxx8BTNP9JbHiUNJUcgJir2gmNkECQctLkFnogQ3xF7EiHUtrE2PhPh8pkRvYK8CQ1oPv5", Network::Bitcoin, AddressType::P2WPKHinP2SH),
+            ("zpub6sKmB2kqrYyzjvNZVzxQRSPffL7X4PGW687vRwrqWBqFSF7pYNdvvYGpKvocgg2fdqXRdpLpFU2wwErfxQhVh5udG367ijZvCGNmb1jERwg", Network::Bitcoin, AddressType::P2WPKH),
+            ("tpubDFJnjeM57mHkG8LhyzfDwsWYJUWwta4Aq4nPo59hfVGhanWn7h98c2q6WoexVgkHx9Bg2vrAhCQi13tZozsZmrU8ca43c7em3RUvMXbSdHi", Network::Testnet, AddressType::P2PKH),
+            ("upub5GSNJQWdxShrSSaW2jKG7gtfTSyx5KacY6dS6LQuBZPAgCQPHVoMbQBrD9hQyTqHwKJMAvYcn7X1f1ZGG3nHVhy2wxDUArY4eHhaRpdA6GF", Network::Testnet, AddressType::P2WPKHinP2SH),
+            ("vpub5bGdc5BZ78FLHjmcs66tKmzAdR8Q1wa7TD9esjJnZZm3jJDcY9xvDTqzEMezyNVDLxR9vQ9BEmsZYJApykCJHwedpHutkmMYv1mDpLQDrNJ", Network::Testnet, AddressType::P2WPKH),
+        ];
+        for (value, network, address_type) in all {
+            let act = XPub::from_str(value).unwrap();
+            assert_eq!(act.value.network, network, "network for {}", value);
+            assert_eq!(act.address_type, address_type, "address type for {}", value);
+            assert_eq!(act.to_string(), value.to_string());
+        }
+    }
+
     #[test]
     fn find_path() {
         // seed: dream frog grape this park hungry quarter elbow fluid acid rack knee brown anxiety jewel
@@ -10,6 +10,10 @@ pub enum EthereumChainId {
     EthereumClassic,
     /// Kovan Testnet
     Kovan,
+    /// Mordor, Ethereum Classic PoW Testnet
+    Mordor,
+    /// Kotti, Ethereum Classic PoA Testnet
+    Kotti,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -25,15 +29,45 @@ pub enum Blockchain {
     Ethereum = 100,
     EthereumClassic = 101,
     KovanTestnet = 10002,
+    MordorTestnet = 10006,
+    KottiTestnet = 10007,
 }
 
 impl Blockchain {
+    /// All supported blockchains
+    pub fn all() -> &'static [Blockchain] {
+        &[
+            Blockchain::Bitcoin,
+            Blockchain::BitcoinTestnet,
+            Blockchain::Ethereum,
+            Blockchain::EthereumClassic,
+            Blockchain::KovanTestnet,
+            Blockchain::MordorTestnet,
+            Blockchain::KottiTestnet,
+        ]
+    }
+
+    /// Human readable name of the blockchain
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Blockchain::Bitcoin => "Bitcoin",
+            Blockchain::BitcoinTestnet => "Bitcoin Testnet",
+            Blockchain::Ethereum => "Ethereum",
+            Blockchain::EthereumClassic => "Ethereum Classic",
+            Blockchain::KovanTestnet => "Kovan Testnet",
+            Blockchain::MordorTestnet => "Mordor Testnet",
+            Blockchain::KottiTestnet => "Kotti Testnet",
+        }
+    }
+
     pub fn get_type(&self) -> BlockchainType {
         match self {
             Blockchain::BitcoinTestnet | Blockchain::Bitcoin => BlockchainType::Bitcoin,
-            Blockchain::Ethereum | Blockchain::EthereumClassic | Blockchain::KovanTestnet => {
-                BlockchainType::Ethereum
-            }
+            Blockchain::Ethereum
+            | Blockchain::EthereumClassic
+            | Blockchain::KovanTestnet
+            | Blockchain::MordorTestnet
+            | Blockchain::KottiTestnet => BlockchainType::Ethereum,
         }
     }
 
@@ -45,6 +79,27 @@ impl Blockchain {
         }
     }
 
+    /// SLIP-44 coin type used in the HD Path of the blockchain
+    pub fn coin_type(&self) -> u32 {
+        match self {
+            Blockchain::Bitcoin => 0,
+            Blockchain::BitcoinTestnet => 1,
+            Blockchain::Ethereum | Blockchain::KovanTestnet => 60,
+            Blockchain::EthereumClassic => 61,
+            // SLIP-44 coin type for all testnets
+            Blockchain::MordorTestnet | Blockchain::KottiTestnet => 1,
+        }
+    }
+
+    /// BIP-43 purposes (`m/purpose'/...`) of HD Paths allowed for the blockchain. Bitcoin entries
+    /// are limited to BIP-84, because only P2WPKH inputs can be signed
+    pub fn expected_purposes(&self) -> &'static [u32] {
+        match self.get_type() {
+            BlockchainType::Bitcoin => &[84],
+            BlockchainType::Ethereum => &[44],
+        }
+    }
+
     pub fn is_mainnet(&self) -> bool {
         match self {
             Blockchain::Bitcoin | Blockchain::Ethereum | Blockchain::EthereumClassic => true,
@@ -59,6 +114,8 @@ impl From<Blockchain> for EthereumChainId {
             Blockchain::Ethereum => EthereumChainId::Ethereum,
             Blockchain::EthereumClassic => EthereumChainId::EthereumClassic,
             Blockchain::KovanTestnet => EthereumChainId::Kovan,
+            Blockchain::MordorTestnet => EthereumChainId::Mordor,
+            Blockchain::KottiTestnet => EthereumChainId::Kotti,
             _ => panic!("not an ethereum blockchain"),
         }
     }
@@ -72,10 +129,24 @@ impl TryFrom<EthereumChainId> for Blockchain {
             EthereumChainId::Ethereum => Ok(Blockchain::Ethereum),
             EthereumChainId::EthereumClassic => Ok(Blockchain::EthereumClassic),
             EthereumChainId::Kovan => Ok(Blockchain::KovanTestnet),
+            EthereumChainId::Mordor => Ok(Blockchain::MordorTestnet),
+            EthereumChainId::Kotti => Ok(Blockchain::KottiTestnet),
         }
     }
 }
 
+impl TryFrom<u8> for EthereumChainId {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        EthereumChainId::all()
+            .iter()
+            .find(|c| c.as_chainid() == value)
+            .cloned()
+            .ok_or(())
+    }
+}
+
 impl TryFrom<u32> for Blockchain {
     type Error = ();
 
@@ -86,6 +157,8 @@ impl TryFrom<u32> for Blockchain {
             100 => Ok(Blockchain::Ethereum),
             101 => Ok(Blockchain::EthereumClassic),
             10002 => Ok(Blockchain::KovanTestnet),
+            10006 => Ok(Blockchain::MordorTestnet),
+            10007 => Ok(Blockchain::KottiTestnet),
             _ => Err(()),
         }
     }
@@ -99,6 +172,8 @@ impl FromStr for EthereumChainId {
         match clean.as_str() {
             "eth" | "ethereum" | "eth-mainnet" => Ok(EthereumChainId::Ethereum),
             "kovan" => Ok(EthereumChainId::Kovan),
+            "mordor" => Ok(EthereumChainId::Mordor),
+            "kotti" => Ok(EthereumChainId::Kotti),
             "etc-mainnet" | "etc" | "ethereum-classic" | "ethereum classic" => {
                 Ok(EthereumChainId::EthereumClassic)
             }
@@ -108,12 +183,81 @@ impl FromStr for EthereumChainId {
 }
 
 impl EthereumChainId {
+    /// All supported chains
+    pub fn all() -> &'static [EthereumChainId] {
+        &[
+            EthereumChainId::Ethereum,
+            EthereumChainId::EthereumClassic,
+            EthereumChainId::Kovan,
+            EthereumChainId::Mordor,
+            EthereumChainId::Kotti,
+        ]
+    }
+
     /// chain_id for current Chain
     pub fn as_chainid(&self) -> u8 {
         match self {
             EthereumChainId::Ethereum => 1,
             EthereumChainId::Kovan => 42,
             EthereumChainId::EthereumClassic => 61,
+            EthereumChainId::Mordor => 63,
+            EthereumChainId::Kotti => 6,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_all_chains() {
+        let all = Blockchain::all();
+        assert_eq!(7, all.len());
+        for b in all {
+            assert_eq!(Ok(*b), Blockchain::try_from(*b as u32));
+        }
+        let coins: Vec<(&str, u32)> = all.iter().map(|b| (b.get_name(), b.coin_type())).collect();
+        assert!(coins.contains(&("Bitcoin", 0)));
+        assert!(coins.contains(&("Bitcoin Testnet", 1)));
+        assert!(coins.contains(&("Ethereum", 60)));
+        assert!(coins.contains(&("Ethereum Classic", 61)));
+        assert!(coins.contains(&("Mordor Testnet", 1)));
+        assert!(coins.contains(&("Kotti Testnet", 1)));
+    }
+
+    #[test]
+    fn all_chains_defined_in_proto() {
+        use crate::proto::common::BlockchainId;
+        use protobuf::ProtobufEnum;
+
+        for b in Blockchain::all() {
+            assert!(BlockchainId::from_i32(*b as i32).is_some(), "{:?} is not in proto", b);
+        }
+        assert_eq!(Some(BlockchainId::CHAIN_MORDOR), BlockchainId::from_i32(Blockchain::MordorTestnet as i32));
+        assert_eq!(Some(BlockchainId::CHAIN_KOTTI), BlockchainId::from_i32(Blockchain::KottiTestnet as i32));
+    }
+
+    #[test]
+    fn etc_testnets() {
+        for b in &[Blockchain::MordorTestnet, Blockchain::KottiTestnet] {
+            assert_eq!(BlockchainType::Ethereum, b.get_type());
+            assert!(!b.is_mainnet());
+            assert_eq!(&[44], b.expected_purposes());
+            assert_eq!(Ok(*b), Blockchain::try_from(EthereumChainId::from(*b)));
+        }
+        assert_eq!(63, EthereumChainId::from(Blockchain::MordorTestnet).as_chainid());
+        assert_eq!(6, EthereumChainId::from(Blockchain::KottiTestnet).as_chainid());
+        assert_eq!(Ok(EthereumChainId::Mordor), EthereumChainId::from_str("Mordor"));
+        assert_eq!(Ok(EthereumChainId::Kotti), EthereumChainId::from_str("kotti"));
+    }
+
+    #[test]
+    fn chain_id_from_number() {
+        for c in EthereumChainId::all() {
+            assert_eq!(Ok(*c), EthereumChainId::try_from(c.as_chainid()));
         }
+        assert_eq!(Ok(EthereumChainId::EthereumClassic), EthereumChainId::try_from(61u8));
+        assert_eq!(Err(()), EthereumChainId::try_from(5u8));
     }
 }
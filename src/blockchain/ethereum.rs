@@ -13,7 +13,9 @@ WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 See the License for the specific language governing permissions and
 limitations under the License.
 */
+pub mod abi;
 pub mod address;
+pub mod airgap;
 pub mod signature;
 pub mod transaction;
 
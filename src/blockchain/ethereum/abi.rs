@@ -0,0 +1,323 @@
+/*
+Copyright 2020 EmeraldPay, Inc
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! # Contract ABI helpers to prepare data for a contract call and read its results
+
+use crate::util::keccak256;
+use ethabi::{spec::param_type::Reader, Decoder, Encoder};
+use num::bigint::BigUint;
+use std::fmt;
+pub use ethabi::{spec::ParamType, Token};
+
+/// Function selector size in bytes
+pub const SELECTOR_BYTES: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiError {
+    /// Function signature is not in the form of `name(type1,type2,...)`
+    InvalidSignature,
+    /// Arguments don't match the function signature
+    InvalidArguments,
+    /// Data cannot be decoded as the requested types
+    InvalidData,
+}
+
+impl fmt::Display for AbiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AbiError::InvalidSignature => write!(f, "Invalid function signature"),
+            AbiError::InvalidArguments => write!(f, "Arguments don't match the function signature"),
+            AbiError::InvalidData => write!(f, "Invalid ABI encoded data"),
+        }
+    }
+}
+
+/// Calculate a function selector, i.e. first 4 bytes of Keccak-256 of the canonical signature
+/// (ex. `transfer(address,uint256)`)
+pub fn function_selector(signature: &str) -> [u8; SELECTOR_BYTES] {
+    let hash = keccak256(signature.as_bytes());
+    let mut result = [0u8; SELECTOR_BYTES];
+    result.copy_from_slice(&hash[0..SELECTOR_BYTES]);
+    result
+}
+
+/// Check that the sizes of the type are allowed by the ABI spec, i.e. `uint<M>`/`int<M>` with
+/// `0 < M <= 256, M % 8 == 0` and `bytes<M>` with `0 < M <= 32`
+fn is_valid_size(param: &ParamType) -> bool {
+    match param {
+        ParamType::Int(m) | ParamType::Uint(m) => *m > 0 && *m <= 256 && *m % 8 == 0,
+        ParamType::FixedBytes(m) => *m > 0 && *m <= 32,
+        ParamType::Array(p) => is_valid_size(p),
+        ParamType::FixedArray(p, _) => is_valid_size(p),
+        _ => true,
+    }
+}
+
+/// Read a single parameter type. The type must be in the canonical form (ex. `uint256` instead
+/// of `uint`), because the signature is used as is to calculate the function selector
+fn read_param(name: &str) -> Result<ParamType, AbiError> {
+    let param = Reader::read(name).map_err(|_| AbiError::InvalidSignature)?;
+    if !is_valid_size(&param) || param.to_string() != name {
+        return Err(AbiError::InvalidSignature);
+    }
+    Ok(param)
+}
+
+/// Parameter types declared in the signature. Tuples are not supported
+fn parse_params(signature: &str) -> Result<Vec<ParamType>, AbiError> {
+    let start = signature.find('(').ok_or(AbiError::InvalidSignature)?;
+    if start == 0 || !signature.ends_with(')') || signature.contains(' ') {
+        return Err(AbiError::InvalidSignature);
+    }
+    let params = &signature[start + 1..signature.len() - 1];
+    if params.is_empty() {
+        return Ok(vec![]);
+    }
+    params
+        .split(',')
+        .map(read_param)
+        .collect()
+}
+
+/// Check that the value can be encoded as the specified type
+fn type_check(token: &Token, param: &ParamType) -> bool {
+    match (token, param) {
+        (Token::Address(_), ParamType::Address) => true,
+        (Token::Bytes(_), ParamType::Bytes) => true,
+        (Token::FixedBytes(v), ParamType::FixedBytes(len)) => v.len() == *len,
+        (Token::Int(_), ParamType::Int(_)) => true,
+        (Token::Uint(_), ParamType::Uint(_)) => true,
+        (Token::Bool(_), ParamType::Bool) => true,
+        (Token::String(_), ParamType::String) => true,
+        (Token::Array(items), ParamType::Array(p)) => items.iter().all(|t| type_check(t, p)),
+        (Token::FixedArray(items), ParamType::FixedArray(p, len)) => {
+            items.len() == *len && items.iter().all(|t| type_check(t, p))
+        }
+        _ => false,
+    }
+}
+
+/// Encode data for a contract call, as the function selector followed by ABI encoded arguments
+///
+/// # Arguments
+///
+/// * `signature` - canonical function signature, ex. `transfer(address,uint256)`
+/// * `args` - function arguments, in the same order and of the same types as in the signature
+pub fn encode_call(signature: &str, args: &[Token]) -> Result<Vec<u8>, AbiError> {
+    let params = parse_params(signature)?;
+    if params.len() != args.len() || !args.iter().zip(params.iter()).all(|(t, p)| type_check(t, p)) {
+        return Err(AbiError::InvalidArguments);
+    }
+    let mut result = function_selector(signature).to_vec();
+    result.extend(Encoder::encode(args.to_vec()));
+    Ok(result)
+}
+
+/// Decode data returned by a contract call (i.e. `eth_call` result)
+///
+/// # Arguments
+///
+/// * `types` - expected output types
+/// * `data` - raw ABI encoded data
+pub fn decode_output(types: &[ParamType], data: &[u8]) -> Result<Vec<Token>, AbiError> {
+    if types.is_empty() {
+        return if data.is_empty() { Ok(vec![]) } else { Err(AbiError::InvalidData) };
+    }
+    Decoder::decode(types, data.to_vec()).map_err(|_| AbiError::InvalidData)
+}
+
+/// Format a decoded value as a plain string suitable for JSON output, numbers are formatted as
+/// decimals and binary values as 0x-prefixed hex
+pub fn token_to_string(token: &Token) -> String {
+    match token {
+        Token::Address(v) => format!("0x{}", hex::encode(v)),
+        Token::FixedBytes(v) | Token::Bytes(v) => format!("0x{}", hex::encode(v)),
+        Token::Uint(v) => BigUint::from_bytes_be(v).to_string(),
+        Token::Int(v) => {
+            if v[0] & 0x80 == 0 {
+                BigUint::from_bytes_be(v).to_string()
+            } else {
+                // two's complement for a negative value
+                let inverted: Vec<u8> = v.iter().map(|b| !b).collect();
+                let abs = BigUint::from_bytes_be(inverted.as_slice()) + 1u32;
+                format!("-{}", abs)
+            }
+        }
+        Token::Bool(v) => v.to_string(),
+        Token::String(v) => v.clone(),
+        Token::FixedArray(v) | Token::Array(v) => {
+            let items: Vec<String> = v.iter().map(|t| token_to_string(t)).collect();
+            format!("[{}]", items.join(","))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{to_20bytes, to_32bytes};
+
+    #[test]
+    fn selector_for_transfer() {
+        assert_eq!(
+            hex::encode(function_selector("transfer(address,uint256)")),
+            "a9059cbb"
+        );
+        assert_eq!(
+            hex::encode(function_selector("balanceOf(address)")),
+            "70a08231"
+        );
+    }
+
+    #[test]
+    fn encode_transfer() {
+        let act = encode_call(
+            "transfer(address,uint256)",
+            &[
+                Token::Address(to_20bytes("3535353535353535353535353535353535353535")),
+                Token::Uint(to_32bytes("0de0b6b3a7640000")),
+            ],
+        ).unwrap();
+        assert_eq!(
+            hex::encode(act),
+            "a9059cbb\
+             0000000000000000000000003535353535353535353535353535353535353535\
+             0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+        );
+    }
+
+    #[test]
+    fn encode_dynamic_string() {
+        let act = encode_call(
+            "setName(string)",
+            &[Token::String("emerald".to_string())],
+        ).unwrap();
+        assert_eq!(
+            hex::encode(act),
+            "c47f0027\
+             0000000000000000000000000000000000000000000000000000000000000020\
+             0000000000000000000000000000000000000000000000000000000000000007\
+             656d6572616c6400000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn encode_without_args() {
+        let act = encode_call("totalSupply()", &[]).unwrap();
+        assert_eq!(hex::encode(act), "18160ddd");
+    }
+
+    #[test]
+    fn fail_on_wrong_args() {
+        assert_eq!(
+            encode_call("transfer(address,uint256)", &[Token::Bool(true)]),
+            Err(AbiError::InvalidArguments)
+        );
+    }
+
+    #[test]
+    fn fail_on_wrong_arg_types() {
+        assert_eq!(
+            encode_call(
+                "transfer(address,uint256)",
+                &[Token::Bool(true), Token::Uint(to_32bytes("0de0b6b3a7640000"))],
+            ),
+            Err(AbiError::InvalidArguments)
+        );
+        assert_eq!(
+            encode_call("setHash(bytes32)", &[Token::FixedBytes(vec![0u8; 20])]),
+            Err(AbiError::InvalidArguments)
+        );
+        assert_eq!(
+            encode_call("setOwners(address[])", &[Token::Array(vec![Token::Bool(true)])]),
+            Err(AbiError::InvalidArguments)
+        );
+    }
+
+    #[test]
+    fn fail_on_invalid_signature() {
+        assert_eq!(encode_call("transfer", &[]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("(address)", &[Token::Bool(true)]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("transfer(address, uint256)", &[]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("transfer(address,uint256", &[]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("transfer(address,unknown)", &[]), Err(AbiError::InvalidSignature));
+    }
+
+    #[test]
+    fn fail_on_non_canonical_types() {
+        let args = [
+            Token::Address(to_20bytes("3535353535353535353535353535353535353535")),
+            Token::Uint(to_32bytes("0de0b6b3a7640000")),
+        ];
+        // would give a selector different from transfer(address,uint256)
+        assert_eq!(encode_call("transfer(address,uint)", &args), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("transfer(address,uint0256)", &args), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("setValue(int)", &[Token::Int(to_32bytes("01"))]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("setValues(uint[])", &[Token::Array(vec![])]), Err(AbiError::InvalidSignature));
+    }
+
+    #[test]
+    fn fail_on_invalid_type_size() {
+        assert_eq!(encode_call("setValue(uint7)", &[Token::Uint(to_32bytes("01"))]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("setValue(int264)", &[Token::Int(to_32bytes("01"))]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("setValue(uint0)", &[Token::Uint(to_32bytes("01"))]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("setHash(bytes33)", &[Token::FixedBytes(vec![0u8; 33])]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("setHash(bytes0)", &[Token::FixedBytes(vec![])]), Err(AbiError::InvalidSignature));
+        assert_eq!(encode_call("setHashes(bytes33[2])", &[]), Err(AbiError::InvalidSignature));
+        assert!(encode_call("setValue(uint8)", &[Token::Uint(to_32bytes("01"))]).is_ok());
+    }
+
+    #[test]
+    fn decode_uint_and_bool() {
+        let data = hex::decode(
+            "0000000000000000000000000000000000000000000000000de0b6b3a7640000\
+             0000000000000000000000000000000000000000000000000000000000000001"
+        ).unwrap();
+        let act = decode_output(&[ParamType::Uint(256), ParamType::Bool], data.as_slice()).unwrap();
+        assert_eq!(act, vec![
+            Token::Uint(to_32bytes("0de0b6b3a7640000")),
+            Token::Bool(true),
+        ]);
+        assert_eq!(token_to_string(&act[0]), "1000000000000000000");
+        assert_eq!(token_to_string(&act[1]), "true");
+    }
+
+    #[test]
+    fn decode_dynamic_string() {
+        let data = hex::decode(
+            "0000000000000000000000000000000000000000000000000000000000000020\
+             0000000000000000000000000000000000000000000000000000000000000007\
+             656d6572616c6400000000000000000000000000000000000000000000000000"
+        ).unwrap();
+        let act = decode_output(&[ParamType::String], data.as_slice()).unwrap();
+        assert_eq!(act, vec![Token::String("emerald".to_string())]);
+        assert_eq!(token_to_string(&act[0]), "emerald");
+    }
+
+    #[test]
+    fn fail_to_decode_short_data() {
+        let data = hex::decode("0000000000000000000000000000000000000000000000000de0b6b3a7640000").unwrap();
+        assert_eq!(
+            decode_output(&[ParamType::Uint(256), ParamType::Bool], data.as_slice()),
+            Err(AbiError::InvalidData)
+        );
+    }
+
+    #[test]
+    fn format_negative_int() {
+        let value = to_32bytes("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff85");
+        assert_eq!(token_to_string(&Token::Int(value)), "-123");
+    }
+}
@@ -17,7 +17,7 @@ limitations under the License.
 //! # Account address (20 bytes)
 
 use super::super::Error;
-use crate::util::to_arr;
+use crate::util::{to_arr, trim_hex};
 use hex;
 use std::{fmt, ops, str::FromStr};
 use crate::{EthereumPrivateKey, PRIVATE_KEY_BYTES, keccak256};
@@ -53,6 +53,43 @@ impl EthereumAddress {
 
         Ok(EthereumAddress(to_arr(data)))
     }
+
+    /// Address of a secp256k1 public key, which can be either 65 bytes uncompressed or 33 bytes
+    /// compressed
+    ///
+    /// # Arguments
+    ///
+    /// * `pubkey` - A serialized public key, starting with `04` for uncompressed, or with `02`/`03`
+    ///   for compressed form
+    pub fn from_public_key(pubkey: &[u8]) -> Result<Self, Error> {
+        match pubkey.len() {
+            33 | 65 => Ok(EthereumAddress::from(PublicKey::from_slice(pubkey)?)),
+            _ => Err(Error::InvalidLength(pubkey.len())),
+        }
+    }
+
+    /// Address as a hex string with mixed-case checksum, as defined by EIP-55
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// let addr = emerald_vault::blockchain::EthereumAddress::from_str("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+    /// assert_eq!(addr.to_checksum(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    /// ```
+    pub fn to_checksum(&self) -> String {
+        let value = hex::encode(self.0);
+        let hash = keccak256(value.as_bytes());
+        let checksum: String = value
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0f };
+                if nibble >= 8 { c.to_ascii_uppercase() } else { c }
+            })
+            .collect();
+        format!("0x{}", checksum)
+    }
 }
 
 impl ops::Deref for EthereumAddress {
@@ -78,17 +115,13 @@ impl AsRef<[u8]> for EthereumAddress {
 impl FromStr for EthereumAddress {
     type Err = Error;
 
+    /// Parse address from a hex string, with or without `0x` prefix
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != ETHEREUM_ADDRESS_BYTES * 2 && !s.starts_with("0x") {
+        let value = trim_hex(s);
+        if value.len() != ETHEREUM_ADDRESS_BYTES * 2 {
             return Err(Error::InvalidHexLength(s.to_string()));
         }
 
-        let value = if s.starts_with("0x") {
-            s.split_at(2).1
-        } else {
-            s
-        };
-
         EthereumAddress::try_from(hex::decode(&value)?.as_slice())
     }
 }
@@ -124,6 +157,7 @@ impl From<PublicKey> for EthereumAddress {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::*;
 
     #[test]
     fn should_display_zero_address() {
@@ -133,6 +167,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_format_with_checksum() {
+        // test vectors from EIP-55
+        let addresses = vec![
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ];
+        for exp in addresses {
+            let addr = EthereumAddress::from_str(exp.to_lowercase().as_str()).unwrap();
+            assert_eq!(exp, addr.to_checksum());
+        }
+        assert_eq!(
+            "0x0000000000000000000000000000000000000000",
+            EthereumAddress::default().to_checksum()
+        );
+    }
+
     #[test]
     fn should_display_real_address() {
         let addr = EthereumAddress([
@@ -213,4 +266,76 @@ mod tests {
     fn should_catch_empty_address_string() {
         assert!("".parse::<EthereumAddress>().is_err());
     }
+
+    #[test]
+    fn should_report_wrong_length() {
+        match "0x0e7c045110b8dbf297650473808989".parse::<EthereumAddress>() {
+            Err(Error::InvalidHexLength(v)) => assert_eq!(v, "0x0e7c045110b8dbf297650473808989"),
+            other => panic!("unexpected result: {:?}", other.map(|a| a.to_string())),
+        }
+        match "0e7c045110b8dbf297650473808989".parse::<EthereumAddress>() {
+            Err(Error::InvalidHexLength(_)) => {}
+            other => panic!("unexpected result: {:?}", other.map(|a| a.to_string())),
+        }
+    }
+
+    #[test]
+    fn should_report_non_hex() {
+        match "0x___c045110b8dbf29765047380898919c5cb56f4".parse::<EthereumAddress>() {
+            Err(Error::UnexpectedHexEncoding(_)) => {}
+            other => panic!("unexpected result: {:?}", other.map(|a| a.to_string())),
+        }
+        match "zz7c045110b8dbf29765047380898919c5cb56f4".parse::<EthereumAddress>() {
+            Err(Error::UnexpectedHexEncoding(_)) => {}
+            other => panic!("unexpected result: {:?}", other.map(|a| a.to_string())),
+        }
+    }
+
+    #[test]
+    fn address_from_uncompressed_pubkey() {
+        let pubkey = hex::decode(
+            "0408d76a59b5d4b4b5f99f74679ec57382616b0c7aab0292ad47483f1ab56e61d4\
+             e46fb1a2567d61a682271e804d3390edbd7531363fe17d721d05e04cbd4a12c3",
+        )
+        .unwrap();
+        assert_eq!(
+            EthereumAddress::from_str("0x3f4E0668C20E100d7C2A27D4b177Ac65B2875D26").unwrap(),
+            EthereumAddress::from_public_key(&pubkey).unwrap()
+        );
+    }
+
+    #[test]
+    fn address_from_compressed_pubkey() {
+        let pubkey =
+            hex::decode("0308d76a59b5d4b4b5f99f74679ec57382616b0c7aab0292ad47483f1ab56e61d4")
+                .unwrap();
+        assert_eq!(
+            EthereumAddress::from_str("0x3f4E0668C20E100d7C2A27D4b177Ac65B2875D26").unwrap(),
+            EthereumAddress::from_public_key(&pubkey).unwrap()
+        );
+    }
+
+    #[test]
+    fn address_from_pubkey_of_private_key() {
+        let pk = EthereumPrivateKey(to_32bytes(
+            "00b413b37c71bfb92719d16e28d7329dea5befa0d0b8190742f89e55617991cf",
+        ));
+        let pubkey = PublicKey::from_secret_key(&secp256k1::Secp256k1::new(), &pk.into());
+        assert_eq!(
+            pk.to_address(),
+            EthereumAddress::from_public_key(&pubkey.serialize()).unwrap()
+        );
+        assert_eq!(
+            pk.to_address(),
+            EthereumAddress::from_public_key(&pubkey.serialize_uncompressed()).unwrap()
+        );
+    }
+
+    #[test]
+    fn deny_invalid_pubkey() {
+        assert!(EthereumAddress::from_public_key(&[]).is_err());
+        assert!(EthereumAddress::from_public_key(&[4u8; 64]).is_err());
+        assert!(EthereumAddress::from_public_key(&[4u8; 65]).is_err());
+        assert!(EthereumAddress::from_public_key(&[5u8; 33]).is_err());
+    }
 }
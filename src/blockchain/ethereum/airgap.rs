@@ -0,0 +1,246 @@
+/*
+Copyright 2020 EmeraldPay, Inc
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! # Compact format to pass transactions to and from an air-gapped signer (ex. as a QR code)
+//!
+//! A message is a version byte followed by RLP list `[type, chain_id, payload]`, where payload
+//! is an RLP list of the transaction fields for an unsigned transaction, or the raw bytes of a
+//! signed transaction.
+
+use super::{EthereumAddress, EthereumTransaction};
+use crate::{
+    blockchain::chains::EthereumChainId,
+    convert::error::ConversionError,
+    util::{align_bytes, to_arr},
+};
+use rlp::{Rlp, RlpStream};
+use std::convert::TryFrom;
+
+/// Current version of the format
+pub const AIRGAP_VERSION: u8 = 1;
+
+const TYPE_UNSIGNED: u8 = 1;
+const TYPE_SIGNED: u8 = 2;
+
+/// Message passed between a watch-only device and an offline signer
+#[derive(Clone, Debug)]
+pub enum AirGapMessage {
+    /// Transaction to sign
+    Unsigned(EthereumChainId, EthereumTransaction),
+    /// Raw signed transaction, ready to broadcast
+    Signed(EthereumChainId, Vec<u8>),
+}
+
+impl AirGapMessage {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut rlp = RlpStream::new_list(3);
+        match self {
+            AirGapMessage::Unsigned(chain, tx) => {
+                rlp.append(&TYPE_UNSIGNED);
+                rlp.append(&chain.as_chainid());
+                rlp.append_raw(&tx.to_rlp(None), 1);
+            }
+            AirGapMessage::Signed(chain, raw) => {
+                rlp.append(&TYPE_SIGNED);
+                rlp.append(&chain.as_chainid());
+                rlp.append(raw);
+            }
+        }
+        let mut result = vec![AIRGAP_VERSION];
+        result.extend(rlp.out());
+        result
+    }
+
+    pub fn decode(data: &[u8]) -> Result<AirGapMessage, ConversionError> {
+        if data.is_empty() {
+            return Err(ConversionError::InvalidLength);
+        }
+        if data[0] != AIRGAP_VERSION {
+            return Err(ConversionError::UnsupportedVersion);
+        }
+        let rlp = Rlp::new(&data[1..]);
+        if rlp.item_count().map_err(|_| ConversionError::InvalidLength)? != 3 {
+            return Err(ConversionError::InvalidLength);
+        }
+        let chain_id: u8 = rlp
+            .val_at(1)
+            .map_err(|_| ConversionError::InvalidFieldValue("chain_id".to_string()))?;
+        let chain = EthereumChainId::try_from(chain_id)
+            .map_err(|_| ConversionError::UnsupportedValue("chain_id".to_string()))?;
+        let payload = rlp
+            .at(2)
+            .map_err(|_| ConversionError::FieldIsEmpty("payload".to_string()))?;
+        match rlp.val_at::<u8>(0) {
+            Ok(TYPE_UNSIGNED) => Ok(AirGapMessage::Unsigned(chain, decode_tx(&payload)?)),
+            Ok(TYPE_SIGNED) => {
+                let raw = payload
+                    .data()
+                    .map_err(|_| ConversionError::InvalidFieldValue("payload".to_string()))?;
+                Ok(AirGapMessage::Signed(chain, raw.to_vec()))
+            }
+            _ => Err(ConversionError::UnsupportedValue("type".to_string())),
+        }
+    }
+}
+
+/// Decode a 256-bit number, which is RLP encoded without leading zeroes
+fn decode_u256(rlp: &Rlp, index: usize, field: &str) -> Result<[u8; 32], ConversionError> {
+    let value = rlp
+        .at(index)
+        .and_then(|v| v.data().map(|d| d.to_vec()))
+        .map_err(|_| ConversionError::InvalidFieldValue(field.to_string()))?;
+    if value.len() > 32 {
+        return Err(ConversionError::InvalidFieldValue(field.to_string()));
+    }
+    Ok(to_arr(align_bytes(&value, 32).as_slice()))
+}
+
+fn decode_tx(rlp: &Rlp) -> Result<EthereumTransaction, ConversionError> {
+    if rlp.item_count().map_err(|_| ConversionError::InvalidLength)? != 6 {
+        return Err(ConversionError::InvalidLength);
+    }
+    let to = rlp
+        .at(3)
+        .and_then(|v| v.data().map(|d| d.to_vec()))
+        .map_err(|_| ConversionError::InvalidFieldValue("to".to_string()))?;
+    let to = if to.is_empty() {
+        None
+    } else {
+        Some(
+            EthereumAddress::try_from(&to)
+                .map_err(|_| ConversionError::InvalidFieldValue("to".to_string()))?,
+        )
+    };
+    let tx = EthereumTransaction {
+        nonce: rlp
+            .val_at(0)
+            .map_err(|_| ConversionError::InvalidFieldValue("nonce".to_string()))?,
+        gas_price: decode_u256(rlp, 1, "gas_price")?,
+        gas_limit: rlp
+            .val_at(2)
+            .map_err(|_| ConversionError::InvalidFieldValue("gas_limit".to_string()))?,
+        to,
+        value: decode_u256(rlp, 4, "value")?,
+        data: rlp
+            .at(5)
+            .and_then(|v| v.data().map(|d| d.to_vec()))
+            .map_err(|_| ConversionError::InvalidFieldValue("data".to_string()))?,
+    };
+    Ok(tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::*, EthereumPrivateKey};
+
+    fn create_tx() -> EthereumTransaction {
+        EthereumTransaction {
+            nonce: 1048585,
+            gas_price: to_32bytes(
+                "00000000000000000000000000000000000000000000000000000004a817c800",
+            ),
+            gas_limit: 21000,
+            to: Some(
+                "0x163b454d1ccdd0a12e88341b12afb2c98044c599"
+                    .parse::<EthereumAddress>()
+                    .unwrap(),
+            ),
+            value: to_32bytes(
+                "00000000000000000000000000000000000000000000001e7751166579880000",
+            ),
+            data: hex::decode("a9059cbb").unwrap(),
+        }
+    }
+
+    #[test]
+    fn encode_decode_unsigned() {
+        let tx = create_tx();
+        let encoded =
+            AirGapMessage::Unsigned(EthereumChainId::EthereumClassic, tx.clone()).encode();
+        assert_eq!(AIRGAP_VERSION, encoded[0]);
+
+        match AirGapMessage::decode(&encoded).unwrap() {
+            AirGapMessage::Unsigned(chain, act) => {
+                assert_eq!(EthereumChainId::EthereumClassic, chain);
+                assert_eq!(tx.nonce, act.nonce);
+                assert_eq!(tx.gas_price, act.gas_price);
+                assert_eq!(tx.gas_limit, act.gas_limit);
+                assert_eq!(tx.to, act.to);
+                assert_eq!(tx.value, act.value);
+                assert_eq!(tx.data, act.data);
+            }
+            _ => panic!("not unsigned"),
+        }
+    }
+
+    #[test]
+    fn encode_decode_contract_creation() {
+        let tx = EthereumTransaction {
+            to: None,
+            data: vec![0x60, 0x80, 0x60, 0x40],
+            nonce: 0,
+            ..create_tx()
+        };
+        let encoded = AirGapMessage::Unsigned(EthereumChainId::Mordor, tx.clone()).encode();
+
+        match AirGapMessage::decode(&encoded).unwrap() {
+            AirGapMessage::Unsigned(chain, act) => {
+                assert_eq!(EthereumChainId::Mordor, chain);
+                assert_eq!(0, act.nonce);
+                assert_eq!(None, act.to);
+                assert_eq!(tx.to_rlp(Some(63)), act.to_rlp(Some(63)));
+            }
+            _ => panic!("not unsigned"),
+        }
+    }
+
+    #[test]
+    fn encode_decode_signed() {
+        let pk = EthereumPrivateKey(to_32bytes(
+            "28b469dc4b039ff63fcd4cb708c668545e644cb25f21df6920aac20e4bc743f7",
+        ));
+        let raw = create_tx().to_signed_raw(pk, EthereumChainId::EthereumClassic).unwrap();
+        let encoded = AirGapMessage::Signed(EthereumChainId::EthereumClassic, raw.clone()).encode();
+
+        match AirGapMessage::decode(&encoded).unwrap() {
+            AirGapMessage::Signed(chain, act) => {
+                assert_eq!(EthereumChainId::EthereumClassic, chain);
+                assert_eq!(raw, act);
+            }
+            _ => panic!("not signed"),
+        }
+    }
+
+    #[test]
+    fn deny_unsupported_version() {
+        let mut encoded = AirGapMessage::Signed(EthereumChainId::Ethereum, vec![1, 2, 3]).encode();
+        encoded[0] = 2;
+        assert_eq!(
+            ConversionError::UnsupportedVersion,
+            AirGapMessage::decode(&encoded).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn deny_invalid_data() {
+        assert!(AirGapMessage::decode(&[]).is_err());
+        assert!(AirGapMessage::decode(&[AIRGAP_VERSION]).is_err());
+        assert!(AirGapMessage::decode(&[AIRGAP_VERSION, 0xc1, 0x01]).is_err());
+
+        let encoded = AirGapMessage::Signed(EthereumChainId::Ethereum, vec![1, 2, 3]).encode();
+        assert!(AirGapMessage::decode(&encoded[..encoded.len() - 1]).is_err());
+    }
+}
@@ -26,9 +26,11 @@ use hex;
 use rand::{rngs::OsRng, Rng};
 use secp256k1::{
     key::{PublicKey, SecretKey},
+    recovery::{RecoverableSignature, RecoveryId},
     Message,
     Secp256k1,
     SignOnly,
+    VerifyOnly,
 };
 use std::{convert::TryFrom, fmt, ops, str};
 
@@ -40,6 +42,33 @@ pub const ECDSA_SIGNATURE_BYTES: usize = 65;
 
 lazy_static! {
     static ref ECDSA: Secp256k1<SignOnly> = Secp256k1::signing_only();
+    static ref ECDSA_VERIFY: Secp256k1<VerifyOnly> = Secp256k1::verification_only();
+}
+
+/// Method used to sign a message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    /// `eth_sign`, signs the raw 32-byte input as is, without any prefix or hashing.
+    /// The input must be already a hash of the message
+    EthSign,
+    /// `personal_sign`, the message is prefixed with `"\x19Ethereum Signed Message:\n" + len(message)`
+    /// and hashed with Keccak-256 before signing
+    PersonalSign,
+}
+
+impl Method {
+    /// Get the hash which is actually signed for the data
+    pub fn hash(&self, data: &[u8]) -> Result<[u8; KECCAK256_BYTES], Error> {
+        match self {
+            Method::EthSign => {
+                if data.len() != KECCAK256_BYTES {
+                    return Err(Error::InvalidLength(data.len()));
+                }
+                Ok(to_arr(data))
+            }
+            Method::PersonalSign => Ok(bytes_hash(data)),
+        }
+    }
 }
 
 /// Transaction sign data (see Appendix F. "Signing Transactions" from Yellow Paper)
@@ -67,6 +96,42 @@ impl From<[u8; ECDSA_SIGNATURE_BYTES]> for EthereumSignature {
     }
 }
 
+impl EthereumSignature {
+    /// Recovery id as 0 or 1, accepts both legacy (27/28) and raw (0/1) values of `v`
+    fn recovery_id(&self) -> Result<RecoveryId, Error> {
+        let v = if self.v >= 27 { self.v - 27 } else { self.v };
+        Ok(RecoveryId::from_i32(v as i32)?)
+    }
+}
+
+/// Recover address of the signer of the data signed with the specified method
+pub fn recover_address(
+    method: Method,
+    data: &[u8],
+    signature: &EthereumSignature,
+) -> Result<EthereumAddress, Error> {
+    let msg = Message::from_slice(&method.hash(data)?)?;
+    let mut compact = [0u8; 64];
+    compact[0..32].copy_from_slice(&signature.r);
+    compact[32..64].copy_from_slice(&signature.s);
+    let sig = RecoverableSignature::from_compact(&compact, signature.recovery_id()?)?;
+    let key = ECDSA_VERIFY.recover(&msg, &sig)?;
+    Ok(EthereumAddress::from(key))
+}
+
+/// Verify that the data was signed by the expected address. Returns false for an invalid signature
+pub fn verify_message(
+    method: Method,
+    data: &[u8],
+    signature: &EthereumSignature,
+    expected: &EthereumAddress,
+) -> bool {
+    match recover_address(method, data, signature) {
+        Ok(address) => address == *expected,
+        Err(_) => false,
+    }
+}
+
 impl Into<(u8, [u8; 32], [u8; 32])> for EthereumSignature {
     fn into(self) -> (u8, [u8; 32], [u8; 32]) {
         (self.v, self.r, self.s)
@@ -115,7 +180,15 @@ impl EthereumPrivateKey {
         self.sign_hash(bytes_hash(data))
     }
 
-    /// Sign hash from message (Keccak-256)
+    /// Sign data with the specified method. The `v` of the signature is always 27 or 28
+    pub fn sign_with(&self, method: Method, data: &[u8]) -> Result<EthereumSignature, Error> {
+        self.sign_hash(method.hash(data)?)
+    }
+
+    /// Sign hash from message (Keccak-256). The nonce is derived deterministically from the key
+    /// and the hash (RFC 6979), so the same input always gives the same signature. The `s` value
+    /// is always in the lower half of the curve order (EIP-2), as libsecp256k1 normalizes it and
+    /// adjusts the recovery id accordingly
     pub fn sign_hash(&self, hash: [u8; KECCAK256_BYTES]) -> Result<EthereumSignature, Error> {
         let msg = Message::from_slice(&hash)?;
         let key = SecretKey::from_slice(self)?;
@@ -248,6 +321,128 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deterministic_signature() {
+        let key = EthereumPrivateKey(to_32bytes(
+            "3c9229289a6125f7fdf1885a77bb12c37a8d3b4962d936f7e3084dece32a3ca1",
+        ));
+        let hash = to_32bytes("82ff40c0a986c6a5cfad4ddf4c3aa6996f1a7837f9c398e17e5de5cbd5a12b28");
+
+        let first = key.sign_hash(hash).unwrap();
+        let second = key.sign_hash(hash).unwrap();
+        assert_eq!(first.v, second.v);
+        assert_eq!(first.r, second.r);
+        assert_eq!(first.s, second.s);
+
+        let first = key.sign_message("test message").unwrap();
+        let second = key.sign_message("test message").unwrap();
+        assert_eq!(first.r, second.r);
+        assert_eq!(first.s, second.s);
+    }
+
+    #[test]
+    fn signature_has_low_s() {
+        // secp256k1 curve order / 2
+        let half_n =
+            to_32bytes("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0");
+        let key = EthereumPrivateKey(to_32bytes(
+            "00b413b37c71bfb92719d16e28d7329dea5befa0d0b8190742f89e55617991cf",
+        ));
+        // with a random nonce about half of signatures would have a high s
+        for i in 1..=64u8 {
+            let data = [i; 32];
+            let sig = key.sign_with(Method::EthSign, &data).unwrap();
+            assert!(sig.s <= half_n, "high s for {}", i);
+            let act = recover_address(Method::EthSign, &data, &sig).unwrap();
+            assert_eq!(act, key.to_address());
+        }
+    }
+
+    #[test]
+    fn personal_sign_recovers_signer() {
+        let key = EthereumPrivateKey(to_32bytes(
+            "00b413b37c71bfb92719d16e28d7329dea5befa0d0b8190742f89e55617991cf",
+        ));
+        let sig = key.sign_with(Method::PersonalSign, b"Hello world").unwrap();
+        assert!(sig.v == 27 || sig.v == 28);
+        assert_eq!(sig, key.sign_message("Hello world").unwrap());
+
+        let act = recover_address(Method::PersonalSign, b"Hello world", &sig).unwrap();
+        assert_eq!(act, key.to_address());
+
+        let act = recover_address(Method::PersonalSign, b"Hello world!", &sig).unwrap();
+        assert_ne!(act, key.to_address());
+    }
+
+    #[test]
+    fn recover_with_raw_recovery_id() {
+        let key = EthereumPrivateKey(to_32bytes(
+            "00b413b37c71bfb92719d16e28d7329dea5befa0d0b8190742f89e55617991cf",
+        ));
+        let mut sig = key.sign_with(Method::PersonalSign, b"Hello world").unwrap();
+        sig.v -= 27;
+        let act = recover_address(Method::PersonalSign, b"Hello world", &sig).unwrap();
+        assert_eq!(act, key.to_address());
+    }
+
+    #[test]
+    fn eth_sign_uses_raw_hash() {
+        let key = EthereumPrivateKey(to_32bytes(
+            "3c9229289a6125f7fdf1885a77bb12c37a8d3b4962d936f7e3084dece32a3ca1",
+        ));
+        let hash = to_32bytes("82ff40c0a986c6a5cfad4ddf4c3aa6996f1a7837f9c398e17e5de5cbd5a12b28");
+        let sig = key.sign_with(Method::EthSign, &hash).unwrap();
+        assert_eq!(sig, key.sign_hash(hash).unwrap());
+
+        let act = recover_address(Method::EthSign, &hash, &sig).unwrap();
+        assert_eq!(act, key.to_address());
+        let act = recover_address(Method::PersonalSign, &hash, &sig).unwrap();
+        assert_ne!(act, key.to_address());
+    }
+
+    #[test]
+    fn eth_sign_requires_32_bytes() {
+        let key = EthereumPrivateKey(to_32bytes(
+            "3c9229289a6125f7fdf1885a77bb12c37a8d3b4962d936f7e3084dece32a3ca1",
+        ));
+        assert!(key.sign_with(Method::EthSign, b"Hello world").is_err());
+    }
+
+    #[test]
+    fn verify_valid_message() {
+        let key = EthereumPrivateKey(to_32bytes(
+            "00b413b37c71bfb92719d16e28d7329dea5befa0d0b8190742f89e55617991cf",
+        ));
+        let sig = key.sign_with(Method::PersonalSign, b"Login 1234").unwrap();
+        assert!(verify_message(Method::PersonalSign, b"Login 1234", &sig, &key.to_address()));
+
+        let hash = keccak256(b"Login 1234");
+        let sig = key.sign_with(Method::EthSign, &hash).unwrap();
+        assert!(verify_message(Method::EthSign, &hash, &sig, &key.to_address()));
+    }
+
+    #[test]
+    fn doesnt_verify_tampered_message() {
+        let key = EthereumPrivateKey(to_32bytes(
+            "00b413b37c71bfb92719d16e28d7329dea5befa0d0b8190742f89e55617991cf",
+        ));
+        let sig = key.sign_with(Method::PersonalSign, b"Login 1234").unwrap();
+        assert!(!verify_message(Method::PersonalSign, b"Login 1235", &sig, &key.to_address()));
+        assert!(!verify_message(Method::EthSign, &keccak256(b"Login 1234"), &sig, &key.to_address()));
+    }
+
+    #[test]
+    fn doesnt_verify_different_key() {
+        let key = EthereumPrivateKey(to_32bytes(
+            "00b413b37c71bfb92719d16e28d7329dea5befa0d0b8190742f89e55617991cf",
+        ));
+        let other = EthereumPrivateKey(to_32bytes(
+            "3c9229289a6125f7fdf1885a77bb12c37a8d3b4962d936f7e3084dece32a3ca1",
+        ));
+        let sig = other.sign_with(Method::PersonalSign, b"Login 1234").unwrap();
+        assert!(!verify_message(Method::PersonalSign, b"Login 1234", &sig, &key.to_address()));
+    }
+
     #[test]
     fn should_calculate_message_hash() {
         assert_eq!(
@@ -393,4 +393,73 @@ mod tests {
              83d6e12e82e3544cb4439964d5087da78f74cefeec9a450b16ae179fd8fe20"
         );
     }
+
+    #[test]
+    fn encode_contract_creation() {
+        let tx = EthereumTransaction {
+            nonce: 0,
+            gas_price: to_32bytes("04a817c800"),
+            gas_limit: 100000,
+            to: None,
+            value: to_32bytes("00"),
+            data: hex::decode(
+                "6080604052348015600f57600080fd5b50603f80601d6000396000f3fe6080604052600080fdfea164736f6c6343000607000a"
+            ).unwrap(),
+        };
+        let rlp = tx.to_rlp(Some(61));
+
+        assert_eq!(
+            hex::encode(rlp),
+            "".to_owned() +
+                "f844" + //list of 68 bytes
+                "80" + //nonce
+                "85" + "04a817c800" + //gasprice
+                "83" + "0186a0" + //gas
+                "80" + //to, empty for contract creation
+                "80" + //value
+                "b3" + "6080604052348015600f57600080fd5b50603f80601d6000396000f3fe6080604052600080fdfea164736f6c6343000607000a" + //data
+                "3d" + //v
+                "80" + //r
+                "80" //s
+        );
+    }
+
+    #[test]
+    fn encode_tx_with_large_data() {
+        let data: Vec<u8> = (0..1024).map(|i| (i % 256) as u8).collect();
+        let tx = EthereumTransaction {
+            nonce: 3,
+            gas_price: to_32bytes("04a817c800"),
+            gas_limit: 100000,
+            to: Some(
+                "0x3535353535353535353535353535353535353535"
+                    .parse::<EthereumAddress>()
+                    .unwrap(),
+            ),
+            value: to_32bytes("00"),
+            data: data.clone(),
+        };
+        let rlp = tx.to_rlp(Some(61));
+
+        let exp = "".to_owned() +
+            "f90427" + //list of 1063 bytes, long form with 2 bytes length
+            "03" + //nonce
+            "85" + "04a817c800" + //gasprice
+            "83" + "0186a0" + //gas
+            "94" + "3535353535353535353535353535353535353535" + // to
+            "80" + //value
+            "b90400" + hex::encode(&data).as_str() + //data, long form with 2 bytes length
+            "3d" + //v
+            "80" + //r
+            "80"; //s
+        assert_eq!(hex::encode(rlp), exp);
+
+        let pk = EthereumPrivateKey(to_32bytes(
+            "4646464646464646464646464646464646464646464646464646464646464646",
+        ));
+        let signed = tx.to_signed_raw(pk, EthereumChainId::EthereumClassic).unwrap();
+        assert_eq!(0xf9, signed[0]);
+        let len = ((signed[1] as usize) << 8) + signed[2] as usize;
+        assert_eq!(signed.len() - 3, len);
+    }
 }
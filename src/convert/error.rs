@@ -13,6 +13,8 @@ pub enum ConversionError {
     /// value  is the field name
     UnsupportedValue(String),
     UnsupportedVersion,
+    /// Private key of a type that is not supported by the current version of the vault
+    UnsupportedKeyType,
     InvalidHex,
     InvalidBase58,
     CSVError,
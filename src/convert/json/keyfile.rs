@@ -9,9 +9,11 @@ use crate::{
     EthereumPrivateKey,
 };
 use chrono::Utc;
+use rand::{prelude::Rng, thread_rng};
 use serde::Deserialize;
 use std::{convert::TryFrom, fmt, str::FromStr};
 use uuid::Uuid;
+use zeroize::Zeroizing;
 
 /// `PBKDF2` key derivation function name
 pub const PBKDF2_KDF_NAME: &str = "pbkdf2";
@@ -257,6 +259,23 @@ impl fmt::Display for KdfJson {
     }
 }
 
+impl KdfJson {
+    /// Check if the KDF is cheaper to brute force than `other`. Scrypt is considered stronger than
+    /// PBKDF2 with any parameters, because it's memory-hard
+    pub fn is_weaker_than(&self, other: &KdfJson) -> bool {
+        match (*self, *other) {
+            (KdfJson::Pbkdf2 { c, .. }, KdfJson::Pbkdf2 { c: other_c, .. }) => c < other_c,
+            (KdfJson::Pbkdf2 { .. }, KdfJson::Scrypt { .. }) => true,
+            (KdfJson::Scrypt { .. }, KdfJson::Pbkdf2 { .. }) => false,
+            (KdfJson::Scrypt { n, r, p }, KdfJson::Scrypt { n: other_n, r: other_r, p: other_p }) => {
+                let memory = n as u64 * r as u64;
+                let other_memory = other_n as u64 * other_r as u64;
+                memory < other_memory || memory * (p as u64) < other_memory * (other_p as u64)
+            }
+        }
+    }
+}
+
 impl TryFrom<String> for EthereumJsonV3File {
     type Error = ConversionError;
 
@@ -331,6 +350,58 @@ impl From<&CoreCryptoJson> for Kdf {
 }
 
 impl EthereumJsonV3File {
+    /// KDF type and its parameters (n/r/p for Scrypt, or iterations count for PBKDF2) used to
+    /// encrypt the key. Doesn't require a password, so it can be used to check whether an imported
+    /// keystore uses weak parameters
+    pub fn kdf_info(&self) -> KdfJson {
+        self.crypto.kdf_params.kdf
+    }
+
+    /// Re-encrypt the key using another KDF, ex. to replace weak parameters of an imported
+    /// keystore. Id, address and labels stay the same, while salt and IV are generated anew.
+    /// Returns `CryptoError::InvalidParams` if the new KDF is weaker than the current one
+    pub fn upgrade_kdf(
+        &self,
+        password: &str,
+        kdf: KdfJson,
+    ) -> Result<EthereumJsonV3File, CryptoError> {
+        if kdf.is_weaker_than(&self.kdf_info()) {
+            return Err(CryptoError::InvalidParams);
+        }
+        let current = Encrypted::try_from(self)
+            .map_err(|_| CryptoError::UnsupportedSource("keyfile crypto".to_string()))?;
+        let mut salt = [0u8; KDF_SALT_BYTES];
+        thread_rng()
+            .try_fill(&mut salt)
+            .map_err(|_| CryptoError::NoEntropy)?;
+        let kdf = match kdf {
+            KdfJson::Pbkdf2 { prf, c } => Kdf::Pbkdf2(Pbkdf2 {
+                dklen: DEFAULT_DK_LENGTH as u32,
+                c,
+                salt: salt.to_vec(),
+                prf: match prf {
+                    PrfJson::HmacSha256 => PrfType::HmacSha256,
+                    PrfJson::HmacSha512 => PrfType::HmacSha512,
+                },
+            }),
+            KdfJson::Scrypt { n, r, p } => Kdf::Scrypt(ScryptKdf {
+                dklen: DEFAULT_DK_LENGTH as u32,
+                salt: salt.to_vec(),
+                n,
+                r,
+                p,
+            }),
+        };
+        let pk = Zeroizing::new(current.decrypt(password)?);
+        let encrypted = Encrypted::encrypt_with_kdf(pk.to_vec(), password, kdf)?;
+        let crypto = CoreCryptoJson::try_from(&encrypted)
+            .map_err(|_| CryptoError::UnsupportedSource("encrypted format".to_string()))?;
+        Ok(EthereumJsonV3File {
+            crypto,
+            ..self.clone()
+        })
+    }
+
     pub fn from_wallet(
         label: Option<String>,
         pk: &PrivateKeyHolder,
@@ -449,6 +520,8 @@ impl TryFrom<&Encrypted> for CoreCryptoJson {
                 };
                 Ok(result)
             }
+            // Web3 Secret Storage supports only AES-128-CTR
+            Cipher::Aes256Gcm(_) => Err(ConversionError::UnsupportedValue("cipher".to_string())),
         }
     }
 }
@@ -589,6 +662,202 @@ mod tests {
         assert_eq!(None, parsed.address);
     }
 
+    #[test]
+    fn provides_kdf_info() {
+        let json = r#"
+            {
+                "crypto" : {
+                    "cipher" : "aes-128-ctr",
+                    "cipherparams" : {
+                        "iv" : "83dbcc02d8ccb40e466191a123791e0e"
+                    },
+                    "ciphertext" : "d172bf743a674da9cdad04534d56926ef8358534d458fffccd4e6ad2fbde479c",
+                    "kdf" : "scrypt",
+                    "kdfparams" : {
+                        "dklen" : 32,
+                        "n" : 262144,
+                        "p" : 8,
+                        "r" : 1,
+                        "salt" : "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+                    },
+                    "mac" : "2103ac29920d71da29f15d75b4a16dbe95cfd7ff8faea1056c33131d846e3097"
+                },
+                "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+                "version" : 3
+            }
+            "#;
+        let parsed = EthereumJsonV3File::try_from(json.to_string()).unwrap();
+        assert_eq!(
+            KdfJson::Scrypt {
+                n: 262144,
+                r: 1,
+                p: 8
+            },
+            parsed.kdf_info()
+        );
+        assert_eq!("scrypt", parsed.kdf_info().to_string());
+
+        let json = r#"
+            {
+                "crypto" : {
+                    "cipher" : "aes-128-ctr",
+                    "cipherparams" : {
+                        "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+                    },
+                    "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+                    "kdf" : "pbkdf2",
+                    "kdfparams" : {
+                        "c" : 262144,
+                        "dklen" : 32,
+                        "prf" : "hmac-sha256",
+                        "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+                    },
+                    "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+                },
+                "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+                "version" : 3
+            }
+            "#;
+        let parsed = EthereumJsonV3File::try_from(json.to_string()).unwrap();
+        assert_eq!(
+            KdfJson::Pbkdf2 {
+                prf: PrfJson::HmacSha256,
+                c: 262144
+            },
+            parsed.kdf_info()
+        );
+        assert_eq!("pbkdf2", parsed.kdf_info().to_string());
+    }
+
+    #[test]
+    fn upgrade_pbkdf2_to_scrypt() {
+        let json = r#"
+            {
+                "crypto" : {
+                    "cipher" : "aes-128-ctr",
+                    "cipherparams" : {
+                        "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+                    },
+                    "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+                    "kdf" : "pbkdf2",
+                    "kdfparams" : {
+                        "c" : 262144,
+                        "dklen" : 32,
+                        "prf" : "hmac-sha256",
+                        "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+                    },
+                    "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+                },
+                "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+                "version" : 3,
+                "name" : "Test"
+            }
+            "#;
+        let parsed = EthereumJsonV3File::try_from(json.to_string()).unwrap();
+        let pk = Encrypted::try_from(&parsed)
+            .unwrap()
+            .decrypt("testpassword")
+            .unwrap();
+
+        let upgraded = parsed
+            .upgrade_kdf("testpassword", KdfJson::from((16384, 8, 1)))
+            .unwrap();
+
+        assert_eq!(
+            KdfJson::Scrypt {
+                n: 16384,
+                r: 8,
+                p: 1
+            },
+            upgraded.kdf_info()
+        );
+        assert_eq!(parsed.id, upgraded.id);
+        assert_eq!(Some("Test".to_string()), upgraded.name);
+        assert_ne!(parsed.crypto.kdf_params.salt, upgraded.crypto.kdf_params.salt);
+        assert_ne!(parsed.crypto.cipher_text, upgraded.crypto.cipher_text);
+
+        let act = Encrypted::try_from(&upgraded)
+            .unwrap()
+            .decrypt("testpassword")
+            .unwrap();
+        assert_eq!(hex::encode(pk), hex::encode(act));
+    }
+
+    #[test]
+    fn doesnt_upgrade_with_wrong_password() {
+        let json = r#"
+            {
+                "crypto" : {
+                    "cipher" : "aes-128-ctr",
+                    "cipherparams" : {
+                        "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+                    },
+                    "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+                    "kdf" : "pbkdf2",
+                    "kdfparams" : {
+                        "c" : 262144,
+                        "dklen" : 32,
+                        "prf" : "hmac-sha256",
+                        "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+                    },
+                    "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+                },
+                "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+                "version" : 3
+            }
+            "#;
+        let parsed = EthereumJsonV3File::try_from(json.to_string()).unwrap();
+        let act = parsed.upgrade_kdf("testpassword1", KdfJson::from((16384, 8, 1)));
+        assert_eq!(CryptoError::WrongKey, act.unwrap_err());
+    }
+
+    #[test]
+    fn doesnt_upgrade_to_weaker_kdf() {
+        let json = r#"
+            {
+                "crypto" : {
+                    "cipher" : "aes-128-ctr",
+                    "cipherparams" : {
+                        "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+                    },
+                    "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+                    "kdf" : "pbkdf2",
+                    "kdfparams" : {
+                        "c" : 262144,
+                        "dklen" : 32,
+                        "prf" : "hmac-sha256",
+                        "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+                    },
+                    "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+                },
+                "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+                "version" : 3
+            }
+            "#;
+        let parsed = EthereumJsonV3File::try_from(json.to_string()).unwrap();
+        let act = parsed.upgrade_kdf("testpassword", KdfJson::from(1024));
+        assert_eq!(CryptoError::InvalidParams, act.unwrap_err());
+
+        let upgraded = parsed.upgrade_kdf("testpassword", KdfJson::from((16384, 8, 1))).unwrap();
+        let act = upgraded.upgrade_kdf("testpassword", KdfJson::from((1024, 8, 1)));
+        assert_eq!(CryptoError::InvalidParams, act.unwrap_err());
+        let act = upgraded.upgrade_kdf("testpassword", KdfJson::from(262144));
+        assert_eq!(CryptoError::InvalidParams, act.unwrap_err());
+        assert!(upgraded.upgrade_kdf("testpassword", KdfJson::from((16384, 8, 1))).is_ok());
+    }
+
+    #[test]
+    fn compare_kdf_strength() {
+        assert!(KdfJson::from(1024).is_weaker_than(&KdfJson::from(262144)));
+        assert!(!KdfJson::from(262144).is_weaker_than(&KdfJson::from(262144)));
+        assert!(KdfJson::from(1_000_000).is_weaker_than(&KdfJson::from((1024, 8, 1))));
+        assert!(!KdfJson::from((1024, 8, 1)).is_weaker_than(&KdfJson::from(1_000_000)));
+        assert!(KdfJson::from((1024, 8, 1)).is_weaker_than(&KdfJson::from((16384, 8, 1))));
+        assert!(KdfJson::from((16384, 4, 1)).is_weaker_than(&KdfJson::from((16384, 8, 1))));
+        assert!(KdfJson::from((16384, 8, 1)).is_weaker_than(&KdfJson::from((16384, 8, 2))));
+        assert!(!KdfJson::from((16384, 8, 2)).is_weaker_than(&KdfJson::from((16384, 8, 1))));
+    }
+
     #[test]
     fn import_with_address() {
         let json = r#"
@@ -11,7 +11,7 @@ use crate::{
         ScryptKdf as proto_ScryptKdf,
     },
     structs::{
-        crypto::{Aes128CtrCipher, Cipher, Encrypted, Kdf, MacType, Pbkdf2, PrfType, ScryptKdf},
+        crypto::{Aes128CtrCipher, Aes256GcmCipher, Cipher, Encrypted, Kdf, MacType, Pbkdf2, PrfType, ScryptKdf},
         types::IsVerified,
     },
 };
@@ -62,6 +62,10 @@ impl TryFrom<&proto_Encrypted> for Cipher {
                     None => Err(ConversionError::FieldIsEmpty("mac".to_string())),
                 }?,
             })),
+            proto_CipherType::CIPHER_AES256_GCM => Ok(Cipher::Aes256Gcm(Aes256GcmCipher {
+                encrypted: data.secret.clone(),
+                iv: data.iv.clone(),
+            })),
             proto_CipherType::CIPHER_UNKNOWN => {
                 Err(ConversionError::FieldIsEmpty("cipher_type".to_string()))
             }
@@ -171,6 +175,9 @@ impl TryFrom<&Encrypted> for proto_Encrypted {
                     }
                 }
             }
+            Cipher::Aes256Gcm(_) => {
+                encrypted.set_field_type(proto_CipherType::CIPHER_AES256_GCM);
+            }
         }
 
         Ok(encrypted)
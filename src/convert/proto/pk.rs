@@ -8,6 +8,7 @@ use crate::{
             EthereumPK3 as proto_EthereumPK3,
             EthereumPrivateKey as proto_EthereumPrivateKey,
             PrivateKey as proto_PrivateKey,
+            PrivateKey_oneof_pk as proto_PrivateKey_oneof_pk,
         },
     },
     structs::{
@@ -25,36 +26,37 @@ impl TryFrom<&[u8]> for PrivateKeyHolder {
     type Error = ConversionError;
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
         let m = parse_from_bytes::<proto_PrivateKey>(data)?;
-        if m.has_ethereum() {
-            let pk = m.get_ethereum();
-            if pk.has_pk() {
-                let pk = pk.get_pk();
-                let key = match &pk.value.clone().into_option() {
-                    Some(v) => Encrypted::try_from(v),
-                    None => Err(ConversionError::FieldIsEmpty("encrypted".to_string())),
-                }?;
-                let address = match EthereumAddress::from_str(pk.get_address()) {
-                    Ok(a) => Some(a),
-                    Err(_) => None,
-                };
-                let result = EthereumPk3 { address, key };
-                let pk = PrivateKeyType::EthereumPk(result);
-                let created_at = Utc
-                    .timestamp_millis_opt(m.get_created_at() as i64)
-                    .single()
-                    .unwrap_or_else(|| Utc.timestamp_millis(0));
-                let result = PrivateKeyHolder {
-                    id: Uuid::from_slice(m.get_id())
-                        .map_err(|_| ConversionError::InvalidFieldValue("id".to_string()))?,
-                    pk,
-                    created_at,
-                };
-                Ok(result)
-            } else {
-                Err(ConversionError::FieldIsEmpty("pk".to_string()))
-            }
+        // a key type added in a newer version is parsed as an unset oneof, so it's treated the
+        // same way as a known but unsupported type
+        let pk = match &m.pk {
+            Some(proto_PrivateKey_oneof_pk::ethereum(pk)) => pk,
+            Some(_) | None => return Err(ConversionError::UnsupportedKeyType),
+        };
+        if pk.has_pk() {
+            let pk = pk.get_pk();
+            let key = match &pk.value.clone().into_option() {
+                Some(v) => Encrypted::try_from(v),
+                None => Err(ConversionError::FieldIsEmpty("encrypted".to_string())),
+            }?;
+            let address = match EthereumAddress::from_str(pk.get_address()) {
+                Ok(a) => Some(a),
+                Err(_) => None,
+            };
+            let result = EthereumPk3 { address, key };
+            let pk = PrivateKeyType::EthereumPk(result);
+            let created_at = Utc
+                .timestamp_millis_opt(m.get_created_at() as i64)
+                .single()
+                .unwrap_or_else(|| Utc.timestamp_millis(0));
+            let result = PrivateKeyHolder {
+                id: Uuid::from_slice(m.get_id())
+                    .map_err(|_| ConversionError::InvalidFieldValue("id".to_string()))?,
+                pk,
+                created_at,
+            };
+            Ok(result)
         } else {
-            Err(ConversionError::InvalidFieldValue("pk".to_string()))
+            Err(ConversionError::FieldIsEmpty("pk".to_string()))
         }
     }
 }
@@ -98,7 +100,15 @@ impl TryFrom<PrivateKeyHolder> for Vec<u8> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{proto::pk::PrivateKey as proto_PrivateKey, structs::pk::PrivateKeyHolder};
+    use crate::{
+        convert::error::ConversionError,
+        proto::pk::{BitcoinPrivateKey as proto_BitcoinPrivateKey, PrivateKey as proto_PrivateKey},
+        structs::{
+            crypto::Encrypted,
+            pk::{EthereumPk3, PrivateKeyHolder, PrivateKeyType},
+        },
+        EthereumAddress,
+    };
     use chrono::{TimeZone, Utc};
     use protobuf::{parse_from_bytes, Message, ProtobufEnum};
     use std::{
@@ -150,4 +160,62 @@ mod tests {
         let act = PrivateKeyHolder::try_from(buf).unwrap();
         assert_eq!(act.created_at.timestamp_millis(), 0);
     }
+
+    #[test]
+    fn fail_on_unsupported_key_type() {
+        let mut m = proto_PrivateKey::new();
+        m.set_id(Uuid::new_v4().as_bytes().to_vec());
+        m.set_bitcoin(proto_BitcoinPrivateKey::new());
+
+        let buf = m.write_to_bytes().unwrap();
+        let act = PrivateKeyHolder::try_from(buf).err();
+        assert_eq!(Some(ConversionError::UnsupportedKeyType), act);
+    }
+
+    #[test]
+    fn fail_on_unset_key_type() {
+        let mut m = proto_PrivateKey::new();
+        m.set_id(Uuid::new_v4().as_bytes().to_vec());
+        m.set_created_at(1592624592679);
+
+        let buf = m.write_to_bytes().unwrap();
+        let act = PrivateKeyHolder::try_from(buf).err();
+        assert_eq!(Some(ConversionError::UnsupportedKeyType), act);
+    }
+
+    fn assert_round_trip(address: Option<EthereumAddress>) {
+        let key = Encrypted::encrypt(
+            hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd").unwrap(),
+            "test",
+        ).unwrap();
+        let pk = PrivateKeyHolder {
+            id: Uuid::new_v4(),
+            pk: PrivateKeyType::EthereumPk(EthereumPk3 { address, key: key.clone() }),
+            created_at: Utc.timestamp_millis(1592624592679),
+        };
+        let id = pk.id;
+
+        let b: Vec<u8> = pk.try_into().unwrap();
+        let act = PrivateKeyHolder::try_from(b).unwrap();
+        assert_eq!(act.id, id);
+        assert_eq!(act.created_at, Utc.timestamp_millis(1592624592679));
+        match act.pk {
+            PrivateKeyType::EthereumPk(act) => {
+                assert_eq!(act.address, address);
+                assert_eq!(act.key, key);
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_with_address() {
+        assert_round_trip(Some(
+            EthereumAddress::from_str("0x008aeeda4d805471df9b2a5b0f38a0c3bcba786b").unwrap()
+        ));
+    }
+
+    #[test]
+    fn round_trip_without_address() {
+        assert_round_trip(None);
+    }
 }
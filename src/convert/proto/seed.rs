@@ -13,12 +13,16 @@ use crate::{
     },
     structs::{
         crypto::Encrypted,
-        seed::{Bytes256, FingerprintType, HDPathFingerprint, LedgerSource, Seed, SeedSource},
+        seed::{
+            Bytes256, FingerprintType, HDPathFingerprint, LedgerSource, Seed, SeedFingerprint,
+            SeedSource,
+        },
     },
     util::none_if_empty,
 };
+use bitcoin::util::bip32::Fingerprint;
 use chrono::{TimeZone, Utc};
-use hdpath::{Purpose, StandardHDPath};
+use hdpath::{AccountHDPath, Purpose, StandardHDPath};
 use protobuf::{parse_from_bytes, Message};
 use std::convert::{TryFrom, TryInto};
 use uuid::Uuid;
@@ -51,6 +55,16 @@ impl From<StandardHDPath> for proto_HDPath {
     }
 }
 
+impl From<AccountHDPath> for proto_HDPath {
+    fn from(hdpath: AccountHDPath) -> Self {
+        let mut m = proto_HDPath::new();
+        m.set_purpose(hdpath.purpose().as_value().as_number());
+        m.set_coin(hdpath.coin_type());
+        m.set_account(hdpath.account());
+        m
+    }
+}
+
 /// Read from Protobuf message
 impl TryFrom<&proto_LedgerSeed> for LedgerSource {
     type Error = ConversionError;
@@ -73,7 +87,16 @@ impl TryFrom<&proto_LedgerSeed> for LedgerSource {
             }
             fingerprints
         };
-        let result = LedgerSource { fingerprints };
+        let master_fingerprint = match value.get_master_fingerprint() {
+            b if b.is_empty() => None,
+            b if b.len() == 4 => Some(Fingerprint::from(b)),
+            _ => return Err(ConversionError::InvalidFieldValue("master_fingerprint".to_string())),
+        };
+        let result = LedgerSource {
+            fingerprints,
+            device_model: none_if_empty(value.get_device_model()),
+            master_fingerprint,
+        };
         Ok(result)
     }
 }
@@ -84,7 +107,7 @@ impl TryFrom<LedgerSource> for proto_LedgerSeed {
 
     fn try_from(value: LedgerSource) -> Result<Self, Self::Error> {
         let mut m = proto_LedgerSeed::new();
-        if m.get_fingerprints().len() > 0 {
+        if !value.fingerprints.is_empty() {
             let fingerprings: Vec<proto_HDFingerprint> = value
                 .fingerprints
                 .iter()
@@ -100,10 +123,42 @@ impl TryFrom<LedgerSource> for proto_LedgerSeed {
 
             m.set_fingerprints(protobuf::RepeatedField::from_vec(fingerprings));
         }
+        if let Some(device_model) = value.device_model {
+            m.set_device_model(device_model);
+        }
+        if let Some(master_fingerprint) = value.master_fingerprint {
+            m.set_master_fingerprint(master_fingerprint.as_bytes().to_vec());
+        }
         Ok(m)
     }
 }
 
+/// Read from fingerprint bytes, which are 16 bytes of salt followed by 32 bytes of hash
+impl TryFrom<&[u8]> for SeedFingerprint {
+    type Error = ConversionError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() != 48 {
+            return Err(ConversionError::InvalidLength);
+        }
+        let mut salt: [u8; 16] = [0; 16];
+        salt.copy_from_slice(&value[0..16]);
+        let hash = Bytes256::try_from(&value[16..])
+            .map_err(|_| ConversionError::InvalidFieldValue("fingerprint".to_string()))?;
+        Ok(SeedFingerprint { salt, hash })
+    }
+}
+
+/// Write as fingerprint bytes
+impl From<SeedFingerprint> for Vec<u8> {
+    fn from(value: SeedFingerprint) -> Self {
+        let hash: Vec<u8> = value.hash.into();
+        let mut result = value.salt.to_vec();
+        result.extend_from_slice(hash.as_slice());
+        result
+    }
+}
+
 /// Read from Protobuf bytes
 impl TryFrom<&[u8]> for Seed {
     type Error = ConversionError;
@@ -122,12 +177,18 @@ impl TryFrom<&[u8]> for Seed {
             .timestamp_millis_opt(m.get_created_at() as i64)
             .single()
             .unwrap_or_else(|| Utc.timestamp_millis(0));
+        let fingerprint = if m.get_fingerprint().is_empty() {
+            None
+        } else {
+            Some(SeedFingerprint::try_from(m.get_fingerprint())?)
+        };
         let result = Seed {
             id: Uuid::from_slice(m.get_id())
                 .map_err(|_| ConversionError::InvalidFieldValue("id".to_string()))?,
             source,
             label,
             created_at,
+            fingerprint,
         };
         Ok(result)
     }
@@ -158,6 +219,9 @@ impl TryFrom<Seed> for Vec<u8> {
             SeedSource::Ledger(s) => m.set_ledger(s.try_into()?),
         }
         m.set_created_at(value.created_at.timestamp_millis() as u64);
+        if let Some(fingerprint) = value.fingerprint {
+            m.set_fingerprint(fingerprint.into());
+        }
         m.write_to_bytes().map_err(|e| ConversionError::from(e))
     }
 }
@@ -168,10 +232,13 @@ mod tests {
         proto::seed::{LedgerSeed as proto_LedgerSeed, Seed as proto_Seed},
         structs::{
             crypto::Encrypted,
-            seed::{LedgerSource, Seed, SeedSource},
+            seed::{Bytes256, HDPathFingerprint, LedgerSource, Seed, SeedFingerprint, SeedSource},
         },
+        EthereumAddress,
     };
+    use bitcoin::util::bip32::Fingerprint;
     use chrono::{TimeZone, Utc};
+    use hdpath::StandardHDPath;
     use protobuf::{parse_from_bytes, Message, ProtobufEnum};
     use std::{
         convert::{TryFrom, TryInto},
@@ -186,6 +253,7 @@ mod tests {
             source: SeedSource::Bytes(Encrypted::encrypt(b"test".to_vec(), "test").unwrap()),
             label: None,
             created_at: Utc.timestamp_millis(1592624592679),
+            fingerprint: None,
         };
 
         let b: Vec<u8> = seed.clone().try_into().unwrap();
@@ -208,6 +276,7 @@ mod tests {
             source: SeedSource::Bytes(Encrypted::encrypt(b"test".to_vec(), "test").unwrap()),
             label: None,
             created_at: Utc::now(),
+            fingerprint: None,
         };
         let seed_id = seed.id.clone();
         let buf: Vec<u8> = seed.try_into().unwrap();
@@ -226,9 +295,12 @@ mod tests {
             id: Uuid::new_v4(),
             source: SeedSource::Ledger(LedgerSource {
                 fingerprints: vec![],
+                device_model: None,
+                master_fingerprint: None,
             }),
             label: None,
             created_at: Utc::now(),
+            fingerprint: None,
         };
         let seed_id = seed.id.clone();
         let buf: Vec<u8> = seed.try_into().unwrap();
@@ -241,15 +313,44 @@ mod tests {
         };
     }
 
+    #[test]
+    fn write_and_read_ledger_fingerprints() {
+        let fingerprint = HDPathFingerprint::from_address(
+            StandardHDPath::from_str("m/44'/60'/0'/0/0").unwrap(),
+            &EthereumAddress::from_str("0x3eaf0b987b49c4d782ee134fdac1243fd0d82535").unwrap(),
+        );
+        let seed = Seed {
+            id: Uuid::new_v4(),
+            source: SeedSource::Ledger(LedgerSource {
+                fingerprints: vec![fingerprint.clone()],
+                device_model: None,
+                master_fingerprint: None,
+            }),
+            label: None,
+            created_at: Utc::now(),
+            fingerprint: None,
+        };
+        let buf: Vec<u8> = seed.try_into().unwrap();
+        let seed_act = Seed::try_from(buf).unwrap();
+
+        match seed_act.source {
+            SeedSource::Ledger(v) => assert_eq!(v.fingerprints, vec![fingerprint]),
+            _ => panic!("Not ledger"),
+        };
+    }
+
     #[test]
     fn write_and_read_label() {
         let seed = Seed {
             id: Uuid::new_v4(),
             source: SeedSource::Ledger(LedgerSource {
                 fingerprints: vec![],
+                device_model: None,
+                master_fingerprint: None,
             }),
             label: Some("Hello World!".to_string()),
             created_at: Utc::now(),
+            fingerprint: None,
         };
         let seed_id = seed.id.clone();
         let buf: Vec<u8> = seed.try_into().unwrap();
@@ -264,9 +365,12 @@ mod tests {
             id: Uuid::new_v4(),
             source: SeedSource::Ledger(LedgerSource {
                 fingerprints: vec![],
+                device_model: None,
+                master_fingerprint: None,
             }),
             label: Some("".to_string()),
             created_at: Utc::now(),
+            fingerprint: None,
         };
         let seed_id = seed.id.clone();
         let buf: Vec<u8> = seed.try_into().unwrap();
@@ -281,9 +385,12 @@ mod tests {
             id: Uuid::new_v4(),
             source: SeedSource::Ledger(LedgerSource {
                 fingerprints: vec![],
+                device_model: None,
+                master_fingerprint: None,
             }),
             label: Some("Hello World!".to_string()),
             created_at: Utc.timestamp_millis(1592624592679),
+            fingerprint: None,
         };
         let seed_id = seed.id.clone();
         let buf: Vec<u8> = seed.try_into().unwrap();
@@ -303,4 +410,141 @@ mod tests {
         let act = Seed::try_from(buf).unwrap();
         assert_eq!(act.created_at.timestamp_millis(), 0);
     }
+
+    #[test]
+    fn round_trip_bytes() {
+        let seed = Seed {
+            id: Uuid::new_v4(),
+            source: SeedSource::Bytes(Encrypted::encrypt(b"test".to_vec(), "test").unwrap()),
+            label: Some("Test seed".to_string()),
+            created_at: Utc.timestamp_millis(1592624592679),
+            fingerprint: None,
+        };
+        let buf: Vec<u8> = seed.clone().try_into().unwrap();
+        let act = Seed::try_from(buf).unwrap();
+        assert_eq!(act, seed);
+    }
+
+    #[test]
+    fn round_trip_fingerprint() {
+        let seed = Seed {
+            id: Uuid::new_v4(),
+            source: SeedSource::Bytes(Encrypted::encrypt(b"test".to_vec(), "test").unwrap()),
+            label: None,
+            created_at: Utc.timestamp_millis(1592624592679),
+            fingerprint: Some(SeedFingerprint {
+                salt: [7; 16],
+                hash: Bytes256::try_from(vec![0xab; 32].as_slice()).unwrap(),
+            }),
+        };
+        let buf: Vec<u8> = seed.clone().try_into().unwrap();
+        let act = Seed::try_from(buf).unwrap();
+        assert_eq!(act, seed);
+    }
+
+    #[test]
+    fn fail_on_invalid_fingerprint() {
+        let mut m = proto_Seed::new();
+        m.set_id(Uuid::new_v4().as_bytes().to_vec());
+        m.set_ledger(proto_LedgerSeed::new());
+        m.set_fingerprint(vec![1, 2, 3]);
+
+        let buf = m.write_to_bytes().unwrap();
+        let act = Seed::try_from(buf);
+        assert!(act.is_err());
+    }
+
+    #[test]
+    fn round_trip_bytes_gcm() {
+        let seed = Seed {
+            id: Uuid::new_v4(),
+            source: SeedSource::Bytes(Encrypted::encrypt_gcm(b"test".to_vec(), "test").unwrap()),
+            label: None,
+            created_at: Utc.timestamp_millis(1592624592679),
+            fingerprint: None,
+        };
+        let buf: Vec<u8> = seed.clone().try_into().unwrap();
+        let act = Seed::try_from(buf).unwrap();
+        assert_eq!(act, seed);
+        match act.source {
+            SeedSource::Bytes(e) => assert_eq!(e.decrypt("test").unwrap(), b"test".to_vec()),
+            _ => panic!("Not bytes"),
+        };
+    }
+
+    #[test]
+    fn round_trip_ledger() {
+        let seed = Seed {
+            id: Uuid::new_v4(),
+            source: SeedSource::Ledger(LedgerSource {
+                fingerprints: vec![
+                    HDPathFingerprint::from_address(
+                        StandardHDPath::from_str("m/44'/60'/0'/0/0").unwrap(),
+                        &EthereumAddress::from_str("0x3eaf0b987b49c4d782ee134fdac1243fd0d82535").unwrap(),
+                    ),
+                    HDPathFingerprint::from_bytes(
+                        StandardHDPath::from_str("m/84'/0'/0'/0/0").unwrap(),
+                        &[0u8; 22],
+                    ),
+                ],
+                device_model: None,
+                master_fingerprint: None,
+            }),
+            label: None,
+            created_at: Utc.timestamp_millis(1592624592679),
+            fingerprint: None,
+        };
+        let buf: Vec<u8> = seed.clone().try_into().unwrap();
+        let act = Seed::try_from(buf).unwrap();
+        assert_eq!(act, seed);
+    }
+
+    #[test]
+    fn round_trip_ledger_device_model() {
+        let seed = Seed {
+            id: Uuid::new_v4(),
+            source: SeedSource::Ledger(LedgerSource {
+                fingerprints: vec![],
+                device_model: Some("Nano X".to_string()),
+                master_fingerprint: None,
+            }),
+            label: None,
+            created_at: Utc.timestamp_millis(1592624592679),
+            fingerprint: None,
+        };
+        let buf: Vec<u8> = seed.clone().try_into().unwrap();
+        let act = Seed::try_from(buf).unwrap();
+        assert_eq!(act, seed);
+        match act.source {
+            SeedSource::Ledger(l) => assert_eq!(l.device_model, Some("Nano X".to_string())),
+            _ => panic!("Not ledger"),
+        }
+    }
+
+    #[test]
+    fn round_trip_ledger_master_fingerprint() {
+        let seed = Seed {
+            id: Uuid::new_v4(),
+            source: SeedSource::Ledger(LedgerSource {
+                fingerprints: vec![],
+                device_model: None,
+                master_fingerprint: Some(Fingerprint::from(&[0x73, 0xc5, 0xda, 0x0a][..])),
+            }),
+            label: None,
+            created_at: Utc.timestamp_millis(1592624592679),
+            fingerprint: None,
+        };
+        let buf: Vec<u8> = seed.clone().try_into().unwrap();
+        let m = parse_from_bytes::<proto_Seed>(buf.as_slice()).unwrap();
+        assert_eq!(m.get_ledger().get_master_fingerprint(), &[0x73, 0xc5, 0xda, 0x0a]);
+        let act = Seed::try_from(buf).unwrap();
+        assert_eq!(act, seed);
+    }
+
+    #[test]
+    fn fail_on_invalid_master_fingerprint() {
+        let mut m = proto_LedgerSeed::new();
+        m.set_master_fingerprint(vec![0x73, 0xc5, 0xda]);
+        assert!(LedgerSource::try_from(&m).is_err());
+    }
 }
@@ -1,10 +1,11 @@
 use crate::{
-    blockchain::chains::Blockchain,
+    blockchain::chains::{Blockchain, BlockchainType},
     convert::error::ConversionError,
     proto::{
         common::FileType as proto_FileType,
-        seed::SeedHD as proto_SeedHD,
+        seed::{SeedHD as proto_SeedHD, SeedHD_oneof_path_type as proto_SeedPathType},
         wallet::{
+            AddressLabel as proto_AddressLabel,
             Reserved as proto_Reserved,
             Wallet as proto_Wallet,
             WalletEntry as proto_WalletEntry,
@@ -12,13 +13,13 @@ use crate::{
         },
     },
     structs::{
-        seed::SeedRef,
+        seed::{SeedPath, SeedRef},
         wallet::{PKType, ReservedPath, Wallet, WalletEntry},
     },
     util::none_if_empty,
 };
 use chrono::{TimeZone, Utc};
-use hdpath::StandardHDPath;
+use hdpath::{AccountHDPath, StandardHDPath};
 use protobuf::{parse_from_bytes, Message};
 use std::{
     cmp,
@@ -39,13 +40,33 @@ impl TryFrom<&proto_WalletEntry> for WalletEntry {
         let key = match &value.pk_type {
             Some(pk_type) => match pk_type {
                 proto_WalletEntryPkType::hd_path(seed) => {
+                    let hd_path = match &seed.path_type {
+                        Some(proto_SeedPathType::path(path)) => {
+                            let path = StandardHDPath::try_from(path).map_err(|_| {
+                                ConversionError::InvalidFieldValue("hd_path".to_string())
+                            })?;
+                            // Bitcoin entries were stored with the path to the first address
+                            // of the account, which is converted to the account itself. The vault
+                            // rewrites them in the new format with the v2 migration
+                            if blockchain.get_type() == BlockchainType::Bitcoin {
+                                SeedPath::Account(AccountHDPath::from(&path))
+                            } else {
+                                SeedPath::Address(path)
+                            }
+                        }
+                        Some(proto_SeedPathType::account(account)) => {
+                            let path = StandardHDPath::try_from(account).map_err(|_| {
+                                ConversionError::InvalidFieldValue("hd_path".to_string())
+                            })?;
+                            SeedPath::Account(AccountHDPath::from(&path))
+                        }
+                        None => return Err(ConversionError::FieldIsEmpty("hd_path".to_string())),
+                    };
                     let seed = SeedRef {
                         seed_id: Uuid::from_slice(seed.get_seed_id()).map_err(|_| {
                             ConversionError::InvalidFieldValue("seed_id".to_string())
                         })?,
-                        hd_path: StandardHDPath::try_from(seed.get_path()).map_err(|_| {
-                            ConversionError::InvalidFieldValue("hd_path".to_string())
-                        })?,
+                        hd_path,
                     };
                     PKType::SeedHd(seed)
                 }
@@ -53,6 +74,7 @@ impl TryFrom<&proto_WalletEntry> for WalletEntry {
                     Uuid::from_slice(pk_id)
                         .map_err(|_| ConversionError::InvalidFieldValue("pk_id".to_string()))?,
                 ),
+                proto_WalletEntryPkType::watch_only(_) => PKType::Watch,
             },
             None => return Err(ConversionError::FieldIsEmpty("pk_type".to_string())),
         };
@@ -60,6 +82,12 @@ impl TryFrom<&proto_WalletEntry> for WalletEntry {
         let receive_disabled = value.get_receive_disabled();
         let label = none_if_empty(value.get_label());
         let created_at = Utc.timestamp_millis(value.get_created_at() as i64);
+        let labels = value
+            .get_address_labels()
+            .iter()
+            .filter(|l| !l.get_label().is_empty())
+            .map(|l| (l.get_index(), l.get_label().to_string()))
+            .collect();
         let result = WalletEntry {
             id,
             blockchain,
@@ -68,6 +96,7 @@ impl TryFrom<&proto_WalletEntry> for WalletEntry {
             receive_disabled,
             label,
             created_at,
+            labels,
         };
         Ok(result)
     }
@@ -90,14 +119,28 @@ impl From<&WalletEntry> for proto_WalletEntry {
             PKType::SeedHd(seed_ref) => {
                 let mut seed_hd = proto_SeedHD::new();
                 seed_hd.set_seed_id(seed_ref.seed_id.as_bytes().to_vec());
-                seed_hd.set_path(seed_ref.hd_path.clone().into());
+                match &seed_ref.hd_path {
+                    SeedPath::Address(hd_path) => seed_hd.set_path(hd_path.clone().into()),
+                    SeedPath::Account(account) => seed_hd.set_account(account.clone().into()),
+                }
                 result.set_hd_path(seed_hd);
             }
             PKType::PrivateKeyRef(addr) => {
                 result.set_pk_id(addr.as_bytes().to_vec());
             }
+            PKType::Watch => {
+                result.set_watch_only(true);
+            }
         }
         result.set_created_at(value.created_at.timestamp_millis() as u64);
+        let mut indexes: Vec<&u32> = value.labels.keys().collect();
+        indexes.sort();
+        for index in indexes {
+            let mut label = proto_AddressLabel::new();
+            label.set_index(*index);
+            label.set_label(value.labels[index].clone());
+            result.address_labels.push(label);
+        }
         result
     }
 }
@@ -135,6 +178,7 @@ impl TryFrom<&[u8]> for Wallet {
             .timestamp_millis_opt(m.get_created_at() as i64)
             .single()
             .unwrap_or_else(|| Utc.timestamp_millis(0));
+        let order = m.get_order();
         let result = Wallet {
             id: Uuid::from_slice(m.get_id())
                 .map_err(|_| ConversionError::InvalidFieldValue("id".to_string()))?,
@@ -143,6 +187,7 @@ impl TryFrom<&[u8]> for Wallet {
             entry_seq: m.get_entry_seq() as usize,
             reserved: m.try_into()?,
             created_at,
+            order,
         };
         Ok(result)
     }
@@ -204,6 +249,7 @@ impl TryFrom<Wallet> for Vec<u8> {
             result.hd_accounts.push(r_proto);
         }
         result.set_created_at(value.created_at.timestamp_millis() as u64);
+        result.set_order(value.order);
 
         result
             .write_to_bytes()
@@ -219,7 +265,11 @@ mod tests {
         convert::error::ConversionError,
         proto::{
             seed::{HDPath as proto_HDPath, SeedHD as proto_SeedHD},
-            wallet::{Wallet as proto_Wallet, WalletEntry as proto_WalletEntry},
+            wallet::{
+                AddressLabel as proto_AddressLabel,
+                Wallet as proto_Wallet,
+                WalletEntry as proto_WalletEntry,
+            },
         },
         structs::{
             book::AddressRef,
@@ -228,9 +278,10 @@ mod tests {
         },
     };
     use chrono::{TimeZone, Utc};
-    use hdpath::StandardHDPath;
+    use hdpath::{AccountHDPath, StandardHDPath};
     use protobuf::{parse_from_bytes, Message, ProtobufEnum};
     use std::{
+        collections::HashMap,
         convert::{TryFrom, TryInto},
         str::FromStr,
     };
@@ -320,7 +371,7 @@ mod tests {
                         XPub::from_str("zpub6rxn6spLExVKZkzwQeytdBxDAuZk4KsdchwgHP9Ffi91CtvsnsigkP7ffGjn6KMsNyqcuwwh2DKTBVUrTidWJyrEUbctLKhrK3AT7Kyw4N8").unwrap()
                     ),
                 ),
-                key: PKType::SeedHd(SeedRef { seed_id, hd_path: StandardHDPath::try_from("m/84'/0'/1'/0/0").unwrap() }),
+                key: PKType::SeedHd(SeedRef { seed_id, hd_path: AccountHDPath::from_str("m/84'/0'/1'").unwrap().into() }),
                 created_at: Utc.timestamp_millis(0),
                 ..WalletEntry::default()
             }],
@@ -383,7 +434,7 @@ mod tests {
                 )),
                 key: PKType::SeedHd(SeedRef {
                     seed_id: Uuid::from_str("351ef1f4-f1dd-4acb-9d8b-d7eec02b1da2").unwrap(),
-                    hd_path: StandardHDPath::try_from("m/44'/60'/0'/0/0").unwrap(),
+                    hd_path: StandardHDPath::try_from("m/44'/60'/0'/0/0").unwrap().into(),
                 }),
                 created_at: Utc.timestamp_millis(0),
                 ..WalletEntry::default()
@@ -417,7 +468,7 @@ mod tests {
                 )),
                 key: PKType::SeedHd(SeedRef {
                     seed_id: Uuid::from_str("351ef1f4-f1dd-4acb-9d8b-d7eec02b1da2").unwrap(),
-                    hd_path: StandardHDPath::try_from("m/44'/60'/0'/0/1").unwrap(),
+                    hd_path: StandardHDPath::try_from("m/44'/60'/0'/0/1").unwrap().into(),
                 }),
                 receive_disabled: true,
                 created_at: Utc.timestamp_millis(0),
@@ -476,7 +527,7 @@ mod tests {
                     blockchain: Blockchain::Ethereum,
                     key: PKType::SeedHd(SeedRef {
                         seed_id: Uuid::from_str("126d8ad4-d5a3-4b42-ba31-365cb5c34b5f").unwrap(),
-                        hd_path: StandardHDPath::try_from("m/44'/60'/0'/0/1").unwrap(),
+                        hd_path: StandardHDPath::try_from("m/44'/60'/0'/0/1").unwrap().into(),
                     }),
                     created_at: Utc.timestamp_millis(0),
                     ..WalletEntry::default()
@@ -486,7 +537,7 @@ mod tests {
                     blockchain: Blockchain::Ethereum,
                     key: PKType::SeedHd(SeedRef {
                         seed_id: Uuid::from_str("ad22b0da-12ae-4433-960a-755ad3a2558c").unwrap(),
-                        hd_path: StandardHDPath::try_from("m/44'/60'/1'/0/1").unwrap(),
+                        hd_path: StandardHDPath::try_from("m/44'/60'/1'/0/1").unwrap().into(),
                     }),
                     created_at: Utc.timestamp_millis(0),
                     ..WalletEntry::default()
@@ -544,6 +595,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn migrate_bitcoin_entry_to_account() {
+        let seed_id = Uuid::new_v4();
+        let mut pk = proto_SeedHD::default();
+        pk.set_seed_id(seed_id.as_bytes().to_vec());
+        let mut hdpath = proto_HDPath::new();
+        hdpath.set_purpose(84);
+        hdpath.set_coin(0);
+        hdpath.set_account(1);
+        hdpath.set_change(0);
+        hdpath.set_index(0);
+        pk.set_path(hdpath.clone());
+
+        let mut entry = proto_WalletEntry::default();
+        entry.set_id(1);
+        entry.set_blockchain_id(Blockchain::Bitcoin as u32);
+        entry.set_hd_path(pk.clone());
+
+        let act = WalletEntry::try_from(&entry).unwrap();
+        assert_eq!(
+            PKType::SeedHd(SeedRef {
+                seed_id,
+                hd_path: AccountHDPath::from_str("m/84'/0'/1'").unwrap().into(),
+            }),
+            act.key
+        );
+        let written = proto_WalletEntry::from(&act);
+        assert!(written.get_hd_path().has_account());
+        assert!(!written.get_hd_path().has_path());
+
+        // ethereum entries keep the full path
+        let mut entry = proto_WalletEntry::default();
+        entry.set_id(1);
+        entry.set_blockchain_id(Blockchain::Ethereum as u32);
+        hdpath.set_purpose(44);
+        hdpath.set_coin(60);
+        hdpath.set_index(5);
+        pk.set_path(hdpath);
+        entry.set_hd_path(pk);
+
+        let act = WalletEntry::try_from(&entry).unwrap();
+        assert_eq!(
+            PKType::SeedHd(SeedRef {
+                seed_id,
+                hd_path: StandardHDPath::try_from("m/44'/60'/1'/0/5").unwrap().into(),
+            }),
+            act.key
+        );
+    }
+
+    #[test]
+    fn write_and_read_watch_entry() {
+        let wallet = Wallet {
+            id: Uuid::new_v4(),
+            entries: vec![WalletEntry {
+                id: 0,
+                blockchain: Blockchain::Bitcoin,
+                address: Some(
+                    AddressRef::ExtendedPub(
+                        XPub::from_str("zpub6rxn6spLExVKZkzwQeytdBxDAuZk4KsdchwgHP9Ffi91CtvsnsigkP7ffGjn6KMsNyqcuwwh2DKTBVUrTidWJyrEUbctLKhrK3AT7Kyw4N8").unwrap()
+                    ),
+                ),
+                key: PKType::Watch,
+                created_at: Utc.timestamp_millis(0),
+                ..WalletEntry::default()
+            }],
+            entry_seq: 1,
+            created_at: Utc.timestamp_millis(0),
+            ..Wallet::default()
+        };
+
+        let b: Vec<u8> = wallet.clone().try_into().unwrap();
+        let m = parse_from_bytes::<proto_Wallet>(b.as_slice()).unwrap();
+        assert!(m.get_entries()[0].get_watch_only());
+        let act = Wallet::try_from(b).unwrap();
+        assert_eq!(wallet, act);
+    }
+
+    #[test]
+    fn fail_on_entry_without_pk_type() {
+        let mut entry = proto_WalletEntry::default();
+        entry.set_id(1);
+        entry.set_blockchain_id(Blockchain::Bitcoin as u32);
+
+        let act = WalletEntry::try_from(&entry);
+        assert_eq!(Err(ConversionError::FieldIsEmpty("pk_type".to_string())), act);
+    }
+
     #[test]
     fn write_and_read_label() {
         let wallet = Wallet {
@@ -584,6 +723,49 @@ mod tests {
         assert_eq!(act.created_at.to_rfc3339(), "2020-06-20T03:40:07.736+00:00");
     }
 
+    #[test]
+    fn write_and_read_address_labels() {
+        let mut entry = WalletEntry {
+            id: 0,
+            blockchain: Blockchain::Bitcoin,
+            key: PKType::Watch,
+            created_at: Utc.timestamp_millis(0),
+            ..WalletEntry::default()
+        };
+        entry.set_address_label(2, Some("savings".to_string()));
+        let wallet = Wallet {
+            entries: vec![entry],
+            entry_seq: 1,
+            created_at: Utc.timestamp_millis(0),
+            ..Wallet::default()
+        };
+
+        let b: Vec<u8> = wallet.clone().try_into().unwrap();
+        let m = parse_from_bytes::<proto_Wallet>(b.as_slice()).unwrap();
+        let labels = m.get_entries()[0].get_address_labels();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].get_index(), 2);
+        assert_eq!(labels[0].get_label(), "savings");
+
+        let act = Wallet::try_from(b).unwrap();
+        assert_eq!(act, wallet);
+        assert_eq!(act.entries[0].get_address_label(2), Some("savings"));
+        assert_eq!(act.entries[0].get_address_label(0), None);
+    }
+
+    #[test]
+    fn ignore_empty_address_label() {
+        let mut m = proto_WalletEntry::new();
+        m.set_blockchain_id(Blockchain::Bitcoin as u32);
+        m.set_watch_only(true);
+        let mut label = proto_AddressLabel::new();
+        label.set_index(1);
+        m.mut_address_labels().push(label);
+
+        let act = WalletEntry::try_from(&m).unwrap();
+        assert!(act.labels.is_empty());
+    }
+
     #[test]
     fn ignore_big_created_at() {
         let mut m = proto_Wallet::new();
@@ -594,4 +776,55 @@ mod tests {
         let act = Wallet::try_from(buf).unwrap();
         assert_eq!(act.created_at.timestamp_millis(), 0);
     }
+
+    #[test]
+    fn round_trip_all_fields() {
+        let seed_id = Uuid::new_v4();
+        let wallet = Wallet {
+            id: Uuid::new_v4(),
+            label: Some("Test wallet".to_string()),
+            entries: vec![
+                WalletEntry {
+                    id: 0,
+                    blockchain: Blockchain::Ethereum,
+                    address: Some(AddressRef::EthereumAddress(
+                        EthereumAddress::from_str("0x6412c428fc02902d137b60dc0bd0f6cd1255ea99").unwrap(),
+                    )),
+                    key: PKType::PrivateKeyRef(Uuid::new_v4()),
+                    receive_disabled: true,
+                    label: Some("Entry 0".to_string()),
+                    created_at: Utc.timestamp_millis(1592624592679),
+                    labels: HashMap::new(),
+                },
+                WalletEntry {
+                    id: 2,
+                    blockchain: Blockchain::Bitcoin,
+                    address: Some(AddressRef::ExtendedPub(
+                        XPub::from_str("zpub6rxn6spLExVKZkzwQeytdBxDAuZk4KsdchwgHP9Ffi91CtvsnsigkP7ffGjn6KMsNyqcuwwh2DKTBVUrTidWJyrEUbctLKhrK3AT7Kyw4N8").unwrap()
+                    )),
+                    key: PKType::SeedHd(SeedRef {
+                        seed_id,
+                        hd_path: AccountHDPath::from_str("m/84'/0'/1'").unwrap().into(),
+                    }),
+                    receive_disabled: false,
+                    label: None,
+                    created_at: Utc.timestamp_millis(1592624592680),
+                    labels: vec![(0, "Main".to_string()), (7, "Savings".to_string())]
+                        .into_iter()
+                        .collect(),
+                },
+            ],
+            entry_seq: 5,
+            reserved: vec![
+                ReservedPath { seed_id, account_id: 1 },
+                ReservedPath { seed_id: Uuid::new_v4(), account_id: 0 },
+            ],
+            created_at: Utc.timestamp_millis(1592624592000),
+            order: 3,
+        };
+
+        let b: Vec<u8> = wallet.clone().try_into().unwrap();
+        let act = Wallet::try_from(b).unwrap();
+        assert_eq!(act, wallet);
+    }
 }
@@ -1,6 +1,7 @@
 pub mod encrypted;
 pub mod error;
 pub mod kdf;
+pub mod password;
 pub mod pk;
 pub mod seed;
 pub mod sign;
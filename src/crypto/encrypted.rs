@@ -1,14 +1,20 @@
 use crate::{
-    crypto::{error::CryptoError, kdf::KeyDerive},
+    crypto::{error::CryptoError, kdf::KeyDerive, password::PasswordPolicy},
     keccak256,
-    structs::crypto::{Aes128CtrCipher, Cipher, Encrypted, Kdf, MacType, ScryptKdf},
+    util::constant_time_eq,
+    structs::crypto::{Aes128CtrCipher, Aes256GcmCipher, Cipher, Encrypted, Kdf, MacType, ScryptKdf},
 };
 use aes_ctr::{
     stream_cipher::{generic_array::GenericArray, NewStreamCipher, SyncStreamCipher},
     Aes128Ctr,
 };
+use aes_gcm::{
+    aead::{Aead, NewAead},
+    Aes256Gcm,
+};
 use rand::{prelude::Rng, thread_rng};
 use std::convert::TryFrom;
+use zeroize::{Zeroize, Zeroizing};
 
 /// Encrypt given text with provided key and initial vector
 fn encrypt_aes128(data: &[u8], key: &[u8], iv: &[u8]) -> Vec<u8> {
@@ -29,32 +35,116 @@ fn decrypt_aes128(data: &[u8], key: &[u8], iv: &[u8]) -> Vec<u8> {
     buf
 }
 
+/// Encrypt given text with AES-256-GCM, the result is the encrypted text followed by the auth tag
+fn encrypt_aes256gcm(data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    if key.len() != 32 || iv.len() != 12 {
+        return Err(CryptoError::InvalidKey);
+    }
+    let cipher = Aes256Gcm::new(GenericArray::from_slice(key));
+    cipher
+        .encrypt(GenericArray::from_slice(iv), data)
+        .map_err(|_| CryptoError::InvalidParams)
+}
+
+/// Decrypt and authenticate a text encrypted with AES-256-GCM. Returns `CryptoError::WrongKey` if
+/// the key is wrong or the data was modified, as it's impossible to distinguish between them
+fn decrypt_aes256gcm(data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    if key.len() != 32 || iv.len() != 12 {
+        return Err(CryptoError::InvalidKey);
+    }
+    let cipher = Aes256Gcm::new(GenericArray::from_slice(key));
+    cipher
+        .decrypt(GenericArray::from_slice(iv), data)
+        .map_err(|_| CryptoError::WrongKey)
+}
+
+/// New Scrypt KDF with a random salt
+fn random_kdf() -> Result<ScryptKdf, CryptoError> {
+    let mut salt: [u8; 32] = [0; 32];
+    thread_rng()
+        .try_fill(&mut salt)
+        .map_err(|_| CryptoError::NoEntropy)?;
+    Ok(ScryptKdf::create_with_salt(salt))
+}
+
+/// Random IV (nonce) of the specified size
+fn random_iv(size: usize) -> Result<Vec<u8>, CryptoError> {
+    let mut iv = vec![0u8; size];
+    thread_rng()
+        .try_fill(iv.as_mut_slice())
+        .map_err(|_| CryptoError::NoEntropy)?;
+    Ok(iv)
+}
+
+/// Derive a key to encrypt a new value with the password
+fn derive_new_key(kdf: &Kdf, password: &str) -> Result<Zeroizing<Vec<u8>>, CryptoError> {
+    // for security reasons shouldn't allow empty passwords
+    if password.len() == 0 {
+        return Err(CryptoError::InvalidKey);
+    }
+    Ok(Zeroizing::new(kdf.derive(password)?))
+}
+
 impl Encrypted {
+    /// Encrypt with AES-128-CTR and Scrypt KDF. That's what the vault uses for all keys and seeds it
+    /// creates, while AES-256-GCM is only read from existing data or must be requested explicitly
+    /// with `encrypt_gcm`
     pub fn encrypt(msg: Vec<u8>, password: &str) -> Result<Encrypted, CryptoError> {
-        // for security reasons shouldn't allow empty passwords
-        if password.len() == 0 {
-            return Err(CryptoError::InvalidKey);
+        Encrypted::encrypt_with_kdf(msg, password, Kdf::Scrypt(random_kdf()?))
+    }
+
+    /// Encrypt same as `encrypt`, but only if the password satisfies the policy. Returns
+    /// `CryptoError::WeakPassword` otherwise
+    pub fn encrypt_with_policy(
+        mut msg: Vec<u8>,
+        password: &str,
+        policy: &PasswordPolicy,
+    ) -> Result<Encrypted, CryptoError> {
+        if let Err(e) = policy.check(password) {
+            msg.zeroize();
+            return Err(e);
         }
-        let mut salt: [u8; 32] = [0; 32];
-        thread_rng()
-            .try_fill(&mut salt)
-            .map_err(|_| CryptoError::NoEntropy)?;
-        let kdf = ScryptKdf::create_with_salt(salt);
-        let key = kdf.derive(password)?;
-
-        let mut iv: [u8; 16] = [0; 16];
-        thread_rng()
-            .try_fill(&mut iv)
-            .map_err(|_| CryptoError::NoEntropy)?;
-        let key = Web3Key::try_from(key)?;
+        Encrypted::encrypt(msg, password)
+    }
+
+    /// Encrypt with AES-128-CTR using the provided KDF, which must already have a random salt and
+    /// produce a 32 byte key
+    pub fn encrypt_with_kdf(
+        mut msg: Vec<u8>,
+        password: &str,
+        kdf: Kdf,
+    ) -> Result<Encrypted, CryptoError> {
+        let key = derive_new_key(&kdf, password)?;
+        let iv = random_iv(16)?;
+        let key = Web3Key::try_from(key.to_vec())?;
         let encrypted = encrypt_aes128(msg.as_slice(), &key.message_key, &iv);
+        msg.zeroize();
         let result = Encrypted {
             cipher: Cipher::Aes128Ctr(Aes128CtrCipher {
                 encrypted: encrypted.clone(),
-                iv: iv.to_vec(),
+                iv,
                 mac: MacType::sign_web3(&key.mac_key.to_vec(), encrypted)?,
             }),
-            kdf: Kdf::Scrypt(kdf),
+            kdf,
+        };
+        Ok(result)
+    }
+
+    /// Encrypt with AES-256-GCM, which detects any modification of the encrypted data by its
+    /// auth tag. Note that it's not supported by Web3 Secret Storage, so a key encrypted this way
+    /// cannot be exported as JSON
+    pub fn encrypt_gcm(mut msg: Vec<u8>, password: &str) -> Result<Encrypted, CryptoError> {
+        let kdf = Kdf::Scrypt(random_kdf()?);
+        let key = derive_new_key(&kdf, password)?;
+        let iv = random_iv(12)?;
+        let encrypted = encrypt_aes256gcm(msg.as_slice(), key.as_slice(), &iv);
+        msg.zeroize();
+        let result = Encrypted {
+            cipher: Cipher::Aes256Gcm(Aes256GcmCipher {
+                encrypted: encrypted?,
+                iv,
+            }),
+            kdf,
         };
         Ok(result)
     }
@@ -74,19 +164,34 @@ struct Web3Key {
 impl TryFrom<Vec<u8>> for Web3Key {
     type Error = CryptoError;
 
-    fn try_from(key: Vec<u8>) -> Result<Self, Self::Error> {
+    fn try_from(mut key: Vec<u8>) -> Result<Self, Self::Error> {
         if key.len() != 32 {
+            key.zeroize();
             return Err(CryptoError::InvalidKey);
         }
         // left part of the key is Msg key, right part is Mac key
-        let mut message_key: [u8; 16] = [0; 16];
-        let mut mac_key: [u8; 16] = [0; 16];
-        message_key.copy_from_slice(&key[0..16]);
-        mac_key.copy_from_slice(&key[16..]);
-        Ok(Web3Key {
-            message_key,
-            mac_key,
-        })
+        let mut result = Web3Key {
+            message_key: [0; 16],
+            mac_key: [0; 16],
+        };
+        result.message_key.copy_from_slice(&key[0..16]);
+        result.mac_key.copy_from_slice(&key[16..]);
+        key.zeroize();
+        Ok(result)
+    }
+}
+
+impl Zeroize for Web3Key {
+    fn zeroize(&mut self) {
+        self.message_key.zeroize();
+        self.mac_key.zeroize();
+    }
+}
+
+/// Derived key should not stay in memory after it was used
+impl Drop for Web3Key {
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
 
@@ -106,6 +211,10 @@ impl Cipher {
                     Err(CryptoError::WrongKey)
                 }
             }
+            Cipher::Aes256Gcm(conf) => {
+                let key = Zeroizing::new(key);
+                decrypt_aes256gcm(conf.encrypted.as_slice(), key.as_slice(), conf.iv.as_slice())
+            }
         }
     }
 }
@@ -121,7 +230,7 @@ impl MacType {
                 msg.extend_from_slice(key);
                 msg.extend_from_slice(message.as_slice());
                 let hash = keccak256(msg.as_slice());
-                hash == mac.as_slice()
+                constant_time_eq(&hash, mac.as_slice())
             }
         }
     }
@@ -146,10 +255,12 @@ mod tests {
         crypto::{
             encrypted::{decrypt_aes128, encrypt_aes128, Web3Key},
             error::CryptoError,
+            password::PasswordPolicy,
         },
         structs::crypto::{Aes128CtrCipher, Cipher, Encrypted, MacType},
     };
     use std::convert::TryFrom;
+    use zeroize::Zeroize;
 
     #[test]
     fn verify_mac_1() {
@@ -337,5 +448,82 @@ mod tests {
         );
         assert!(act.is_err());
         assert_eq!(CryptoError::InvalidKey, act.err().unwrap());
+
+        let act = Encrypted::encrypt_gcm(b"test".to_vec(), "");
+        assert_eq!(Err(CryptoError::InvalidKey), act);
+    }
+
+    #[test]
+    fn encrypt_with_policy() {
+        let policy = PasswordPolicy::default();
+        let act = Encrypted::encrypt_with_policy(
+            hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                .unwrap(),
+            "test",
+            &policy,
+        );
+        assert_eq!(Err(CryptoError::WeakPassword), act);
+
+        let encrypted = Encrypted::encrypt_with_policy(
+            hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                .unwrap(),
+            "Correct-Horse-Battery-9",
+            &policy,
+        ).unwrap();
+        assert_eq!(
+            "fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd",
+            hex::encode(encrypted.decrypt("Correct-Horse-Battery-9").unwrap())
+        );
+    }
+
+    #[test]
+    fn clears_web3_key() {
+        let mut key = Web3Key::try_from(
+            hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                .unwrap(),
+        ).unwrap();
+        assert_ne!(key.message_key, [0u8; 16]);
+        assert_ne!(key.mac_key, [0u8; 16]);
+        // same as called on drop
+        key.zeroize();
+        assert_eq!(key.message_key, [0u8; 16]);
+        assert_eq!(key.mac_key, [0u8; 16]);
+    }
+
+    #[test]
+    fn encrypt_and_decrypt_gcm() {
+        let encrypted = Encrypted::encrypt_gcm(
+            hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                .unwrap(),
+            "test",
+        ).unwrap();
+        assert!(encrypted.get_separate_mac().is_none());
+        assert!(encrypted.get_mac().is_empty());
+        assert_eq!(12, encrypted.get_iv().len());
+        // message + 16 bytes of the tag
+        assert_eq!(48, encrypted.get_message().len());
+
+        let decrypted = encrypted.decrypt("test").unwrap();
+        assert_eq!(
+            "fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd",
+            hex::encode(decrypted)
+        );
+        assert_eq!(Err(CryptoError::WrongKey), encrypted.decrypt("test2"));
+    }
+
+    #[test]
+    fn deny_modified_gcm() {
+        let encrypted = Encrypted::encrypt_gcm(
+            hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                .unwrap(),
+            "test",
+        ).unwrap();
+        let mut modified = encrypted.clone();
+        match &mut modified.cipher {
+            Cipher::Aes256Gcm(v) => v.encrypted[5] ^= 0x01,
+            _ => panic!("not gcm"),
+        }
+        assert_eq!(Err(CryptoError::WrongKey), modified.decrypt("test"));
+        assert!(encrypted.decrypt("test").is_ok());
     }
 }
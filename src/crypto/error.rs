@@ -7,6 +7,8 @@ pub enum CryptoError {
     WrongKey,
     UnsupportedSource(String),
     NoEntropy,
+    /// Password doesn't satisfy the password policy
+    WeakPassword,
 }
 
 impl From<scrypt::errors::InvalidParams> for CryptoError {
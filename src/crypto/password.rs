@@ -0,0 +1,108 @@
+//! # Password strength policy, to reject weak passwords used to encrypt seeds and keys
+
+use crate::crypto::error::CryptoError;
+
+/// Requirements for a password used to encrypt data in the vault. It's opt-in, i.e. by default
+/// the vault accepts any non-empty password, and the policy is enforced only for a vault set up
+/// with `VaultStorage::with_password_policy`, or when encrypted with `Encrypted::encrypt_with_policy`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    /// Minimal length in characters
+    pub min_length: usize,
+    /// Minimal estimated entropy in bits
+    pub min_entropy: u32,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            min_length: 8,
+            min_entropy: 40,
+        }
+    }
+}
+
+/// Estimate entropy of a password in bits, based on its length and character classes used in it.
+/// It's a rough upper bound which doesn't account for dictionary words, but it's enough to reject
+/// obviously weak passwords.
+pub fn estimate_entropy(password: &str) -> f64 {
+    let mut lower = false;
+    let mut upper = false;
+    let mut digit = false;
+    let mut symbol = false;
+    let mut other = false;
+    for c in password.chars() {
+        if c.is_ascii_lowercase() {
+            lower = true
+        } else if c.is_ascii_uppercase() {
+            upper = true
+        } else if c.is_ascii_digit() {
+            digit = true
+        } else if c.is_ascii() {
+            symbol = true
+        } else {
+            other = true
+        }
+    }
+    let pool = [(lower, 26), (upper, 26), (digit, 10), (symbol, 33), (other, 100)]
+        .iter()
+        .filter(|(used, _)| *used)
+        .map(|(_, size)| *size)
+        .sum::<u32>();
+    if pool == 0 {
+        return 0.0;
+    }
+    password.chars().count() as f64 * (pool as f64).log2()
+}
+
+impl PasswordPolicy {
+    /// Check the password against the policy, returns `CryptoError::WeakPassword` if it doesn't
+    /// satisfy it
+    pub fn check(&self, password: &str) -> Result<(), CryptoError> {
+        if password.chars().count() < self.min_length {
+            return Err(CryptoError::WeakPassword);
+        }
+        if estimate_entropy(password) < self.min_entropy as f64 {
+            return Err(CryptoError::WeakPassword);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_strong_password() {
+        let policy = PasswordPolicy::default();
+        assert_eq!(Ok(()), policy.check("Correct-Horse-Battery-9"));
+        assert_eq!(Ok(()), policy.check("nkd83jfqpz0a"));
+    }
+
+    #[test]
+    fn rejects_weak_password() {
+        let policy = PasswordPolicy::default();
+        assert_eq!(Err(CryptoError::WeakPassword), policy.check(""));
+        assert_eq!(Err(CryptoError::WeakPassword), policy.check("test"));
+        assert_eq!(Err(CryptoError::WeakPassword), policy.check("password"));
+        assert_eq!(Err(CryptoError::WeakPassword), policy.check("12345678901"));
+    }
+
+    #[test]
+    fn uses_configured_threshold() {
+        let policy = PasswordPolicy {
+            min_length: 4,
+            min_entropy: 0,
+        };
+        assert_eq!(Ok(()), policy.check("test"));
+        assert_eq!(Err(CryptoError::WeakPassword), policy.check("tes"));
+    }
+
+    #[test]
+    fn estimates_entropy() {
+        assert_eq!(0.0, estimate_entropy(""));
+        assert!((estimate_entropy("abcd") - 4.0 * 26f64.log2()).abs() < 0.001);
+        assert!((estimate_entropy("aB1!") - 4.0 * 95f64.log2()).abs() < 0.001);
+    }
+}
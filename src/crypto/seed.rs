@@ -1,23 +1,121 @@
 use crate::{
+    crypto::{error::CryptoError, password::PasswordPolicy},
     mnemonic::Mnemonic,
+    sign::bitcoin::DEFAULT_SECP256K1,
+    storage::error::VaultError,
     structs::{
         crypto::Encrypted,
-        seed::{Seed, SeedSource},
+        seed::{Bytes256, Seed, SeedFingerprint, SeedSource},
     },
 };
+use bitcoin::{
+    network::constants::Network,
+    util::bip32::{ExtendedPrivKey, ExtendedPubKey},
+};
 use chrono::Utc;
+use rand::{prelude::Rng, thread_rng};
+use sha2::Digest;
+use std::convert::TryFrom;
 use uuid::Uuid;
 
+impl SeedFingerprint {
+    /// Create fingerprint of the seed bytes with a new random salt
+    pub fn create(seed: &[u8]) -> Result<SeedFingerprint, VaultError> {
+        let mut salt: [u8; 16] = [0; 16];
+        thread_rng()
+            .try_fill(&mut salt)
+            .map_err(|_| CryptoError::NoEntropy)?;
+        SeedFingerprint::with_salt(salt, seed)
+    }
+
+    /// Create fingerprint of the seed bytes with the specified salt
+    pub fn with_salt(salt: [u8; 16], seed: &[u8]) -> Result<SeedFingerprint, VaultError> {
+        let master = ExtendedPrivKey::new_master(Network::Bitcoin, seed)
+            .map_err(|_| VaultError::InvalidPrivateKey)?;
+        let pubkey = ExtendedPubKey::from_private(&DEFAULT_SECP256K1, &master).public_key;
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(&salt);
+        hasher.update(pubkey.to_bytes());
+        let hash = Bytes256::try_from(hasher.finalize().as_slice())
+            .map_err(|_| VaultError::UnrecognizedError)?;
+        Ok(SeedFingerprint { salt, hash })
+    }
+
+    /// Check if the fingerprint was made for the seed bytes
+    pub fn matches(&self, seed: &[u8]) -> bool {
+        match SeedFingerprint::with_salt(self.salt, seed) {
+            Ok(f) => f.hash == self.hash,
+            Err(_) => false,
+        }
+    }
+}
+
 impl Seed {
     pub fn generate(seed_password: Option<String>, save_password: &str) -> Result<Seed, ()> {
         let mnemonic = Mnemonic::default();
         let seed = mnemonic.seed(seed_password);
+        Seed::from_bytes(seed, save_password).map_err(|_| ())
+    }
+
+    /// Create a new seed from the raw seed bytes, encrypted with `save_password`
+    pub fn from_bytes(seed: Vec<u8>, save_password: &str) -> Result<Seed, VaultError> {
+        let fingerprint = SeedFingerprint::create(seed.as_slice())?;
         let result = Seed {
             id: Uuid::new_v4(),
-            source: SeedSource::Bytes(Encrypted::encrypt(seed, save_password).map_err(|_| ())?),
+            source: SeedSource::Bytes(Encrypted::encrypt(seed, save_password)?),
             label: None,
             created_at: Utc::now(),
+            fingerprint: Some(fingerprint),
         };
         Ok(result)
     }
+
+    /// Generate a new seed, same as `generate`, but first checks that `save_password`
+    /// satisfies the policy. Returns `VaultError::WeakPassword` if it doesn't
+    pub fn generate_with_policy(
+        seed_password: Option<String>,
+        save_password: &str,
+        policy: &PasswordPolicy,
+    ) -> Result<Seed, VaultError> {
+        policy.check(save_password)?;
+        let mnemonic = Mnemonic::default();
+        let seed = mnemonic.seed(seed_password);
+        Seed::from_bytes(seed, save_password)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_with_strong_password() {
+        let seed = Seed::generate_with_policy(None, "Correct-Horse-Battery-9", &PasswordPolicy::default());
+        assert!(seed.is_ok());
+    }
+
+    #[test]
+    fn fingerprint_matches_same_seed() {
+        let seed = hex::decode("7a3b09d0e2b8ff7fa8e8a8a5a5b5b73c8a1c3f0b1d2a4e6f8c0e2f4a6b8d0f1e").unwrap();
+        let fingerprint = SeedFingerprint::create(seed.as_slice()).unwrap();
+        assert!(fingerprint.matches(seed.as_slice()));
+
+        let other = hex::decode("6a3b09d0e2b8ff7fa8e8a8a5a5b5b73c8a1c3f0b1d2a4e6f8c0e2f4a6b8d0f1e").unwrap();
+        assert!(!fingerprint.matches(other.as_slice()));
+    }
+
+    #[test]
+    fn fingerprint_uses_random_salt() {
+        let seed = hex::decode("7a3b09d0e2b8ff7fa8e8a8a5a5b5b73c8a1c3f0b1d2a4e6f8c0e2f4a6b8d0f1e").unwrap();
+        let f1 = SeedFingerprint::create(seed.as_slice()).unwrap();
+        let f2 = SeedFingerprint::create(seed.as_slice()).unwrap();
+        assert_ne!(f1.salt, f2.salt);
+        assert_ne!(f1.hash, f2.hash);
+    }
+
+    #[test]
+    fn doesnt_generate_with_weak_password() {
+        let seed = Seed::generate_with_policy(None, "test", &PasswordPolicy::default());
+        assert_eq!(Err(VaultError::WeakPassword), seed);
+    }
 }
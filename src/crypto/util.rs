@@ -29,6 +29,11 @@ pub fn keccak256(data: &[u8]) -> [u8; KECCAK256_BYTES] {
     out
 }
 
+/// Calculate Keccak-256 crypto hash, and return it as a 0x-prefixed hex string
+pub fn keccak256_hex(data: &[u8]) -> String {
+    format!("0x{}", hex::encode(keccak256(data)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,6 +55,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_calculate_empty_keccak256_hex() {
+        assert_eq!(
+            keccak256_hex(b""),
+            "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470".to_string()
+        );
+    }
+
+    #[test]
+    fn should_calculate_small_keccak256_hex() {
+        assert_eq!(
+            keccak256_hex(b"emerald-rs"),
+            "0xf5ab12ff7b15bb4a5cd3d36a41bdbc8e54c180f7558cc4f8cd40acabda02dd84".to_string()
+        );
+    }
+
     #[test]
     fn should_calculate_big_keccak256() {
         assert_eq!(
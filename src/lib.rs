@@ -32,6 +32,7 @@ extern crate serde_derive;
 extern crate enum_display_derive;
 
 extern crate aes_ctr;
+extern crate aes_gcm;
 extern crate bitcoin;
 extern crate byteorder;
 extern crate chrono;
@@ -55,6 +56,8 @@ extern crate sha2;
 extern crate sha3;
 extern crate time;
 extern crate uuid;
+extern crate zeroize;
+extern crate zip;
 #[macro_use]
 extern crate byte_array_struct;
 extern crate emerald_hwkey;
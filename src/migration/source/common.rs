@@ -104,9 +104,14 @@ pub fn add_to_vault(
                     };
                     let seed = Seed {
                         id: Uuid::new_v4(),
-                        source: SeedSource::Ledger(LedgerSource { fingerprints }),
+                        source: SeedSource::Ledger(LedgerSource {
+                            fingerprints,
+                            device_model: None,
+                            master_fingerprint: None,
+                        }),
                         label: None,
                         created_at: Utc::now(),
+                        fingerprint: None,
                     };
                     let id = seed.id.clone();
                     seeds
@@ -123,7 +128,8 @@ pub fn add_to_vault(
                 key: PKType::SeedHd(SeedRef {
                     seed_id,
                     hd_path: StandardHDPath::try_from(data.hd_path.clone().as_str())
-                        .map_err(|_| "Unsupported HDPath")?,
+                        .map_err(|_| "Unsupported HDPath")?
+                        .into(),
                 }),
                 ..WalletEntry::default()
             }
@@ -26,12 +26,16 @@ pub use self::{
     error::Error,
     language::{Language, BIP39_ENGLISH_WORDLIST},
 };
+use crate::util::constant_time_eq;
 use hmac::Hmac;
 use num::{bigint::BigUint, FromPrimitive, ToPrimitive};
 use pbkdf2::pbkdf2;
-use rand::{distributions::Standard, rngs::OsRng, Rng};
+use rand::{distributions::Standard, rngs::OsRng, seq::index, thread_rng, Rng};
 use sha2::{Digest, Sha512};
-use std::ops::{BitAnd, Shr};
+use std::{
+    cmp::min,
+    ops::{BitAnd, Shr},
+};
 
 /// Count of iterations for `pbkdf2`
 const PBKDF2_ROUNDS: u32 = 2048;
@@ -277,6 +281,34 @@ impl Mnemonic {
             }
         }
     }
+
+    /// Pick random positions (starting from 0) of words to ask the user for, to verify that the
+    /// phrase was written down. Positions are distinct and sorted. If `n` is larger than the phrase
+    /// it returns all positions
+    pub fn challenge(&self, n: usize) -> Vec<usize> {
+        let amount = min(n, self.words.len());
+        let mut result = index::sample(&mut thread_rng(), self.words.len(), amount).into_vec();
+        result.sort();
+        result
+    }
+
+    /// Verify words provided by the user as answers to a `challenge`, as pairs of position and word.
+    /// Returns `false` if there are no answers, or if any answer is wrong
+    pub fn verify_words(&self, answers: &[(usize, String)]) -> bool {
+        if answers.is_empty() {
+            return false;
+        }
+        // check all answers, without stopping on the first wrong one
+        let mut valid = true;
+        for (i, answer) in answers {
+            let answer = answer.trim().to_lowercase();
+            valid &= match self.words.get(*i) {
+                Some(word) => constant_time_eq(word.as_bytes(), answer.as_bytes()),
+                None => false,
+            };
+        }
+        valid
+    }
 }
 
 /// Generate entropy
@@ -935,4 +967,45 @@ mod tests {
         let act = checksum(value.as_slice(), StandardMnemonic::size24());
         assert_eq!(act, 0b10111111);
     }
+
+    #[test]
+    fn should_make_challenge() {
+        let mnemonic = Mnemonic::new(Language::English, StandardMnemonic::size24()).unwrap();
+        let act = mnemonic.challenge(4);
+        assert_eq!(4, act.len());
+        for i in 1..act.len() {
+            assert!(act[i - 1] < act[i]);
+        }
+        assert!(act.iter().all(|i| *i < 24));
+
+        let act = mnemonic.challenge(30);
+        assert_eq!((0..24).collect::<Vec<usize>>(), act);
+    }
+
+    #[test]
+    fn should_verify_words() {
+        let mnemonic = Mnemonic::try_from(
+            Language::English,
+            "often impact pistol seminar park example foil urge bird balance reopen uphold enforce protect pear",
+        ).unwrap();
+
+        assert!(mnemonic.verify_words(&[
+            (0, "often".to_string()),
+            (4, "park".to_string()),
+            (14, " Pear".to_string()),
+        ]));
+        assert!(!mnemonic.verify_words(&[
+            (0, "often".to_string()),
+            (4, "pack".to_string()),
+            (14, "pear".to_string()),
+        ]));
+        assert!(!mnemonic.verify_words(&[(15, "pear".to_string())]));
+        assert!(!mnemonic.verify_words(&[]));
+
+        let challenge = mnemonic.challenge(3);
+        let answers: Vec<(usize, String)> = challenge.iter()
+            .map(|i| (*i, mnemonic.sentence().split(' ').nth(*i).unwrap().to_string()))
+            .collect();
+        assert!(mnemonic.verify_words(answers.as_slice()));
+    }
 }
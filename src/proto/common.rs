@@ -259,6 +259,8 @@ pub enum BlockchainId {
     CHAIN_KOVAN = 10002,
     CHAIN_TESTNET_BITCOIN = 10003,
     CHAIN_FLOONET = 10004,
+    CHAIN_MORDOR = 10006,
+    CHAIN_KOTTI = 10007,
 }
 
 impl ::protobuf::ProtobufEnum for BlockchainId {
@@ -277,6 +279,8 @@ impl ::protobuf::ProtobufEnum for BlockchainId {
             10002 => ::std::option::Option::Some(BlockchainId::CHAIN_KOVAN),
             10003 => ::std::option::Option::Some(BlockchainId::CHAIN_TESTNET_BITCOIN),
             10004 => ::std::option::Option::Some(BlockchainId::CHAIN_FLOONET),
+            10006 => ::std::option::Option::Some(BlockchainId::CHAIN_MORDOR),
+            10007 => ::std::option::Option::Some(BlockchainId::CHAIN_KOTTI),
             _ => ::std::option::Option::None
         }
     }
@@ -292,6 +296,8 @@ impl ::protobuf::ProtobufEnum for BlockchainId {
             BlockchainId::CHAIN_KOVAN,
             BlockchainId::CHAIN_TESTNET_BITCOIN,
             BlockchainId::CHAIN_FLOONET,
+            BlockchainId::CHAIN_MORDOR,
+            BlockchainId::CHAIN_KOTTI,
         ];
         values
     }
@@ -329,12 +335,13 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x01\x20\x01(\x0e2\x17.emerald.vault.FileTypeR\x08fileType*X\n\x08FileTy\
     pe\x12\x10\n\x0cFILE_UNKNOWN\x10\0\x12\x0f\n\x0bFILE_WALLET\x10\x01\x12\
     \x0b\n\x07FILE_PK\x10\x02\x12\r\n\tFILE_SEED\x10\x03\x12\r\n\tFILE_BOOK\
-    \x10\x04*\xd0\x01\n\x0cBlockchainId\x12\x15\n\x11CHAIN_UNSPECIFIED\x10\0\
+    \x10\x04*\xf5\x01\n\x0cBlockchainId\x12\x15\n\x11CHAIN_UNSPECIFIED\x10\0\
     \x12\x11\n\rCHAIN_BITCOIN\x10\x01\x12\x0e\n\nCHAIN_GRIN\x10\x02\x12\x12\
     \n\x0eCHAIN_ETHEREUM\x10d\x12\x1a\n\x16CHAIN_ETHEREUM_CLASSIC\x10e\x12\
     \x14\n\x0fCHAIN_LIGHTNING\x10\xe9\x07\x12\x10\n\x0bCHAIN_KOVAN\x10\x92N\
     \x12\x1a\n\x15CHAIN_TESTNET_BITCOIN\x10\x93N\x12\x12\n\rCHAIN_FLOONET\
-    \x10\x94NJ\x86\x06\n\x06\x12\x04\0\0\x1f\x01\n\x08\n\x01\x0c\x12\x03\0\0\
+    \x10\x94N\x12\x11\n\x0cCHAIN_MORDOR\x10\x96N\x12\x10\n\x0bCHAIN_KOTTI\
+    \x10\x97NJ\xd8\x06\n\x06\x12\x04\0\0!\x01\n\x08\n\x01\x0c\x12\x03\0\0\
     \x12\n\x08\n\x01\x02\x12\x03\x01\0\x16\n\n\n\x02\x04\0\x12\x04\x03\0\x05\
     \x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x0c\n\x0b\n\x04\x04\0\x02\0\x12\
     \x03\x04\x04\x1b\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x04\x04\x0c\n\x0c\n\
@@ -350,28 +357,32 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x05\0\x02\x03\x01\x12\x03\x0b\x04\r\n\x0c\n\x05\x05\0\x02\x03\x02\x12\
     \x03\x0b\x10\x11\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x0c\x04\x12\n\x0c\n\
     \x05\x05\0\x02\x04\x01\x12\x03\x0c\x04\r\n\x0c\n\x05\x05\0\x02\x04\x02\
-    \x12\x03\x0c\x10\x11\n\n\n\x02\x05\x01\x12\x04\x0f\0\x1f\x01\n\n\n\x03\
-    \x05\x01\x01\x12\x03\x0f\x05\x11\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x10\
-    \x04\x1a\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x10\x04\x15\n\x0c\n\x05\
-    \x05\x01\x02\0\x02\x12\x03\x10\x18\x19\n\x0b\n\x04\x05\x01\x02\x01\x12\
-    \x03\x12\x04\x16\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x12\x04\x11\n\
-    \x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\x12\x14\x15\n\x0b\n\x04\x05\x01\
-    \x02\x02\x12\x03\x13\x04\x13\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\x13\
-    \x04\x0e\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x13\x11\x12\n\x0b\n\x04\
-    \x05\x01\x02\x03\x12\x03\x15\x04\x19\n\x0c\n\x05\x05\x01\x02\x03\x01\x12\
-    \x03\x15\x04\x12\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x15\x15\x18\n\
-    \x0b\n\x04\x05\x01\x02\x04\x12\x03\x16\x04!\n\x0c\n\x05\x05\x01\x02\x04\
-    \x01\x12\x03\x16\x04\x1a\n\x0c\n\x05\x05\x01\x02\x04\x02\x12\x03\x16\x1d\
-    \x20\n,\n\x04\x05\x01\x02\x05\x12\x03\x19\x04\x1b\x1a\x1f\x20Sidechains\
-    \x20and\x20state\x20channels\n\n\x0c\n\x05\x05\x01\x02\x05\x01\x12\x03\
-    \x19\x04\x13\n\x0c\n\x05\x05\x01\x02\x05\x02\x12\x03\x19\x16\x1a\n\x17\n\
-    \x04\x05\x01\x02\x06\x12\x03\x1c\x04\x18\x1a\n\x20Testnets\n\n\x0c\n\x05\
-    \x05\x01\x02\x06\x01\x12\x03\x1c\x04\x0f\n\x0c\n\x05\x05\x01\x02\x06\x02\
-    \x12\x03\x1c\x12\x17\n\x0b\n\x04\x05\x01\x02\x07\x12\x03\x1d\x04\"\n\x0c\
-    \n\x05\x05\x01\x02\x07\x01\x12\x03\x1d\x04\x19\n\x0c\n\x05\x05\x01\x02\
-    \x07\x02\x12\x03\x1d\x1c!\n\x0b\n\x04\x05\x01\x02\x08\x12\x03\x1e\x04\
-    \x1a\n\x0c\n\x05\x05\x01\x02\x08\x01\x12\x03\x1e\x04\x11\n\x0c\n\x05\x05\
-    \x01\x02\x08\x02\x12\x03\x1e\x14\x19b\x06proto3\
+    \x12\x03\x0c\x10\x11\n\n\n\x02\x05\x01\x12\x04\x0f\0!\x01\n\n\n\x03\x05\
+    \x01\x01\x12\x03\x0f\x05\x11\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x10\x04\
+    \x1a\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x10\x04\x15\n\x0c\n\x05\x05\
+    \x01\x02\0\x02\x12\x03\x10\x18\x19\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\
+    \x12\x04\x16\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x12\x04\x11\n\x0c\n\
+    \x05\x05\x01\x02\x01\x02\x12\x03\x12\x14\x15\n\x0b\n\x04\x05\x01\x02\x02\
+    \x12\x03\x13\x04\x13\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\x13\x04\x0e\
+    \n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x13\x11\x12\n\x0b\n\x04\x05\x01\
+    \x02\x03\x12\x03\x15\x04\x19\n\x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\x15\
+    \x04\x12\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x15\x15\x18\n\x0b\n\x04\
+    \x05\x01\x02\x04\x12\x03\x16\x04!\n\x0c\n\x05\x05\x01\x02\x04\x01\x12\
+    \x03\x16\x04\x1a\n\x0c\n\x05\x05\x01\x02\x04\x02\x12\x03\x16\x1d\x20\n,\
+    \n\x04\x05\x01\x02\x05\x12\x03\x19\x04\x1b\x1a\x1f\x20Sidechains\x20and\
+    \x20state\x20channels\n\n\x0c\n\x05\x05\x01\x02\x05\x01\x12\x03\x19\x04\
+    \x13\n\x0c\n\x05\x05\x01\x02\x05\x02\x12\x03\x19\x16\x1a\n\x17\n\x04\x05\
+    \x01\x02\x06\x12\x03\x1c\x04\x18\x1a\n\x20Testnets\n\n\x0c\n\x05\x05\x01\
+    \x02\x06\x01\x12\x03\x1c\x04\x0f\n\x0c\n\x05\x05\x01\x02\x06\x02\x12\x03\
+    \x1c\x12\x17\n\x0b\n\x04\x05\x01\x02\x07\x12\x03\x1d\x04\"\n\x0c\n\x05\
+    \x05\x01\x02\x07\x01\x12\x03\x1d\x04\x19\n\x0c\n\x05\x05\x01\x02\x07\x02\
+    \x12\x03\x1d\x1c!\n\x0b\n\x04\x05\x01\x02\x08\x12\x03\x1e\x04\x1a\n\x0c\
+    \n\x05\x05\x01\x02\x08\x01\x12\x03\x1e\x04\x11\n\x0c\n\x05\x05\x01\x02\
+    \x08\x02\x12\x03\x1e\x14\x19\n\x0b\n\x04\x05\x01\x02\t\x12\x03\x1f\x04\
+    \x19\n\x0c\n\x05\x05\x01\x02\t\x01\x12\x03\x1f\x04\x10\n\x0c\n\x05\x05\
+    \x01\x02\t\x02\x12\x03\x1f\x13\x18\n\x0b\n\x04\x05\x01\x02\n\x12\x03\x20\
+    \x04\x18\n\x0c\n\x05\x05\x01\x02\n\x01\x12\x03\x20\x04\x0f\n\x0c\n\x05\
+    \x05\x01\x02\n\x02\x12\x03\x20\x12\x17b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
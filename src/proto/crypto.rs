@@ -1074,6 +1074,7 @@ impl ::protobuf::reflect::ProtobufValue for Encrypted {
 pub enum Encrypted_CipherType {
     CIPHER_UNKNOWN = 0,
     CIPHER_AES128_CTR = 1,
+    CIPHER_AES256_GCM = 2,
 }
 
 impl ::protobuf::ProtobufEnum for Encrypted_CipherType {
@@ -1085,6 +1086,7 @@ impl ::protobuf::ProtobufEnum for Encrypted_CipherType {
         match value {
             0 => ::std::option::Option::Some(Encrypted_CipherType::CIPHER_UNKNOWN),
             1 => ::std::option::Option::Some(Encrypted_CipherType::CIPHER_AES128_CTR),
+            2 => ::std::option::Option::Some(Encrypted_CipherType::CIPHER_AES256_GCM),
             _ => ::std::option::Option::None
         }
     }
@@ -1093,6 +1095,7 @@ impl ::protobuf::ProtobufEnum for Encrypted_CipherType {
         static values: &'static [Encrypted_CipherType] = &[
             Encrypted_CipherType::CIPHER_UNKNOWN,
             Encrypted_CipherType::CIPHER_AES128_CTR,
+            Encrypted_CipherType::CIPHER_AES256_GCM,
         ];
         values
     }
@@ -1442,101 +1445,103 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20\x01(\rR\x01r\x12\x0c\n\x01p\x18\x05\x20\x01(\rR\x01p\"j\n\x06Pbkdf2\
     \x12\x14\n\x05dklen\x18\x01\x20\x01(\rR\x05dklen\x12\x0c\n\x01c\x18\x02\
     \x20\x01(\rR\x01c\x12(\n\x03prf\x18\x03\x20\x01(\x0e2\x16.emerald.vault.\
-    PrfTypeR\x03prf\x12\x12\n\x04salt\x18\x04\x20\x01(\x0cR\x04salt\"\xc8\
+    PrfTypeR\x03prf\x12\x12\n\x04salt\x18\x04\x20\x01(\x0cR\x04salt\"\xdf\
     \x02\n\tEncrypted\x127\n\x04type\x18\x01\x20\x01(\x0e2#.emerald.vault.En\
     crypted.CipherTypeR\x04type\x12\x16\n\x06secret\x18\x02\x20\x01(\x0cR\
     \x06secret\x12\x0e\n\x02iv\x18\x03\x20\x01(\x0cR\x02iv\x12$\n\x03mac\x18\
     \x04\x20\x01(\x0b2\x12.emerald.vault.MacR\x03mac\x129\n\nkdf_scrypt\x18\
     \x05\x20\x01(\x0b2\x18.emerald.vault.ScryptKdfH\0R\tkdfScrypt\x124\n\tkd\
     f_pbkdf\x18\x06\x20\x01(\x0b2\x15.emerald.vault.Pbkdf2H\0R\x08kdfPbkdf\"\
-    7\n\nCipherType\x12\x12\n\x0eCIPHER_UNKNOWN\x10\0\x12\x15\n\x11CIPHER_AE\
-    S128_CTR\x10\x01B\n\n\x08kdf_type\"u\n\x03Mac\x12.\n\x04type\x18\x01\x20\
-    \x01(\x0e2\x1a.emerald.vault.Mac.MacTypeR\x04type\x12\x14\n\x05value\x18\
-    \x02\x20\x01(\x0cR\x05value\"(\n\x07MacType\x12\x0f\n\x0bMAC_UNKNOWN\x10\
-    \0\x12\x0c\n\x08MAC_WEB3\x10\x01*/\n\x07PrfType\x12\x0f\n\x0bPRF_UNKNOWN\
-    \x10\0\x12\x13\n\x0fPRF_HMAC_SHA256\x10\x01J\xfe\x0c\n\x06\x12\x04\0\0/\
-    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\0\x16\n\
-    \n\n\x02\x04\0\x12\x04\x03\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\
-    \x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x15\n\r\n\x05\x04\0\x02\0\
-    \x04\x12\x04\x04\x04\x03\x13\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\
-    \n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x10\n\x0c\n\x05\x04\0\x02\
-    \0\x03\x12\x03\x04\x13\x14\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x13\
-    \n\r\n\x05\x04\0\x02\x01\x04\x12\x04\x05\x04\x04\x15\n\x0c\n\x05\x04\0\
-    \x02\x01\x05\x12\x03\x05\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\
-    \n\x0e\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\x11\x12\n\x0b\n\x04\x04\
-    \0\x02\x02\x12\x03\x06\x04\x11\n\r\n\x05\x04\0\x02\x02\x04\x12\x04\x06\
-    \x04\x05\x13\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x06\x04\n\n\x0c\n\x05\
-    \x04\0\x02\x02\x01\x12\x03\x06\x0b\x0c\n\x0c\n\x05\x04\0\x02\x02\x03\x12\
-    \x03\x06\x0f\x10\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x07\x04\x11\n\r\n\x05\
-    \x04\0\x02\x03\x04\x12\x04\x07\x04\x06\x11\n\x0c\n\x05\x04\0\x02\x03\x05\
-    \x12\x03\x07\x04\n\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x07\x0b\x0c\n\
-    \x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x07\x0f\x10\n\x0b\n\x04\x04\0\x02\
-    \x04\x12\x03\x08\x04\x11\n\r\n\x05\x04\0\x02\x04\x04\x12\x04\x08\x04\x07\
-    \x11\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x04\x01\x12\x03\x08\x0b\x0c\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\
-    \x08\x0f\x10\n\n\n\x02\x04\x01\x12\x04\x0b\0\x10\x01\n\n\n\x03\x04\x01\
-    \x01\x12\x03\x0b\x08\x0e\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0c\x04\x15\n\
-    \r\n\x05\x04\x01\x02\0\x04\x12\x04\x0c\x04\x0b\x10\n\x0c\n\x05\x04\x01\
-    \x02\0\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0c\
-    \x0b\x10\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0c\x13\x14\n\x0b\n\x04\
-    \x04\x01\x02\x01\x12\x03\r\x04\x11\n\r\n\x05\x04\x01\x02\x01\x04\x12\x04\
-    \r\x04\x0c\x15\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\r\x04\n\n\x0c\n\
-    \x05\x04\x01\x02\x01\x01\x12\x03\r\x0b\x0c\n\x0c\n\x05\x04\x01\x02\x01\
-    \x03\x12\x03\r\x0f\x10\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0e\x04\x14\n\
-    \r\n\x05\x04\x01\x02\x02\x04\x12\x04\x0e\x04\r\x11\n\x0c\n\x05\x04\x01\
-    \x02\x02\x06\x12\x03\x0e\x04\x0b\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \x0e\x0c\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0e\x12\x13\n\x0b\n\
-    \x04\x04\x01\x02\x03\x12\x03\x0f\x04\x13\n\r\n\x05\x04\x01\x02\x03\x04\
-    \x12\x04\x0f\x04\x0e\x14\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\x0f\x04\
-    \t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0f\n\x0e\n\x0c\n\x05\x04\x01\
-    \x02\x03\x03\x12\x03\x0f\x11\x12\n\n\n\x02\x05\0\x12\x04\x12\0\x15\x01\n\
-    \n\n\x03\x05\0\x01\x12\x03\x12\x05\x0c\n\x0b\n\x04\x05\0\x02\0\x12\x03\
-    \x13\x04\x14\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x13\x04\x0f\n\x0c\n\x05\
-    \x05\0\x02\0\x02\x12\x03\x13\x12\x13\n\x0b\n\x04\x05\0\x02\x01\x12\x03\
-    \x14\x04\x18\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x14\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x01\x02\x12\x03\x14\x16\x17\n\n\n\x02\x04\x02\x12\x04\x17\
-    \0%\x01\n\n\n\x03\x04\x02\x01\x12\x03\x17\x08\x11\n\x0b\n\x04\x04\x02\
-    \x02\0\x12\x03\x18\x04\x18\n\r\n\x05\x04\x02\x02\0\x04\x12\x04\x18\x04\
-    \x17\x13\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x18\x04\x0e\n\x0c\n\x05\
-    \x04\x02\x02\0\x01\x12\x03\x18\x0f\x13\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
-    \x03\x18\x16\x17\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x19\x04\x15\n\r\n\
-    \x05\x04\x02\x02\x01\x04\x12\x04\x19\x04\x18\x18\n\x0c\n\x05\x04\x02\x02\
-    \x01\x05\x12\x03\x19\x04\t\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x19\n\
-    \x10\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x19\x13\x14\n\x0b\n\x04\x04\
-    \x02\x02\x02\x12\x03\x1a\x04\x11\n\r\n\x05\x04\x02\x02\x02\x04\x12\x04\
-    \x1a\x04\x19\x15\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x1a\x04\t\n\x0c\
-    \n\x05\x04\x02\x02\x02\x01\x12\x03\x1a\n\x0c\n\x0c\n\x05\x04\x02\x02\x02\
-    \x03\x12\x03\x1a\x0f\x10\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x1b\x04\x10\
-    \n\r\n\x05\x04\x02\x02\x03\x04\x12\x04\x1b\x04\x1a\x11\n\x0c\n\x05\x04\
-    \x02\x02\x03\x06\x12\x03\x1b\x04\x07\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\
-    \x03\x1b\x08\x0b\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x1b\x0e\x0f\n\
-    \x0c\n\x04\x04\x02\x08\0\x12\x04\x1c\x04\x1f\x05\n\x0c\n\x05\x04\x02\x08\
-    \0\x01\x12\x03\x1c\n\x12\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x1d\x08!\n\
-    \x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x1d\x08\x11\n\x0c\n\x05\x04\x02\
-    \x02\x04\x01\x12\x03\x1d\x12\x1c\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\
-    \x1d\x1f\x20\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x1e\x08\x1d\n\x0c\n\x05\
-    \x04\x02\x02\x05\x06\x12\x03\x1e\x08\x0e\n\x0c\n\x05\x04\x02\x02\x05\x01\
-    \x12\x03\x1e\x0f\x18\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\x1e\x1b\x1c\
-    \n\x0c\n\x04\x04\x02\x04\0\x12\x04!\x04$\x05\n\x0c\n\x05\x04\x02\x04\0\
-    \x01\x12\x03!\t\x13\n\r\n\x06\x04\x02\x04\0\x02\0\x12\x03\"\x08\x19\n\
-    \x0e\n\x07\x04\x02\x04\0\x02\0\x01\x12\x03\"\x08\x16\n\x0e\n\x07\x04\x02\
-    \x04\0\x02\0\x02\x12\x03\"\x17\x18\n\r\n\x06\x04\x02\x04\0\x02\x01\x12\
-    \x03#\x08\x1c\n\x0e\n\x07\x04\x02\x04\0\x02\x01\x01\x12\x03#\x08\x19\n\
-    \x0e\n\x07\x04\x02\x04\0\x02\x01\x02\x12\x03#\x1a\x1b\n\n\n\x02\x04\x03\
-    \x12\x04'\0/\x01\n\n\n\x03\x04\x03\x01\x12\x03'\x08\x0b\n\x0b\n\x04\x04\
-    \x03\x02\0\x12\x03(\x04\x15\n\r\n\x05\x04\x03\x02\0\x04\x12\x04(\x04'\r\
-    \n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03(\x04\x0b\n\x0c\n\x05\x04\x03\x02\
-    \0\x01\x12\x03(\x0c\x10\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03(\x13\x14\n\
-    \x0b\n\x04\x04\x03\x02\x01\x12\x03)\x04\x14\n\r\n\x05\x04\x03\x02\x01\
-    \x04\x12\x04)\x04(\x15\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03)\x04\t\n\
-    \x0c\n\x05\x04\x03\x02\x01\x01\x12\x03)\n\x0f\n\x0c\n\x05\x04\x03\x02\
-    \x01\x03\x12\x03)\x12\x13\n\x0c\n\x04\x04\x03\x04\0\x12\x04+\x04.\x05\n\
-    \x0c\n\x05\x04\x03\x04\0\x01\x12\x03+\t\x10\n\r\n\x06\x04\x03\x04\0\x02\
-    \0\x12\x03,\x08\x18\n\x0e\n\x07\x04\x03\x04\0\x02\0\x01\x12\x03,\x08\x13\
-    \n\x0e\n\x07\x04\x03\x04\0\x02\0\x02\x12\x03,\x16\x17\n\r\n\x06\x04\x03\
-    \x04\0\x02\x01\x12\x03-\x08\x15\n\x0e\n\x07\x04\x03\x04\0\x02\x01\x01\
-    \x12\x03-\x08\x10\n\x0e\n\x07\x04\x03\x04\0\x02\x01\x02\x12\x03-\x13\x14\
-    b\x06proto3\
+    N\n\nCipherType\x12\x12\n\x0eCIPHER_UNKNOWN\x10\0\x12\x15\n\x11CIPHER_AE\
+    S128_CTR\x10\x01\x12\x15\n\x11CIPHER_AES256_GCM\x10\x02B\n\n\x08kdf_type\
+    \"u\n\x03Mac\x12.\n\x04type\x18\x01\x20\x01(\x0e2\x1a.emerald.vault.Mac.\
+    MacTypeR\x04type\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value\"(\n\
+    \x07MacType\x12\x0f\n\x0bMAC_UNKNOWN\x10\0\x12\x0c\n\x08MAC_WEB3\x10\x01\
+    */\n\x07PrfType\x12\x0f\n\x0bPRF_UNKNOWN\x10\0\x12\x13\n\x0fPRF_HMAC_SHA\
+    256\x10\x01J\xad\r\n\x06\x12\x04\0\00\x01\n\x08\n\x01\x0c\x12\x03\0\0\
+    \x12\n\x08\n\x01\x02\x12\x03\x01\0\x16\n\n\n\x02\x04\0\x12\x04\x03\0\t\
+    \x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\
+    \x03\x04\x04\x15\n\r\n\x05\x04\0\x02\0\x04\x12\x04\x04\x04\x03\x13\n\x0c\
+    \n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\
+    \x03\x04\x0b\x10\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x13\x14\n\x0b\n\
+    \x04\x04\0\x02\x01\x12\x03\x05\x04\x13\n\r\n\x05\x04\0\x02\x01\x04\x12\
+    \x04\x05\x04\x04\x15\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x04\t\n\
+    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\n\x0e\n\x0c\n\x05\x04\0\x02\x01\
+    \x03\x12\x03\x05\x11\x12\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x06\x04\x11\n\
+    \r\n\x05\x04\0\x02\x02\x04\x12\x04\x06\x04\x05\x13\n\x0c\n\x05\x04\0\x02\
+    \x02\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\x0b\
+    \x0c\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x06\x0f\x10\n\x0b\n\x04\x04\0\
+    \x02\x03\x12\x03\x07\x04\x11\n\r\n\x05\x04\0\x02\x03\x04\x12\x04\x07\x04\
+    \x06\x11\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\
+    \0\x02\x03\x01\x12\x03\x07\x0b\x0c\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\
+    \x07\x0f\x10\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x08\x04\x11\n\r\n\x05\x04\
+    \0\x02\x04\x04\x12\x04\x08\x04\x07\x11\n\x0c\n\x05\x04\0\x02\x04\x05\x12\
+    \x03\x08\x04\n\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x08\x0b\x0c\n\x0c\n\
+    \x05\x04\0\x02\x04\x03\x12\x03\x08\x0f\x10\n\n\n\x02\x04\x01\x12\x04\x0b\
+    \0\x10\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0b\x08\x0e\n\x0b\n\x04\x04\x01\
+    \x02\0\x12\x03\x0c\x04\x15\n\r\n\x05\x04\x01\x02\0\x04\x12\x04\x0c\x04\
+    \x0b\x10\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03\x0c\x0b\x10\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\
+    \x0c\x13\x14\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\r\x04\x11\n\r\n\x05\x04\
+    \x01\x02\x01\x04\x12\x04\r\x04\x0c\x15\n\x0c\n\x05\x04\x01\x02\x01\x05\
+    \x12\x03\r\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\r\x0b\x0c\n\x0c\
+    \n\x05\x04\x01\x02\x01\x03\x12\x03\r\x0f\x10\n\x0b\n\x04\x04\x01\x02\x02\
+    \x12\x03\x0e\x04\x14\n\r\n\x05\x04\x01\x02\x02\x04\x12\x04\x0e\x04\r\x11\
+    \n\x0c\n\x05\x04\x01\x02\x02\x06\x12\x03\x0e\x04\x0b\n\x0c\n\x05\x04\x01\
+    \x02\x02\x01\x12\x03\x0e\x0c\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\
+    \x0e\x12\x13\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x0f\x04\x13\n\r\n\x05\
+    \x04\x01\x02\x03\x04\x12\x04\x0f\x04\x0e\x14\n\x0c\n\x05\x04\x01\x02\x03\
+    \x05\x12\x03\x0f\x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0f\n\x0e\
+    \n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0f\x11\x12\n\n\n\x02\x05\0\x12\
+    \x04\x12\0\x15\x01\n\n\n\x03\x05\0\x01\x12\x03\x12\x05\x0c\n\x0b\n\x04\
+    \x05\0\x02\0\x12\x03\x13\x04\x14\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x13\
+    \x04\x0f\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x13\x12\x13\n\x0b\n\x04\x05\
+    \0\x02\x01\x12\x03\x14\x04\x18\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x14\
+    \x04\x13\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x14\x16\x17\n\n\n\x02\x04\
+    \x02\x12\x04\x17\0&\x01\n\n\n\x03\x04\x02\x01\x12\x03\x17\x08\x11\n\x0b\
+    \n\x04\x04\x02\x02\0\x12\x03\x18\x04\x18\n\r\n\x05\x04\x02\x02\0\x04\x12\
+    \x04\x18\x04\x17\x13\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x18\x04\x0e\n\
+    \x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x18\x0f\x13\n\x0c\n\x05\x04\x02\x02\
+    \0\x03\x12\x03\x18\x16\x17\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x19\x04\
+    \x15\n\r\n\x05\x04\x02\x02\x01\x04\x12\x04\x19\x04\x18\x18\n\x0c\n\x05\
+    \x04\x02\x02\x01\x05\x12\x03\x19\x04\t\n\x0c\n\x05\x04\x02\x02\x01\x01\
+    \x12\x03\x19\n\x10\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x19\x13\x14\n\
+    \x0b\n\x04\x04\x02\x02\x02\x12\x03\x1a\x04\x11\n\r\n\x05\x04\x02\x02\x02\
+    \x04\x12\x04\x1a\x04\x19\x15\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x1a\
+    \x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x1a\n\x0c\n\x0c\n\x05\x04\
+    \x02\x02\x02\x03\x12\x03\x1a\x0f\x10\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\
+    \x1b\x04\x10\n\r\n\x05\x04\x02\x02\x03\x04\x12\x04\x1b\x04\x1a\x11\n\x0c\
+    \n\x05\x04\x02\x02\x03\x06\x12\x03\x1b\x04\x07\n\x0c\n\x05\x04\x02\x02\
+    \x03\x01\x12\x03\x1b\x08\x0b\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x1b\
+    \x0e\x0f\n\x0c\n\x04\x04\x02\x08\0\x12\x04\x1c\x04\x1f\x05\n\x0c\n\x05\
+    \x04\x02\x08\0\x01\x12\x03\x1c\n\x12\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\
+    \x1d\x08!\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x1d\x08\x11\n\x0c\n\
+    \x05\x04\x02\x02\x04\x01\x12\x03\x1d\x12\x1c\n\x0c\n\x05\x04\x02\x02\x04\
+    \x03\x12\x03\x1d\x1f\x20\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x1e\x08\x1d\
+    \n\x0c\n\x05\x04\x02\x02\x05\x06\x12\x03\x1e\x08\x0e\n\x0c\n\x05\x04\x02\
+    \x02\x05\x01\x12\x03\x1e\x0f\x18\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\
+    \x1e\x1b\x1c\n\x0c\n\x04\x04\x02\x04\0\x12\x04!\x04%\x05\n\x0c\n\x05\x04\
+    \x02\x04\0\x01\x12\x03!\t\x13\n\r\n\x06\x04\x02\x04\0\x02\0\x12\x03\"\
+    \x08\x19\n\x0e\n\x07\x04\x02\x04\0\x02\0\x01\x12\x03\"\x08\x16\n\x0e\n\
+    \x07\x04\x02\x04\0\x02\0\x02\x12\x03\"\x17\x18\n\r\n\x06\x04\x02\x04\0\
+    \x02\x01\x12\x03#\x08\x1c\n\x0e\n\x07\x04\x02\x04\0\x02\x01\x01\x12\x03#\
+    \x08\x19\n\x0e\n\x07\x04\x02\x04\0\x02\x01\x02\x12\x03#\x1a\x1b\n\r\n\
+    \x06\x04\x02\x04\0\x02\x02\x12\x03$\x08\x1c\n\x0e\n\x07\x04\x02\x04\0\
+    \x02\x02\x01\x12\x03$\x08\x19\n\x0e\n\x07\x04\x02\x04\0\x02\x02\x02\x12\
+    \x03$\x1a\x1b\n\n\n\x02\x04\x03\x12\x04(\00\x01\n\n\n\x03\x04\x03\x01\
+    \x12\x03(\x08\x0b\n\x0b\n\x04\x04\x03\x02\0\x12\x03)\x04\x15\n\r\n\x05\
+    \x04\x03\x02\0\x04\x12\x04)\x04(\r\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\
+    )\x04\x0b\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03)\x0c\x10\n\x0c\n\x05\x04\
+    \x03\x02\0\x03\x12\x03)\x13\x14\n\x0b\n\x04\x04\x03\x02\x01\x12\x03*\x04\
+    \x14\n\r\n\x05\x04\x03\x02\x01\x04\x12\x04*\x04)\x15\n\x0c\n\x05\x04\x03\
+    \x02\x01\x05\x12\x03*\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03*\n\
+    \x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03*\x12\x13\n\x0c\n\x04\x04\
+    \x03\x04\0\x12\x04,\x04/\x05\n\x0c\n\x05\x04\x03\x04\0\x01\x12\x03,\t\
+    \x10\n\r\n\x06\x04\x03\x04\0\x02\0\x12\x03-\x08\x18\n\x0e\n\x07\x04\x03\
+    \x04\0\x02\0\x01\x12\x03-\x08\x13\n\x0e\n\x07\x04\x03\x04\0\x02\0\x02\
+    \x12\x03-\x16\x17\n\r\n\x06\x04\x03\x04\0\x02\x01\x12\x03.\x08\x15\n\x0e\
+    \n\x07\x04\x03\x04\0\x02\x01\x01\x12\x03.\x08\x10\n\x0e\n\x07\x04\x03\
+    \x04\0\x02\x01\x02\x12\x03.\x13\x14b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
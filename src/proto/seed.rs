@@ -33,6 +33,7 @@ pub struct Seed {
     pub id: ::std::vec::Vec<u8>,
     pub label: ::std::string::String,
     pub created_at: u64,
+    pub fingerprint: ::std::vec::Vec<u8>,
     // message oneof groups
     pub seed_source: ::std::option::Option<Seed_oneof_seed_source>,
     // special fields
@@ -236,6 +237,32 @@ impl Seed {
     pub fn set_created_at(&mut self, v: u64) {
         self.created_at = v;
     }
+
+    // bytes fingerprint = 7;
+
+
+    pub fn get_fingerprint(&self) -> &[u8] {
+        &self.fingerprint
+    }
+    pub fn clear_fingerprint(&mut self) {
+        self.fingerprint.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fingerprint(&mut self, v: ::std::vec::Vec<u8>) {
+        self.fingerprint = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fingerprint(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.fingerprint
+    }
+
+    // Take field
+    pub fn take_fingerprint(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.fingerprint, ::std::vec::Vec::new())
+    }
 }
 
 impl ::protobuf::Message for Seed {
@@ -285,6 +312,9 @@ impl ::protobuf::Message for Seed {
                     let tmp = is.read_uint64()?;
                     self.created_at = tmp;
                 },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.fingerprint)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -309,6 +339,9 @@ impl ::protobuf::Message for Seed {
         if self.created_at != 0 {
             my_size += ::protobuf::rt::value_size(6, self.created_at, ::protobuf::wire_format::WireTypeVarint);
         }
+        if !self.fingerprint.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.fingerprint);
+        }
         if let ::std::option::Option::Some(ref v) = self.seed_source {
             match v {
                 &Seed_oneof_seed_source::bytes(ref v) => {
@@ -339,6 +372,9 @@ impl ::protobuf::Message for Seed {
         if self.created_at != 0 {
             os.write_uint64(6, self.created_at)?;
         }
+        if !self.fingerprint.is_empty() {
+            os.write_bytes(7, &self.fingerprint)?;
+        }
         if let ::std::option::Option::Some(ref v) = self.seed_source {
             match v {
                 &Seed_oneof_seed_source::bytes(ref v) => {
@@ -425,6 +461,11 @@ impl ::protobuf::Message for Seed {
                     |m: &Seed| { &m.created_at },
                     |m: &mut Seed| { &mut m.created_at },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "fingerprint",
+                    |m: &Seed| { &m.fingerprint },
+                    |m: &mut Seed| { &mut m.fingerprint },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Seed>(
                     "Seed",
                     fields,
@@ -453,6 +494,7 @@ impl ::protobuf::Clear for Seed {
         self.seed_source = ::std::option::Option::None;
         self.label.clear();
         self.created_at = 0;
+        self.fingerprint.clear();
         self.unknown_fields.clear();
     }
 }
@@ -473,6 +515,8 @@ impl ::protobuf::reflect::ProtobufValue for Seed {
 pub struct LedgerSeed {
     // message fields
     pub fingerprints: ::protobuf::RepeatedField<HDPathFingerprint>,
+    pub device_model: ::std::string::String,
+    pub master_fingerprint: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -513,6 +557,58 @@ impl LedgerSeed {
     pub fn take_fingerprints(&mut self) -> ::protobuf::RepeatedField<HDPathFingerprint> {
         ::std::mem::replace(&mut self.fingerprints, ::protobuf::RepeatedField::new())
     }
+
+    // string device_model = 2;
+
+
+    pub fn get_device_model(&self) -> &str {
+        &self.device_model
+    }
+    pub fn clear_device_model(&mut self) {
+        self.device_model.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_model(&mut self, v: ::std::string::String) {
+        self.device_model = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device_model(&mut self) -> &mut ::std::string::String {
+        &mut self.device_model
+    }
+
+    // Take field
+    pub fn take_device_model(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device_model, ::std::string::String::new())
+    }
+
+    // bytes master_fingerprint = 3;
+
+
+    pub fn get_master_fingerprint(&self) -> &[u8] {
+        &self.master_fingerprint
+    }
+    pub fn clear_master_fingerprint(&mut self) {
+        self.master_fingerprint.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_master_fingerprint(&mut self, v: ::std::vec::Vec<u8>) {
+        self.master_fingerprint = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_master_fingerprint(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.master_fingerprint
+    }
+
+    // Take field
+    pub fn take_master_fingerprint(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.master_fingerprint, ::std::vec::Vec::new())
+    }
 }
 
 impl ::protobuf::Message for LedgerSeed {
@@ -532,6 +628,12 @@ impl ::protobuf::Message for LedgerSeed {
                 1 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fingerprints)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device_model)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.master_fingerprint)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -548,6 +650,12 @@ impl ::protobuf::Message for LedgerSeed {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if !self.device_model.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device_model);
+        }
+        if !self.master_fingerprint.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.master_fingerprint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -559,6 +667,12 @@ impl ::protobuf::Message for LedgerSeed {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if !self.device_model.is_empty() {
+            os.write_string(2, &self.device_model)?;
+        }
+        if !self.master_fingerprint.is_empty() {
+            os.write_bytes(3, &self.master_fingerprint)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -606,6 +720,16 @@ impl ::protobuf::Message for LedgerSeed {
                     |m: &LedgerSeed| { &m.fingerprints },
                     |m: &mut LedgerSeed| { &mut m.fingerprints },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device_model",
+                    |m: &LedgerSeed| { &m.device_model },
+                    |m: &mut LedgerSeed| { &mut m.device_model },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "master_fingerprint",
+                    |m: &LedgerSeed| { &m.master_fingerprint },
+                    |m: &mut LedgerSeed| { &mut m.master_fingerprint },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<LedgerSeed>(
                     "LedgerSeed",
                     fields,
@@ -629,6 +753,8 @@ impl ::protobuf::Message for LedgerSeed {
 impl ::protobuf::Clear for LedgerSeed {
     fn clear(&mut self) {
         self.fingerprints.clear();
+        self.device_model.clear();
+        self.master_fingerprint.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1596,94 +1722,114 @@ impl ::protobuf::reflect::ProtobufValue for HDPath {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\nseed.proto\x12\remerald.vault\x1a\x0ccrypto.proto\x1a\x0ccommon.prot\
-    o\"\xf7\x01\n\x04Seed\x124\n\tfile_type\x18\x01\x20\x01(\x0e2\x17.emeral\
+    o\"\x99\x02\n\x04Seed\x124\n\tfile_type\x18\x01\x20\x01(\x0e2\x17.emeral\
     d.vault.FileTypeR\x08fileType\x12\x0e\n\x02id\x18\x02\x20\x01(\x0cR\x02i\
     d\x120\n\x05bytes\x18\x03\x20\x01(\x0b2\x18.emerald.vault.EncryptedH\0R\
     \x05bytes\x123\n\x06ledger\x18\x04\x20\x01(\x0b2\x19.emerald.vault.Ledge\
     rSeedH\0R\x06ledger\x12\x14\n\x05label\x18\x05\x20\x01(\tR\x05label\x12\
-    \x1d\n\ncreated_at\x18\x06\x20\x01(\x04R\tcreatedAtB\r\n\x0bseed_source\
-    \"R\n\nLedgerSeed\x12D\n\x0cfingerprints\x18\x01\x20\x03(\x0b2\x20.emera\
-    ld.vault.HDPathFingerprintR\x0cfingerprints\"\xc4\x01\n\x11HDPathFingerp\
-    rint\x12)\n\x04path\x18\x01\x20\x01(\x0b2\x15.emerald.vault.HDPathR\x04p\
-    ath\x129\n\x04type\x18\x02\x20\x01(\x0e2%.emerald.vault.HDPathFingerprin\
-    t.TypeR\x04type\x12\x20\n\x0bfingerprint\x18\x03\x20\x01(\x0cR\x0bfinger\
-    print\"'\n\x04Type\x12\x0b\n\x07UNKNOWN\x10\0\x12\x12\n\x0eADDRESS_SHA25\
-    6\x10\x01\"\x8e\x01\n\x06SeedHD\x12\x17\n\x07seed_id\x18\x01\x20\x01(\
-    \x0cR\x06seedId\x12+\n\x04path\x18\x02\x20\x01(\x0b2\x15.emerald.vault.H\
-    DPathH\0R\x04path\x121\n\x07account\x18\x03\x20\x01(\x0b2\x15.emerald.va\
-    ult.HDPathH\0R\x07accountB\x0b\n\tpath_type\"~\n\x06HDPath\x12\x18\n\x07\
-    purpose\x18\x01\x20\x01(\rR\x07purpose\x12\x12\n\x04coin\x18\x02\x20\x01\
-    (\rR\x04coin\x12\x18\n\x07account\x18\x03\x20\x01(\rR\x07account\x12\x16\
-    \n\x06change\x18\x04\x20\x01(\rR\x06change\x12\x14\n\x05index\x18\x05\
-    \x20\x01(\rR\x05indexJ\xf9\x0b\n\x06\x12\x04\0\01\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\0\x16\n\t\n\x02\x03\0\x12\
-    \x03\x02\0\x16\n\t\n\x02\x03\x01\x12\x03\x03\0\x16\n\n\n\x02\x04\0\x12\
-    \x04\x05\0\x10\x01\n\n\n\x03\x04\0\x01\x12\x03\x05\x08\x0c\n\x0b\n\x04\
-    \x04\0\x02\0\x12\x03\x06\x04\x1b\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x06\
-    \x04\x0c\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x06\r\x16\n\x0c\n\x05\x04\0\
-    \x02\0\x03\x12\x03\x06\x19\x1a\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x07\x04\
-    \x11\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\0\
-    \x02\x01\x01\x12\x03\x07\n\x0c\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x07\
-    \x0f\x10\n\x0c\n\x04\x04\0\x08\0\x12\x04\x08\x04\x0b\x05\n\x0c\n\x05\x04\
-    \0\x08\0\x01\x12\x03\x08\n\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\t\x08\
-    \x1c\n\x0c\n\x05\x04\0\x02\x02\x06\x12\x03\t\x08\x11\n\x0c\n\x05\x04\0\
-    \x02\x02\x01\x12\x03\t\x12\x17\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\t\
-    \x1a\x1b\n\x0b\n\x04\x04\0\x02\x03\x12\x03\n\x08\x1e\n\x0c\n\x05\x04\0\
-    \x02\x03\x06\x12\x03\n\x08\x12\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\n\
-    \x13\x19\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\n\x1c\x1d\n-\n\x04\x04\0\
-    \x02\x04\x12\x03\r\x04\x15\x1a\x20\x20User\x20assigned\x20label\x20(opti\
-    onal)\n\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x04\x01\x12\x03\r\x0b\x10\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\r\
-    \x13\x14\nD\n\x04\x04\0\x02\x05\x12\x03\x0f\x04\x1a\x1a7\x20creation\x20\
-    date\x20of\x20the\x20seed,\x20millis\x20since\x20epoch,\x20in\x20UTC\n\n\
-    \x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\0\x02\x05\
-    \x01\x12\x03\x0f\x0b\x15\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x0f\x18\
-    \x19\n\n\n\x02\x04\x01\x12\x04\x12\0\x14\x01\n\n\n\x03\x04\x01\x01\x12\
-    \x03\x12\x08\x12\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x13\x040\n\x0c\n\x05\
-    \x04\x01\x02\0\x04\x12\x03\x13\x04\x0c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\
-    \x03\x13\r\x1e\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x13\x1f+\n\x0c\n\
-    \x05\x04\x01\x02\0\x03\x12\x03\x13./\n\n\n\x02\x04\x02\x12\x04\x16\0\x1f\
-    \x01\n\n\n\x03\x04\x02\x01\x12\x03\x16\x08\x19\n\x0b\n\x04\x04\x02\x02\0\
-    \x12\x03\x17\x04\x14\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x17\x04\n\n\
-    \x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x17\x0b\x0f\n\x0c\n\x05\x04\x02\x02\
-    \0\x03\x12\x03\x17\x12\x13\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x18\x04\
-    \x12\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x18\x04\x08\n\x0c\n\x05\x04\
-    \x02\x02\x01\x01\x12\x03\x18\t\r\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\
-    \x18\x10\x11\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x19\x04\x1a\n\x0c\n\x05\
-    \x04\x02\x02\x02\x05\x12\x03\x19\x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\
-    \x12\x03\x19\n\x15\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x19\x18\x19\n\
-    \x0c\n\x04\x04\x02\x04\0\x12\x04\x1b\x04\x1e\x05\n\x0c\n\x05\x04\x02\x04\
-    \0\x01\x12\x03\x1b\t\r\n\r\n\x06\x04\x02\x04\0\x02\0\x12\x03\x1c\x08\x14\
-    \n\x0e\n\x07\x04\x02\x04\0\x02\0\x01\x12\x03\x1c\x08\x0f\n\x0e\n\x07\x04\
-    \x02\x04\0\x02\0\x02\x12\x03\x1c\x12\x13\n\r\n\x06\x04\x02\x04\0\x02\x01\
-    \x12\x03\x1d\x08\x1b\n\x0e\n\x07\x04\x02\x04\0\x02\x01\x01\x12\x03\x1d\
-    \x08\x16\n\x0e\n\x07\x04\x02\x04\0\x02\x01\x02\x12\x03\x1d\x19\x1a\n\n\n\
-    \x02\x04\x03\x12\x04!\0)\x01\n\n\n\x03\x04\x03\x01\x12\x03!\x08\x0e\n\
-    \x0b\n\x04\x04\x03\x02\0\x12\x03\"\x04\x16\n\x0c\n\x05\x04\x03\x02\0\x05\
-    \x12\x03\"\x04\t\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\"\n\x11\n\x0c\n\
-    \x05\x04\x03\x02\0\x03\x12\x03\"\x14\x15\n\x0c\n\x04\x04\x03\x08\0\x12\
-    \x04#\x04(\x05\n\x0c\n\x05\x04\x03\x08\0\x01\x12\x03#\n\x13\n9\n\x04\x04\
-    \x03\x02\x01\x12\x03%\x08\x18\x1a,full\x20m/purpose'/coin'/account'/chan\
-    ge/index\n\n\x0c\n\x05\x04\x03\x02\x01\x06\x12\x03%\x08\x0e\n\x0c\n\x05\
-    \x04\x03\x02\x01\x01\x12\x03%\x0f\x13\n\x0c\n\x05\x04\x03\x02\x01\x03\
-    \x12\x03%\x16\x17\n3\n\x04\x04\x03\x02\x02\x12\x03'\x08\x1b\x1a&short,\
-    \x20only\x20m/purpose'/coin'/account'\n\n\x0c\n\x05\x04\x03\x02\x02\x06\
-    \x12\x03'\x08\x0e\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03'\x0f\x16\n\x0c\
-    \n\x05\x04\x03\x02\x02\x03\x12\x03'\x19\x1a\n\n\n\x02\x04\x04\x12\x04+\0\
-    1\x01\n\n\n\x03\x04\x04\x01\x12\x03+\x08\x0e\n\x0b\n\x04\x04\x04\x02\0\
-    \x12\x03,\x04\x17\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03,\x04\n\n\x0c\n\
-    \x05\x04\x04\x02\0\x01\x12\x03,\x0b\x12\n\x0c\n\x05\x04\x04\x02\0\x03\
-    \x12\x03,\x15\x16\n\x0b\n\x04\x04\x04\x02\x01\x12\x03-\x04\x14\n\x0c\n\
-    \x05\x04\x04\x02\x01\x05\x12\x03-\x04\n\n\x0c\n\x05\x04\x04\x02\x01\x01\
-    \x12\x03-\x0b\x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03-\x12\x13\n\x0b\
-    \n\x04\x04\x04\x02\x02\x12\x03.\x04\x17\n\x0c\n\x05\x04\x04\x02\x02\x05\
-    \x12\x03.\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03.\x0b\x12\n\x0c\n\
-    \x05\x04\x04\x02\x02\x03\x12\x03.\x15\x16\n\x0b\n\x04\x04\x04\x02\x03\
-    \x12\x03/\x04\x16\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x03/\x04\n\n\x0c\n\
-    \x05\x04\x04\x02\x03\x01\x12\x03/\x0b\x11\n\x0c\n\x05\x04\x04\x02\x03\
-    \x03\x12\x03/\x14\x15\n\x0b\n\x04\x04\x04\x02\x04\x12\x030\x04\x15\n\x0c\
-    \n\x05\x04\x04\x02\x04\x05\x12\x030\x04\n\n\x0c\n\x05\x04\x04\x02\x04\
-    \x01\x12\x030\x0b\x10\n\x0c\n\x05\x04\x04\x02\x04\x03\x12\x030\x13\x14b\
+    \x1d\n\ncreated_at\x18\x06\x20\x01(\x04R\tcreatedAt\x12\x20\n\x0bfingerp\
+    rint\x18\x07\x20\x01(\x0cR\x0bfingerprintB\r\n\x0bseed_source\"\xa4\x01\
+    \n\nLedgerSeed\x12D\n\x0cfingerprints\x18\x01\x20\x03(\x0b2\x20.emerald.\
+    vault.HDPathFingerprintR\x0cfingerprints\x12!\n\x0cdevice_model\x18\x02\
+    \x20\x01(\tR\x0bdeviceModel\x12-\n\x12master_fingerprint\x18\x03\x20\x01\
+    (\x0cR\x11masterFingerprint\"\xc4\x01\n\x11HDPathFingerprint\x12)\n\x04p\
+    ath\x18\x01\x20\x01(\x0b2\x15.emerald.vault.HDPathR\x04path\x129\n\x04ty\
+    pe\x18\x02\x20\x01(\x0e2%.emerald.vault.HDPathFingerprint.TypeR\x04type\
+    \x12\x20\n\x0bfingerprint\x18\x03\x20\x01(\x0cR\x0bfingerprint\"'\n\x04T\
+    ype\x12\x0b\n\x07UNKNOWN\x10\0\x12\x12\n\x0eADDRESS_SHA256\x10\x01\"\x8e\
+    \x01\n\x06SeedHD\x12\x17\n\x07seed_id\x18\x01\x20\x01(\x0cR\x06seedId\
+    \x12+\n\x04path\x18\x02\x20\x01(\x0b2\x15.emerald.vault.HDPathH\0R\x04pa\
+    th\x121\n\x07account\x18\x03\x20\x01(\x0b2\x15.emerald.vault.HDPathH\0R\
+    \x07accountB\x0b\n\tpath_type\"~\n\x06HDPath\x12\x18\n\x07purpose\x18\
+    \x01\x20\x01(\rR\x07purpose\x12\x12\n\x04coin\x18\x02\x20\x01(\rR\x04coi\
+    n\x12\x18\n\x07account\x18\x03\x20\x01(\rR\x07account\x12\x16\n\x06chang\
+    e\x18\x04\x20\x01(\rR\x06change\x12\x14\n\x05index\x18\x05\x20\x01(\rR\
+    \x05indexJ\xbf\x10\n\x06\x12\x04\0\09\x01\n\x08\n\x01\x0c\x12\x03\0\0\
+    \x12\n\x08\n\x01\x02\x12\x03\x01\0\x16\n\t\n\x02\x03\0\x12\x03\x02\0\x16\
+    \n\t\n\x02\x03\x01\x12\x03\x03\0\x16\n\n\n\x02\x04\0\x12\x04\x05\0\x13\
+    \x01\n\n\n\x03\x04\0\x01\x12\x03\x05\x08\x0c\n\x0b\n\x04\x04\0\x02\0\x12\
+    \x03\x06\x04\x1b\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\x06\x04\x0c\n\x0c\n\
+    \x05\x04\0\x02\0\x01\x12\x03\x06\r\x16\n\x0c\n\x05\x04\0\x02\0\x03\x12\
+    \x03\x06\x19\x1a\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x07\x04\x11\n\x0c\n\
+    \x05\x04\0\x02\x01\x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\
+    \x12\x03\x07\n\x0c\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x07\x0f\x10\n\
+    \x0c\n\x04\x04\0\x08\0\x12\x04\x08\x04\x0b\x05\n\x0c\n\x05\x04\0\x08\0\
+    \x01\x12\x03\x08\n\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\t\x08\x1c\n\x0c\
+    \n\x05\x04\0\x02\x02\x06\x12\x03\t\x08\x11\n\x0c\n\x05\x04\0\x02\x02\x01\
+    \x12\x03\t\x12\x17\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\t\x1a\x1b\n\x0b\
+    \n\x04\x04\0\x02\x03\x12\x03\n\x08\x1e\n\x0c\n\x05\x04\0\x02\x03\x06\x12\
+    \x03\n\x08\x12\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\n\x13\x19\n\x0c\n\
+    \x05\x04\0\x02\x03\x03\x12\x03\n\x1c\x1d\n-\n\x04\x04\0\x02\x04\x12\x03\
+    \r\x04\x15\x1a\x20\x20User\x20assigned\x20label\x20(optional)\n\n\x0c\n\
+    \x05\x04\0\x02\x04\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\0\x02\x04\x01\x12\
+    \x03\r\x0b\x10\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\r\x13\x14\nD\n\x04\
+    \x04\0\x02\x05\x12\x03\x0f\x04\x1a\x1a7\x20creation\x20date\x20of\x20the\
+    \x20seed,\x20millis\x20since\x20epoch,\x20in\x20UTC\n\n\x0c\n\x05\x04\0\
+    \x02\x05\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x0f\
+    \x0b\x15\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x0f\x18\x19\n\xbd\x01\n\
+    \x04\x04\0\x02\x06\x12\x03\x12\x04\x1a\x1a\xaf\x01\x20Salted\x20hash\x20\
+    of\x20the\x20master\x20public\x20key,\x20to\x20detect\x20a\x20duplicate\
+    \x20seed\x20without\x20decrypting\x20it.\n\x2016\x20bytes\x20of\x20salt\
+    \x20followed\x20by\x20SHA256(salt\x20+\x20pubkey).\x20Empty\x20for\x20Le\
+    dger\x20and\x20older\x20seeds\n\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03\
+    \x12\x04\t\n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03\x12\n\x15\n\x0c\n\x05\
+    \x04\0\x02\x06\x03\x12\x03\x12\x18\x19\n\n\n\x02\x04\x01\x12\x04\x15\0\
+    \x1c\x01\n\n\n\x03\x04\x01\x01\x12\x03\x15\x08\x12\n\x0b\n\x04\x04\x01\
+    \x02\0\x12\x03\x16\x040\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x16\x04\
+    \x0c\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x16\r\x1e\n\x0c\n\x05\x04\x01\
+    \x02\0\x01\x12\x03\x16\x1f+\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x16./\
+    \n[\n\x04\x04\x01\x02\x01\x12\x03\x18\x04\x1c\x1aN\x20Model\x20of\x20the\
+    \x20device\x20used\x20to\x20create\x20the\x20seed\x20(ex.\x20\"Nano\x20X\
+    \").\x20Empty\x20if\x20unknown\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\
+    \x18\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x18\x0b\x17\n\x0c\n\
+    \x05\x04\x01\x02\x01\x03\x12\x03\x18\x1a\x1b\n\xa8\x01\n\x04\x04\x01\x02\
+    \x02\x12\x03\x1b\x04!\x1a\x9a\x01\x20BIP32\x20fingerprint\x20of\x20the\
+    \x20master\x20public\x20key\x20of\x20the\x20device,\x20i.e.\x20first\x20\
+    4\x20bytes\x20of\x20HASH160(pubkey).\n\x20Empty\x20if\x20it\x20wasn't\
+    \x20available\x20when\x20the\x20seed\x20was\x20created\n\n\x0c\n\x05\x04\
+    \x01\x02\x02\x05\x12\x03\x1b\x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\
+    \x03\x1b\n\x1c\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x1b\x1f\x20\n\n\n\
+    \x02\x04\x02\x12\x04\x1e\0'\x01\n\n\n\x03\x04\x02\x01\x12\x03\x1e\x08\
+    \x19\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x1f\x04\x14\n\x0c\n\x05\x04\x02\
+    \x02\0\x06\x12\x03\x1f\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x1f\
+    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x1f\x12\x13\n\x0b\n\x04\
+    \x04\x02\x02\x01\x12\x03\x20\x04\x12\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\
+    \x03\x20\x04\x08\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x20\t\r\n\x0c\n\
+    \x05\x04\x02\x02\x01\x03\x12\x03\x20\x10\x11\n\x0b\n\x04\x04\x02\x02\x02\
+    \x12\x03!\x04\x1a\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03!\x04\t\n\x0c\n\
+    \x05\x04\x02\x02\x02\x01\x12\x03!\n\x15\n\x0c\n\x05\x04\x02\x02\x02\x03\
+    \x12\x03!\x18\x19\n\x0c\n\x04\x04\x02\x04\0\x12\x04#\x04&\x05\n\x0c\n\
+    \x05\x04\x02\x04\0\x01\x12\x03#\t\r\n\r\n\x06\x04\x02\x04\0\x02\0\x12\
+    \x03$\x08\x14\n\x0e\n\x07\x04\x02\x04\0\x02\0\x01\x12\x03$\x08\x0f\n\x0e\
+    \n\x07\x04\x02\x04\0\x02\0\x02\x12\x03$\x12\x13\n\r\n\x06\x04\x02\x04\0\
+    \x02\x01\x12\x03%\x08\x1b\n\x0e\n\x07\x04\x02\x04\0\x02\x01\x01\x12\x03%\
+    \x08\x16\n\x0e\n\x07\x04\x02\x04\0\x02\x01\x02\x12\x03%\x19\x1a\n\n\n\
+    \x02\x04\x03\x12\x04)\01\x01\n\n\n\x03\x04\x03\x01\x12\x03)\x08\x0e\n\
+    \x0b\n\x04\x04\x03\x02\0\x12\x03*\x04\x16\n\x0c\n\x05\x04\x03\x02\0\x05\
+    \x12\x03*\x04\t\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03*\n\x11\n\x0c\n\x05\
+    \x04\x03\x02\0\x03\x12\x03*\x14\x15\n\x0c\n\x04\x04\x03\x08\0\x12\x04+\
+    \x040\x05\n\x0c\n\x05\x04\x03\x08\0\x01\x12\x03+\n\x13\n9\n\x04\x04\x03\
+    \x02\x01\x12\x03-\x08\x18\x1a,full\x20m/purpose'/coin'/account'/change/i\
+    ndex\n\n\x0c\n\x05\x04\x03\x02\x01\x06\x12\x03-\x08\x0e\n\x0c\n\x05\x04\
+    \x03\x02\x01\x01\x12\x03-\x0f\x13\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\
+    \x03-\x16\x17\n3\n\x04\x04\x03\x02\x02\x12\x03/\x08\x1b\x1a&short,\x20on\
+    ly\x20m/purpose'/coin'/account'\n\n\x0c\n\x05\x04\x03\x02\x02\x06\x12\
+    \x03/\x08\x0e\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03/\x0f\x16\n\x0c\n\
+    \x05\x04\x03\x02\x02\x03\x12\x03/\x19\x1a\n\n\n\x02\x04\x04\x12\x043\09\
+    \x01\n\n\n\x03\x04\x04\x01\x12\x033\x08\x0e\n\x0b\n\x04\x04\x04\x02\0\
+    \x12\x034\x04\x17\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x034\x04\n\n\x0c\n\
+    \x05\x04\x04\x02\0\x01\x12\x034\x0b\x12\n\x0c\n\x05\x04\x04\x02\0\x03\
+    \x12\x034\x15\x16\n\x0b\n\x04\x04\x04\x02\x01\x12\x035\x04\x14\n\x0c\n\
+    \x05\x04\x04\x02\x01\x05\x12\x035\x04\n\n\x0c\n\x05\x04\x04\x02\x01\x01\
+    \x12\x035\x0b\x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x035\x12\x13\n\x0b\
+    \n\x04\x04\x04\x02\x02\x12\x036\x04\x17\n\x0c\n\x05\x04\x04\x02\x02\x05\
+    \x12\x036\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x036\x0b\x12\n\x0c\n\
+    \x05\x04\x04\x02\x02\x03\x12\x036\x15\x16\n\x0b\n\x04\x04\x04\x02\x03\
+    \x12\x037\x04\x16\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x037\x04\n\n\x0c\n\
+    \x05\x04\x04\x02\x03\x01\x12\x037\x0b\x11\n\x0c\n\x05\x04\x04\x02\x03\
+    \x03\x12\x037\x14\x15\n\x0b\n\x04\x04\x04\x02\x04\x12\x038\x04\x15\n\x0c\
+    \n\x05\x04\x04\x02\x04\x05\x12\x038\x04\n\n\x0c\n\x05\x04\x04\x02\x04\
+    \x01\x12\x038\x0b\x10\n\x0c\n\x05\x04\x04\x02\x04\x03\x12\x038\x13\x14b\
     \x06proto3\
 ";
 
@@ -36,6 +36,7 @@ pub struct Wallet {
     pub entries: ::protobuf::RepeatedField<WalletEntry>,
     pub entry_seq: u32,
     pub created_at: u64,
+    pub order: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -198,6 +199,21 @@ impl Wallet {
     pub fn set_created_at(&mut self, v: u64) {
         self.created_at = v;
     }
+
+    // uint32 order = 8;
+
+
+    pub fn get_order(&self) -> u32 {
+        self.order
+    }
+    pub fn clear_order(&mut self) {
+        self.order = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_order(&mut self, v: u32) {
+        self.order = v;
+    }
 }
 
 impl ::protobuf::Message for Wallet {
@@ -248,6 +264,13 @@ impl ::protobuf::Message for Wallet {
                     let tmp = is.read_uint64()?;
                     self.created_at = tmp;
                 },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.order = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -283,6 +306,9 @@ impl ::protobuf::Message for Wallet {
         if self.created_at != 0 {
             my_size += ::protobuf::rt::value_size(7, self.created_at, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.order != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.order, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -314,6 +340,9 @@ impl ::protobuf::Message for Wallet {
         if self.created_at != 0 {
             os.write_uint64(7, self.created_at)?;
         }
+        if self.order != 0 {
+            os.write_uint32(8, self.order)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -391,6 +420,11 @@ impl ::protobuf::Message for Wallet {
                     |m: &Wallet| { &m.created_at },
                     |m: &mut Wallet| { &mut m.created_at },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "order",
+                    |m: &Wallet| { &m.order },
+                    |m: &mut Wallet| { &mut m.order },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Wallet>(
                     "Wallet",
                     fields,
@@ -420,6 +454,7 @@ impl ::protobuf::Clear for Wallet {
         self.entries.clear();
         self.entry_seq = 0;
         self.created_at = 0;
+        self.order = 0;
         self.unknown_fields.clear();
     }
 }
@@ -649,6 +684,7 @@ pub struct WalletEntry {
     pub address: ::protobuf::SingularPtrField<super::address::Address>,
     pub label: ::std::string::String,
     pub created_at: u64,
+    pub address_labels: ::protobuf::RepeatedField<AddressLabel>,
     // message oneof groups
     pub pk_type: ::std::option::Option<WalletEntry_oneof_pk_type>,
     // special fields
@@ -666,6 +702,7 @@ impl<'a> ::std::default::Default for &'a WalletEntry {
 pub enum WalletEntry_oneof_pk_type {
     hd_path(super::seed::SeedHD),
     pk_id(::std::vec::Vec<u8>),
+    watch_only(bool),
 }
 
 impl WalletEntry {
@@ -875,6 +912,31 @@ impl WalletEntry {
         }
     }
 
+    // bool watch_only = 11;
+
+
+    pub fn get_watch_only(&self) -> bool {
+        match self.pk_type {
+            ::std::option::Option::Some(WalletEntry_oneof_pk_type::watch_only(v)) => v,
+            _ => false,
+        }
+    }
+    pub fn clear_watch_only(&mut self) {
+        self.pk_type = ::std::option::Option::None;
+    }
+
+    pub fn has_watch_only(&self) -> bool {
+        match self.pk_type {
+            ::std::option::Option::Some(WalletEntry_oneof_pk_type::watch_only(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_watch_only(&mut self, v: bool) {
+        self.pk_type = ::std::option::Option::Some(WalletEntry_oneof_pk_type::watch_only(v))
+    }
+
     // uint64 created_at = 9;
 
 
@@ -889,6 +951,31 @@ impl WalletEntry {
     pub fn set_created_at(&mut self, v: u64) {
         self.created_at = v;
     }
+
+    // repeated .emerald.vault.AddressLabel address_labels = 10;
+
+
+    pub fn get_address_labels(&self) -> &[AddressLabel] {
+        &self.address_labels
+    }
+    pub fn clear_address_labels(&mut self) {
+        self.address_labels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_address_labels(&mut self, v: ::protobuf::RepeatedField<AddressLabel>) {
+        self.address_labels = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_address_labels(&mut self) -> &mut ::protobuf::RepeatedField<AddressLabel> {
+        &mut self.address_labels
+    }
+
+    // Take field
+    pub fn take_address_labels(&mut self) -> ::protobuf::RepeatedField<AddressLabel> {
+        ::std::mem::replace(&mut self.address_labels, ::protobuf::RepeatedField::new())
+    }
 }
 
 impl ::protobuf::Message for WalletEntry {
@@ -898,6 +985,11 @@ impl ::protobuf::Message for WalletEntry {
                 return false;
             }
         };
+        for v in &self.address_labels {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         if let Some(WalletEntry_oneof_pk_type::hd_path(ref v)) = self.pk_type {
             if !v.is_initialized() {
                 return false;
@@ -949,6 +1041,12 @@ impl ::protobuf::Message for WalletEntry {
                     }
                     self.pk_type = ::std::option::Option::Some(WalletEntry_oneof_pk_type::pk_id(is.read_bytes()?));
                 },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.pk_type = ::std::option::Option::Some(WalletEntry_oneof_pk_type::watch_only(is.read_bool()?));
+                },
                 9 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
@@ -956,6 +1054,9 @@ impl ::protobuf::Message for WalletEntry {
                     let tmp = is.read_uint64()?;
                     self.created_at = tmp;
                 },
+                10 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.address_labels)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -987,6 +1088,10 @@ impl ::protobuf::Message for WalletEntry {
         if self.created_at != 0 {
             my_size += ::protobuf::rt::value_size(9, self.created_at, ::protobuf::wire_format::WireTypeVarint);
         }
+        for value in &self.address_labels {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         if let ::std::option::Option::Some(ref v) = self.pk_type {
             match v {
                 &WalletEntry_oneof_pk_type::hd_path(ref v) => {
@@ -996,6 +1101,9 @@ impl ::protobuf::Message for WalletEntry {
                 &WalletEntry_oneof_pk_type::pk_id(ref v) => {
                     my_size += ::protobuf::rt::bytes_size(8, &v);
                 },
+                &WalletEntry_oneof_pk_type::watch_only(v) => {
+                    my_size += 2;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -1024,6 +1132,11 @@ impl ::protobuf::Message for WalletEntry {
         if self.created_at != 0 {
             os.write_uint64(9, self.created_at)?;
         }
+        for v in &self.address_labels {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         if let ::std::option::Option::Some(ref v) = self.pk_type {
             match v {
                 &WalletEntry_oneof_pk_type::hd_path(ref v) => {
@@ -1034,6 +1147,9 @@ impl ::protobuf::Message for WalletEntry {
                 &WalletEntry_oneof_pk_type::pk_id(ref v) => {
                     os.write_bytes(8, v)?;
                 },
+                &WalletEntry_oneof_pk_type::watch_only(v) => {
+                    os.write_bool(11, v)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -1113,11 +1229,21 @@ impl ::protobuf::Message for WalletEntry {
                     WalletEntry::has_pk_id,
                     WalletEntry::get_pk_id,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor::<_>(
+                    "watch_only",
+                    WalletEntry::has_watch_only,
+                    WalletEntry::get_watch_only,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "created_at",
                     |m: &WalletEntry| { &m.created_at },
                     |m: &mut WalletEntry| { &mut m.created_at },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AddressLabel>>(
+                    "address_labels",
+                    |m: &WalletEntry| { &m.address_labels },
+                    |m: &mut WalletEntry| { &mut m.address_labels },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<WalletEntry>(
                     "WalletEntry",
                     fields,
@@ -1147,7 +1273,9 @@ impl ::protobuf::Clear for WalletEntry {
         self.label.clear();
         self.pk_type = ::std::option::Option::None;
         self.pk_type = ::std::option::Option::None;
+        self.pk_type = ::std::option::Option::None;
         self.created_at = 0;
+        self.address_labels.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1164,103 +1292,334 @@ impl ::protobuf::reflect::ProtobufValue for WalletEntry {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct AddressLabel {
+    // message fields
+    pub index: u32,
+    pub label: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AddressLabel {
+    fn default() -> &'a AddressLabel {
+        <AddressLabel as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AddressLabel {
+    pub fn new() -> AddressLabel {
+        ::std::default::Default::default()
+    }
+
+    // uint32 index = 1;
+
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u32) {
+        self.index = v;
+    }
+
+    // string label = 2;
+
+
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+    pub fn clear_label(&mut self) {
+        self.label.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_label(&mut self, v: ::std::string::String) {
+        self.label = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_label(&mut self) -> &mut ::std::string::String {
+        &mut self.label
+    }
+
+    // Take field
+    pub fn take_label(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.label, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AddressLabel {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.index = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.label)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.label.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.label);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.index != 0 {
+            os.write_uint32(1, self.index)?;
+        }
+        if !self.label.is_empty() {
+            os.write_string(2, &self.label)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AddressLabel {
+        AddressLabel::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "index",
+                    |m: &AddressLabel| { &m.index },
+                    |m: &mut AddressLabel| { &mut m.index },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "label",
+                    |m: &AddressLabel| { &m.label },
+                    |m: &mut AddressLabel| { &mut m.label },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AddressLabel>(
+                    "AddressLabel",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static AddressLabel {
+        static mut instance: ::protobuf::lazy::Lazy<AddressLabel> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AddressLabel,
+        };
+        unsafe {
+            instance.get(AddressLabel::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for AddressLabel {
+    fn clear(&mut self) {
+        self.index = 0;
+        self.label.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AddressLabel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AddressLabel {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0cwallet.proto\x12\remerald.vault\x1a\raddress.proto\x1a\nseed.proto\
-    \x1a\x0ccommon.proto\"\x90\x02\n\x06Wallet\x124\n\tfile_type\x18\x01\x20\
+    \x1a\x0ccommon.proto\"\xa6\x02\n\x06Wallet\x124\n\tfile_type\x18\x01\x20\
     \x01(\x0e2\x17.emerald.vault.FileTypeR\x08fileType\x12\x0e\n\x02id\x18\
     \x02\x20\x01(\x0cR\x02id\x12\x14\n\x05label\x18\x03\x20\x01(\tR\x05label\
     \x128\n\x0bhd_accounts\x18\x04\x20\x03(\x0b2\x17.emerald.vault.ReservedR\
     \nhdAccounts\x124\n\x07entries\x18\x05\x20\x03(\x0b2\x1a.emerald.vault.W\
     alletEntryR\x07entries\x12\x1b\n\tentry_seq\x18\x06\x20\x01(\rR\x08entry\
-    Seq\x12\x1d\n\ncreated_at\x18\x07\x20\x01(\x04R\tcreatedAt\"B\n\x08Reser\
-    ved\x12\x17\n\x07seed_id\x18\x01\x20\x01(\x0cR\x06seedId\x12\x1d\n\nacco\
-    unt_id\x18\x02\x20\x01(\rR\taccountId\"\xa8\x02\n\x0bWalletEntry\x12\x0e\
-    \n\x02id\x18\x01\x20\x01(\rR\x02id\x12#\n\rblockchain_id\x18\x02\x20\x01\
-    (\rR\x0cblockchainId\x12)\n\x10receive_disabled\x18\x03\x20\x01(\x08R\
-    \x0freceiveDisabled\x120\n\x07address\x18\x05\x20\x01(\x0b2\x16.emerald.\
-    vault.AddressR\x07address\x12\x14\n\x05label\x18\x06\x20\x01(\tR\x05labe\
-    l\x120\n\x07hd_path\x18\x07\x20\x01(\x0b2\x15.emerald.vault.SeedHDH\0R\
-    \x06hdPath\x12\x15\n\x05pk_id\x18\x08\x20\x01(\x0cH\0R\x04pkId\x12\x1d\n\
-    \ncreated_at\x18\t\x20\x01(\x04R\tcreatedAtB\t\n\x07pk_typeJ\x9a\x11\n\
-    \x06\x12\x04\0\09\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\
-    \x12\x03\x01\0\x16\n\t\n\x02\x03\0\x12\x03\x02\0\x17\n\t\n\x02\x03\x01\
-    \x12\x03\x03\0\x14\n\t\n\x02\x03\x02\x12\x03\x04\0\x16\n\xa5\x01\n\x02\
-    \x04\0\x12\x04\x08\0\x1b\x01\x1a\x98\x01\x20Wallet\x20is\x20a\x20group\
-    \x20of\x20entries\x20used\x20together.\x20An\x20entry\x20may\x20be\x20a\
-    \x20private\x20key\x20for\x20a\x20single\x20address,\n\x20or\x20a\x20gro\
-    up\x20of\x20addresses\x20specified\x20by\x20HDPath\x20on\x20a\x20Seed\n\
-    \n\n\n\x03\x04\0\x01\x12\x03\x08\x08\x0e\n\x0b\n\x04\x04\0\x02\0\x12\x03\
-    \t\x04\x1b\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\t\x04\x0c\n\x0c\n\x05\x04\
-    \0\x02\0\x01\x12\x03\t\r\x16\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\t\x19\
-    \x1a\n\x13\n\x04\x04\0\x02\x01\x12\x03\x0b\x04\x11\x1a\x06\x20UUID\n\n\
-    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x0b\x04\t\n\x0c\n\x05\x04\0\x02\x01\
-    \x01\x12\x03\x0b\n\x0c\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x0b\x0f\x10\
-    \n\"\n\x04\x04\0\x02\x02\x12\x03\r\x04\x15\x1a\x15\x20User\x20assigned\
-    \x20label\n\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\r\x04\n\n\x0c\n\x05\
-    \x04\0\x02\x02\x01\x12\x03\r\x0b\x10\n\x0c\n\x05\x04\0\x02\x02\x03\x12\
-    \x03\r\x13\x14\n\x9c\x01\n\x04\x04\0\x02\x03\x12\x03\x11\x04&\x1a\x8e\
-    \x01\x20List\x20of\x20HDPath\x20accounts\x20that\x20belongs\x20to\x20the\
-    \x20wallet,\x20used\x20to\x20automate\x20finding\x20new\n\x20addresses\
-    \x20and\x20avoiding\x20collision\x20between\x20different\x20wallets\n\n\
-    \x0c\n\x05\x04\0\x02\x03\x04\x12\x03\x11\x04\x0c\n\x0c\n\x05\x04\0\x02\
-    \x03\x06\x12\x03\x11\r\x15\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x11\x16\
-    !\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x11$%\n+\n\x04\x04\0\x02\x04\x12\
-    \x03\x13\x04%\x1a\x1e\x20Actual\x20entries\x20in\x20the\x20wallet\n\n\
-    \x0c\n\x05\x04\0\x02\x04\x04\x12\x03\x13\x04\x0c\n\x0c\n\x05\x04\0\x02\
-    \x04\x06\x12\x03\x13\r\x18\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x13\x19\
-    \x20\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x13#$\n\xd0\x01\n\x04\x04\0\
-    \x02\x05\x12\x03\x17\x04\x19\x1a\xc2\x01\x20Sequence\x20for\x20entry\x20\
-    ids\x20in\x20that\x20wallet.\x20Incremented\x20each\x20time\x20a\x20new\
-    \x20entry\x20added,\x20and\x20used\n\x20as\x20the\x20id\x20that\x20new\
-    \x20entry.\x20Using\x20this\x20sequence,\x20if\x20entry\x20gets\x20delet\
-    ed\x20its\x20id\x20is\x20not\x20going\n\x20to\x20be\x20reused\n\n\x0c\n\
-    \x05\x04\0\x02\x05\x05\x12\x03\x17\x04\n\n\x0c\n\x05\x04\0\x02\x05\x01\
-    \x12\x03\x17\x0b\x14\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x17\x17\x18\n\
-    F\n\x04\x04\0\x02\x06\x12\x03\x1a\x04\x1a\x1a9\x20creation\x20date\x20of\
-    \x20the\x20wallet,\x20millis\x20since\x20epoch,\x20in\x20UTC\n\n\x0c\n\
-    \x05\x04\0\x02\x06\x05\x12\x03\x1a\x04\n\n\x0c\n\x05\x04\0\x02\x06\x01\
-    \x12\x03\x1a\x0b\x15\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\x1a\x18\x19\n\
-    =\n\x02\x04\x01\x12\x04\x1e\0#\x01\x1a1\x20Reserved\x20index\x20on\x20a\
-    \x20HD\x20path,\x20an\x20x\x20from\x20m/44'/x'\n\n\n\n\x03\x04\x01\x01\
-    \x12\x03\x1e\x08\x10\n)\n\x04\x04\x01\x02\0\x12\x03\x20\x04\x16\x1a\x1c\
-    \x20reference\x20to\x20a\x20source\x20seed\n\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x20\x04\t\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x20\n\x11\n\
-    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x20\x14\x15\n*\n\x04\x04\x01\x02\
-    \x01\x12\x03\"\x04\x1a\x1a\x1d\x20reserved\x20HD\x20Path\x20account\x20i\
-    d\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\"\x04\n\n\x0c\n\x05\x04\x01\
-    \x02\x01\x01\x12\x03\"\x0b\x15\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\"\
-    \x18\x19\n\x1b\n\x02\x04\x02\x12\x04&\09\x01\x1a\x0f\x20Entry\x20details\
-    \n\n\n\n\x03\x04\x02\x01\x12\x03&\x08\x13\n/\n\x04\x04\x02\x02\0\x12\x03\
-    (\x04\x12\x1a\"\x20id\x20to\x20reference\x20entry\x20per\x20wallet\n\n\
-    \x0c\n\x05\x04\x02\x02\0\x05\x12\x03(\x04\n\n\x0c\n\x05\x04\x02\x02\0\
-    \x01\x12\x03(\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03(\x10\x11\ne\n\
-    \x04\x04\x02\x02\x01\x12\x03*\x04\x1d\x1aX\x20Associated\x20blockchain;\
-    \x20entry\x20may\x20have\x20multiple\x20different\x20assets\x20on\x20a\
-    \x20single\x20blockchain\n\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03*\x04\
-    \n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03*\x0b\x18\n\x0c\n\x05\x04\x02\
-    \x02\x01\x03\x12\x03*\x1b\x1c\nM\n\x04\x04\x02\x02\x02\x12\x03,\x04\x1e\
-    \x1a@\x20true\x20if\x20entry\x20is\x20disabled\x20for\x20receiving,\x20i\
-    .e.\x20only\x20for\x20sending\n\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03,\
-    \x04\x08\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03,\t\x19\n\x0c\n\x05\x04\
-    \x02\x02\x02\x03\x12\x03,\x1c\x1d\n,\n\x04\x04\x02\x02\x03\x12\x03.\x04\
-    \x18\x1a\x1f\x20public\x20address,\x20for\x20reference\n\n\x0c\n\x05\x04\
-    \x02\x02\x03\x06\x12\x03.\x04\x0b\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\
-    \x03.\x0c\x13\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03.\x16\x17\n\"\n\x04\
-    \x04\x02\x02\x04\x12\x030\x04\x15\x1a\x15\x20user\x20assigned\x20label\n\
-    \n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x030\x04\n\n\x0c\n\x05\x04\x02\x02\
-    \x04\x01\x12\x030\x0b\x10\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x030\x13\
-    \x14\n!\n\x04\x04\x02\x08\0\x12\x042\x045\x05\x1a\x13\x20link\x20to\x20a\
-    ctual\x20PK\n\n\x0c\n\x05\x04\x02\x08\0\x01\x12\x032\n\x11\n\x0b\n\x04\
-    \x04\x02\x02\x05\x12\x033\x08\x1b\n\x0c\n\x05\x04\x02\x02\x05\x06\x12\
-    \x033\x08\x0e\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x033\x0f\x16\n\x0c\n\
-    \x05\x04\x02\x02\x05\x03\x12\x033\x19\x1a\n\x0b\n\x04\x04\x02\x02\x06\
-    \x12\x034\x08\x18\n\x0c\n\x05\x04\x02\x02\x06\x05\x12\x034\x08\r\n\x0c\n\
-    \x05\x04\x02\x02\x06\x01\x12\x034\x0e\x13\n\x0c\n\x05\x04\x02\x02\x06\
-    \x03\x12\x034\x16\x17\nE\n\x04\x04\x02\x02\x07\x12\x038\x04\x1a\x1a8\x20\
-    creation\x20date\x20of\x20the\x20entry,\x20millis\x20since\x20epoch,\x20\
-    in\x20UTC\n\n\x0c\n\x05\x04\x02\x02\x07\x05\x12\x038\x04\n\n\x0c\n\x05\
-    \x04\x02\x02\x07\x01\x12\x038\x0b\x15\n\x0c\n\x05\x04\x02\x02\x07\x03\
-    \x12\x038\x18\x19b\x06proto3\
+    Seq\x12\x1d\n\ncreated_at\x18\x07\x20\x01(\x04R\tcreatedAt\x12\x14\n\x05\
+    order\x18\x08\x20\x01(\rR\x05order\"B\n\x08Reserved\x12\x17\n\x07seed_id\
+    \x18\x01\x20\x01(\x0cR\x06seedId\x12\x1d\n\naccount_id\x18\x02\x20\x01(\
+    \rR\taccountId\"\x8d\x03\n\x0bWalletEntry\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\rR\x02id\x12#\n\rblockchain_id\x18\x02\x20\x01(\rR\x0cblockchainId\
+    \x12)\n\x10receive_disabled\x18\x03\x20\x01(\x08R\x0freceiveDisabled\x12\
+    0\n\x07address\x18\x05\x20\x01(\x0b2\x16.emerald.vault.AddressR\x07addre\
+    ss\x12\x14\n\x05label\x18\x06\x20\x01(\tR\x05label\x120\n\x07hd_path\x18\
+    \x07\x20\x01(\x0b2\x15.emerald.vault.SeedHDH\0R\x06hdPath\x12\x15\n\x05p\
+    k_id\x18\x08\x20\x01(\x0cH\0R\x04pkId\x12\x1f\n\nwatch_only\x18\x0b\x20\
+    \x01(\x08H\0R\twatchOnly\x12\x1d\n\ncreated_at\x18\t\x20\x01(\x04R\tcrea\
+    tedAt\x12B\n\x0eaddress_labels\x18\n\x20\x03(\x0b2\x1b.emerald.vault.Add\
+    ressLabelR\raddressLabelsB\t\n\x07pk_type\":\n\x0cAddressLabel\x12\x14\n\
+    \x05index\x18\x01\x20\x01(\rR\x05index\x12\x14\n\x05label\x18\x02\x20\
+    \x01(\tR\x05labelJ\xbe\x16\n\x06\x12\x04\0\0I\x01\n\x08\n\x01\x0c\x12\
+    \x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\0\x16\n\t\n\x02\x03\0\x12\x03\
+    \x02\0\x17\n\t\n\x02\x03\x01\x12\x03\x03\0\x14\n\t\n\x02\x03\x02\x12\x03\
+    \x04\0\x16\n\xa5\x01\n\x02\x04\0\x12\x04\x08\0\x1f\x01\x1a\x98\x01\x20Wa\
+    llet\x20is\x20a\x20group\x20of\x20entries\x20used\x20together.\x20An\x20\
+    entry\x20may\x20be\x20a\x20private\x20key\x20for\x20a\x20single\x20addre\
+    ss,\n\x20or\x20a\x20group\x20of\x20addresses\x20specified\x20by\x20HDPat\
+    h\x20on\x20a\x20Seed\n\n\n\n\x03\x04\0\x01\x12\x03\x08\x08\x0e\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\t\x04\x1b\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\t\
+    \x04\x0c\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\t\r\x16\n\x0c\n\x05\x04\0\
+    \x02\0\x03\x12\x03\t\x19\x1a\n\x13\n\x04\x04\0\x02\x01\x12\x03\x0b\x04\
+    \x11\x1a\x06\x20UUID\n\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x0b\x04\t\n\
+    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x0b\n\x0c\n\x0c\n\x05\x04\0\x02\x01\
+    \x03\x12\x03\x0b\x0f\x10\n\"\n\x04\x04\0\x02\x02\x12\x03\r\x04\x15\x1a\
+    \x15\x20User\x20assigned\x20label\n\n\x0c\n\x05\x04\0\x02\x02\x05\x12\
+    \x03\r\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\r\x0b\x10\n\x0c\n\x05\
+    \x04\0\x02\x02\x03\x12\x03\r\x13\x14\n\x9c\x01\n\x04\x04\0\x02\x03\x12\
+    \x03\x11\x04&\x1a\x8e\x01\x20List\x20of\x20HDPath\x20accounts\x20that\
+    \x20belongs\x20to\x20the\x20wallet,\x20used\x20to\x20automate\x20finding\
+    \x20new\n\x20addresses\x20and\x20avoiding\x20collision\x20between\x20dif\
+    ferent\x20wallets\n\n\x0c\n\x05\x04\0\x02\x03\x04\x12\x03\x11\x04\x0c\n\
+    \x0c\n\x05\x04\0\x02\x03\x06\x12\x03\x11\r\x15\n\x0c\n\x05\x04\0\x02\x03\
+    \x01\x12\x03\x11\x16!\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x11$%\n+\n\
+    \x04\x04\0\x02\x04\x12\x03\x13\x04%\x1a\x1e\x20Actual\x20entries\x20in\
+    \x20the\x20wallet\n\n\x0c\n\x05\x04\0\x02\x04\x04\x12\x03\x13\x04\x0c\n\
+    \x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x13\r\x18\n\x0c\n\x05\x04\0\x02\x04\
+    \x01\x12\x03\x13\x19\x20\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x13#$\n\
+    \xd0\x01\n\x04\x04\0\x02\x05\x12\x03\x17\x04\x19\x1a\xc2\x01\x20Sequence\
+    \x20for\x20entry\x20ids\x20in\x20that\x20wallet.\x20Incremented\x20each\
+    \x20time\x20a\x20new\x20entry\x20added,\x20and\x20used\n\x20as\x20the\
+    \x20id\x20that\x20new\x20entry.\x20Using\x20this\x20sequence,\x20if\x20e\
+    ntry\x20gets\x20deleted\x20its\x20id\x20is\x20not\x20going\n\x20to\x20be\
+    \x20reused\n\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x17\x04\n\n\x0c\n\x05\
+    \x04\0\x02\x05\x01\x12\x03\x17\x0b\x14\n\x0c\n\x05\x04\0\x02\x05\x03\x12\
+    \x03\x17\x17\x18\nF\n\x04\x04\0\x02\x06\x12\x03\x1a\x04\x1a\x1a9\x20crea\
+    tion\x20date\x20of\x20the\x20wallet,\x20millis\x20since\x20epoch,\x20in\
+    \x20UTC\n\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03\x1a\x04\n\n\x0c\n\x05\
+    \x04\0\x02\x06\x01\x12\x03\x1a\x0b\x15\n\x0c\n\x05\x04\0\x02\x06\x03\x12\
+    \x03\x1a\x18\x19\n|\n\x04\x04\0\x02\x07\x12\x03\x1e\x04\x15\x1ao\x20Posi\
+    tion\x20of\x20the\x20wallet\x20in\x20the\x20list,\x20as\x20set\x20by\x20\
+    the\x20user.\x200\x20if\x20not\x20set,\x20such\x20wallets\x20go\n\x20aft\
+    er\x20the\x20ordered\x20ones\n\n\x0c\n\x05\x04\0\x02\x07\x05\x12\x03\x1e\
+    \x04\n\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\x1e\x0b\x10\n\x0c\n\x05\x04\
+    \0\x02\x07\x03\x12\x03\x1e\x13\x14\n=\n\x02\x04\x01\x12\x04\"\0'\x01\x1a\
+    1\x20Reserved\x20index\x20on\x20a\x20HD\x20path,\x20an\x20x\x20from\x20m\
+    /44'/x'\n\n\n\n\x03\x04\x01\x01\x12\x03\"\x08\x10\n)\n\x04\x04\x01\x02\0\
+    \x12\x03$\x04\x16\x1a\x1c\x20reference\x20to\x20a\x20source\x20seed\n\n\
+    \x0c\n\x05\x04\x01\x02\0\x05\x12\x03$\x04\t\n\x0c\n\x05\x04\x01\x02\0\
+    \x01\x12\x03$\n\x11\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03$\x14\x15\n*\n\
+    \x04\x04\x01\x02\x01\x12\x03&\x04\x1a\x1a\x1d\x20reserved\x20HD\x20Path\
+    \x20account\x20id\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03&\x04\n\n\x0c\
+    \n\x05\x04\x01\x02\x01\x01\x12\x03&\x0b\x15\n\x0c\n\x05\x04\x01\x02\x01\
+    \x03\x12\x03&\x18\x19\n\x1b\n\x02\x04\x02\x12\x04*\0A\x01\x1a\x0f\x20Ent\
+    ry\x20details\n\n\n\n\x03\x04\x02\x01\x12\x03*\x08\x13\n/\n\x04\x04\x02\
+    \x02\0\x12\x03,\x04\x12\x1a\"\x20id\x20to\x20reference\x20entry\x20per\
+    \x20wallet\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03,\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03,\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03,\
+    \x10\x11\ne\n\x04\x04\x02\x02\x01\x12\x03.\x04\x1d\x1aX\x20Associated\
+    \x20blockchain;\x20entry\x20may\x20have\x20multiple\x20different\x20asse\
+    ts\x20on\x20a\x20single\x20blockchain\n\n\x0c\n\x05\x04\x02\x02\x01\x05\
+    \x12\x03.\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03.\x0b\x18\n\x0c\n\
+    \x05\x04\x02\x02\x01\x03\x12\x03.\x1b\x1c\nM\n\x04\x04\x02\x02\x02\x12\
+    \x030\x04\x1e\x1a@\x20true\x20if\x20entry\x20is\x20disabled\x20for\x20re\
+    ceiving,\x20i.e.\x20only\x20for\x20sending\n\n\x0c\n\x05\x04\x02\x02\x02\
+    \x05\x12\x030\x04\x08\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x030\t\x19\n\
+    \x0c\n\x05\x04\x02\x02\x02\x03\x12\x030\x1c\x1d\n,\n\x04\x04\x02\x02\x03\
+    \x12\x032\x04\x18\x1a\x1f\x20public\x20address,\x20for\x20reference\n\n\
+    \x0c\n\x05\x04\x02\x02\x03\x06\x12\x032\x04\x0b\n\x0c\n\x05\x04\x02\x02\
+    \x03\x01\x12\x032\x0c\x13\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x032\x16\
+    \x17\n\"\n\x04\x04\x02\x02\x04\x12\x034\x04\x15\x1a\x15\x20user\x20assig\
+    ned\x20label\n\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x034\x04\n\n\x0c\n\
+    \x05\x04\x02\x02\x04\x01\x12\x034\x0b\x10\n\x0c\n\x05\x04\x02\x02\x04\
+    \x03\x12\x034\x13\x14\n!\n\x04\x04\x02\x08\0\x12\x046\x04;\x05\x1a\x13\
+    \x20link\x20to\x20actual\x20PK\n\n\x0c\n\x05\x04\x02\x08\0\x01\x12\x036\
+    \n\x11\n\x0b\n\x04\x04\x02\x02\x05\x12\x037\x08\x1b\n\x0c\n\x05\x04\x02\
+    \x02\x05\x06\x12\x037\x08\x0e\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x037\
+    \x0f\x16\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x037\x19\x1a\n\x0b\n\x04\
+    \x04\x02\x02\x06\x12\x038\x08\x18\n\x0c\n\x05\x04\x02\x02\x06\x05\x12\
+    \x038\x08\r\n\x0c\n\x05\x04\x02\x02\x06\x01\x12\x038\x0e\x13\n\x0c\n\x05\
+    \x04\x02\x02\x06\x03\x12\x038\x16\x17\nA\n\x04\x04\x02\x02\x07\x12\x03:\
+    \x08\x1d\x1a4\x20marks\x20a\x20watch-only\x20entry,\x20which\x20has\x20n\
+    o\x20private\x20key\n\n\x0c\n\x05\x04\x02\x02\x07\x05\x12\x03:\x08\x0c\n\
+    \x0c\n\x05\x04\x02\x02\x07\x01\x12\x03:\r\x17\n\x0c\n\x05\x04\x02\x02\
+    \x07\x03\x12\x03:\x1a\x1c\nE\n\x04\x04\x02\x02\x08\x12\x03>\x04\x1a\x1a8\
+    \x20creation\x20date\x20of\x20the\x20entry,\x20millis\x20since\x20epoch,\
+    \x20in\x20UTC\n\n\x0c\n\x05\x04\x02\x02\x08\x05\x12\x03>\x04\n\n\x0c\n\
+    \x05\x04\x02\x02\x08\x01\x12\x03>\x0b\x15\n\x0c\n\x05\x04\x02\x02\x08\
+    \x03\x12\x03>\x18\x19\nZ\n\x04\x04\x02\x02\t\x12\x03@\x04.\x1aM\x20User\
+    \x20assigned\x20labels\x20of\x20individual\x20addresses\x20of\x20the\x20\
+    entry,\x20by\x20address\x20index\n\n\x0c\n\x05\x04\x02\x02\t\x04\x12\x03\
+    @\x04\x0c\n\x0c\n\x05\x04\x02\x02\t\x06\x12\x03@\r\x19\n\x0c\n\x05\x04\
+    \x02\x02\t\x01\x12\x03@\x1a(\n\x0c\n\x05\x04\x02\x02\t\x03\x12\x03@+-\n;\
+    \n\x02\x04\x03\x12\x04D\0I\x01\x1a/\x20Label\x20of\x20a\x20single\x20add\
+    ress\x20derived\x20by\x20an\x20entry\n\n\n\n\x03\x04\x03\x01\x12\x03D\
+    \x08\x14\n8\n\x04\x04\x03\x02\0\x12\x03F\x04\x15\x1a+\x20index\x20of\x20\
+    the\x20address\x20on\x20the\x20entry\x20HD\x20path\n\n\x0c\n\x05\x04\x03\
+    \x02\0\x05\x12\x03F\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03F\x0b\x10\
+    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03F\x13\x14\n\"\n\x04\x04\x03\x02\
+    \x01\x12\x03H\x04\x15\x1a\x15\x20user\x20assigned\x20label\n\n\x0c\n\x05\
+    \x04\x03\x02\x01\x05\x12\x03H\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\
+    \x03H\x0b\x10\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03H\x13\x14b\x06proto\
+    3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
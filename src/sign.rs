@@ -2,3 +2,4 @@ pub mod bip32;
 pub mod bitcoin;
 pub mod ethereum;
 mod key_source;
+pub mod ledger;
@@ -26,7 +26,7 @@ use bitcoin::{
     network::constants::Network,
     util::bip32::{DerivationPath, ExtendedPrivKey},
 };
-use hdpath::{StandardHDPath, HDPath, CustomHDPath};
+use hdpath::{AccountHDPath, StandardHDPath, HDPath, CustomHDPath, Purpose};
 use secp256k1::Secp256k1;
 use std::convert::TryFrom;
 use bitcoin::util::bip32::ExtendedPubKey;
@@ -52,11 +52,89 @@ pub fn generate_pubkey<P: HDPath>(path: &P, seed: &[u8]) -> Result<ExtendedPubKe
     Ok(ExtendedPubKey::from_private(&DEFAULT_SECP256K1, &sec_key))
 }
 
+/// Max value of an index in HD Path, with hardened flag stripped
+const MAX_INDEX: u32 = 0x7fff_ffff;
+
+/// Parse components of HD Path, as pairs of the index and the hardened flag. Accepts both `'` and
+/// `h`/`H` as hardened notation
+fn parse_components(value: &str) -> Result<Vec<(u32, bool)>, String> {
+    let value = value.trim();
+    let rest = if value.starts_with("m/") {
+        &value[2..]
+    } else {
+        return Err("must start with m/".to_string());
+    };
+    rest.split('/')
+        .enumerate()
+        .map(|(i, item)| {
+            let (number, hardened) = match item.chars().last() {
+                Some('\'') | Some('h') | Some('H') => (&item[..item.len() - 1], true),
+                _ => (item, false),
+            };
+            if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+                return Err(format!("invalid component {} at position {}", item, i + 1));
+            }
+            let index = number
+                .parse::<u32>()
+                .ok()
+                .filter(|n| *n <= MAX_INDEX)
+                .ok_or_else(|| format!("index {} at position {} is out of range", number, i + 1))?;
+            Ok((index, hardened))
+        })
+        .collect()
+}
+
+fn parse_error(value: &str, msg: String) -> VaultError {
+    VaultError::InvalidDataError(format!("Invalid HD Path {}: {}", value, msg))
+}
+
+/// Parse BIP-44 address path, i.e. `m/purpose'/coin_type'/account'/change/address_index`. Unlike
+/// parsing with `StandardHDPath::from_str` it accepts `h`/`H` as hardened notation and describes
+/// what's wrong with an invalid path
+pub fn parse_standard_hd_path(value: &str) -> Result<StandardHDPath, VaultError> {
+    let components = parse_components(value).map_err(|e| parse_error(value, e))?;
+    let expected_hardened = [true, true, true, false, false];
+    if components.len() != expected_hardened.len() {
+        return Err(parse_error(value, format!("expected 5 components, got {}", components.len())));
+    }
+    for (i, ((_, hardened), expected)) in components.iter().zip(expected_hardened.iter()).enumerate() {
+        if hardened != expected {
+            let msg = if *expected { "must be hardened" } else { "must not be hardened" };
+            return Err(parse_error(value, format!("component at position {} {}", i + 1, msg)));
+        }
+    }
+    let purpose = Purpose::try_from(components[0].0)
+        .map_err(|_| parse_error(value, "unsupported purpose".to_string()))?;
+    StandardHDPath::try_new(
+        purpose,
+        components[1].0,
+        components[2].0,
+        components[3].0,
+        components[4].0,
+    )
+    .map_err(|e| parse_error(value, e.0))
+}
+
+/// Parse BIP-44 account path, i.e. `m/purpose'/coin_type'/account'`. Unlike parsing with
+/// `AccountHDPath::from_str` it accepts `h`/`H` as hardened notation and describes what's wrong
+/// with an invalid path
+pub fn parse_account_hd_path(value: &str) -> Result<AccountHDPath, VaultError> {
+    let components = parse_components(value).map_err(|e| parse_error(value, e))?;
+    if components.len() != 3 {
+        return Err(parse_error(value, format!("expected 3 components, got {}", components.len())));
+    }
+    if let Some(i) = components.iter().position(|(_, hardened)| !hardened) {
+        return Err(parse_error(value, format!("component at position {} must be hardened", i + 1)));
+    }
+    let purpose = Purpose::try_from(components[0].0)
+        .map_err(|_| parse_error(value, "unsupported purpose".to_string()))?;
+    Ok(AccountHDPath::new(purpose, components[1].0, components[2].0))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::blockchain::EthereumAddress;
-    use hdpath::Purpose;
     use hex::FromHex;
     use std::str::FromStr;
 
@@ -95,4 +173,59 @@ mod test {
             EthereumAddress::from_str("0x5d383cDB23983578131aD57f3F36Ab19ca6E6854").unwrap()
         );
     }
+
+    #[test]
+    fn parse_account_with_hardened_notations() {
+        let exp = AccountHDPath::from_str("m/84'/0'/3'").unwrap();
+        assert_eq!(Ok(exp.clone()), parse_account_hd_path("m/84'/0'/3'"));
+        assert_eq!(Ok(exp.clone()), parse_account_hd_path("m/84h/0h/3h"));
+        assert_eq!(Ok(exp.clone()), parse_account_hd_path("m/84H/0'/3h"));
+    }
+
+    #[test]
+    fn parse_standard_with_hardened_notations() {
+        let exp = StandardHDPath::from_str("m/44'/60'/0'/0/1").unwrap();
+        assert_eq!(Ok(exp.clone()), parse_standard_hd_path("m/44'/60'/0'/0/1"));
+        assert_eq!(Ok(exp.clone()), parse_standard_hd_path("m/44h/60h/0h/0/1"));
+    }
+
+    #[test]
+    fn fail_on_out_of_range_index() {
+        assert_eq!(
+            Err(VaultError::InvalidDataError(
+                "Invalid HD Path m/84'/0'/2147483648': index 2147483648 at position 3 is out of range".to_string()
+            )),
+            parse_account_hd_path("m/84'/0'/2147483648'")
+        );
+        assert!(parse_account_hd_path("m/84'/0'/2147483647'").is_ok());
+        assert!(parse_standard_hd_path("m/44'/60'/0'/0/4294967296").is_err());
+    }
+
+    #[test]
+    fn fail_on_malformed_path() {
+        assert_eq!(
+            Err(VaultError::InvalidDataError(
+                "Invalid HD Path m/84'/0'/3'/: invalid component  at position 4".to_string()
+            )),
+            parse_account_hd_path("m/84'/0'/3'/")
+        );
+        assert_eq!(
+            Err(VaultError::InvalidDataError(
+                "Invalid HD Path m/84'/0/3': component at position 2 must be hardened".to_string()
+            )),
+            parse_account_hd_path("m/84'/0/3'")
+        );
+        assert_eq!(
+            Err(VaultError::InvalidDataError(
+                "Invalid HD Path m/44'/60'/0'/0'/0: component at position 4 must not be hardened".to_string()
+            )),
+            parse_standard_hd_path("m/44'/60'/0'/0'/0")
+        );
+        assert!(parse_account_hd_path("84'/0'/3'").is_err());
+        assert!(parse_account_hd_path("m/84'/0'").is_err());
+        assert!(parse_account_hd_path("m/84'//3'").is_err());
+        assert!(parse_account_hd_path("m/84'/+0'/3'").is_err());
+        assert!(parse_account_hd_path("m/84''/0'/3'").is_err());
+        assert!(parse_standard_hd_path("m/44'/60'/0'/0").is_err());
+    }
 }
@@ -11,7 +11,7 @@ use crate::{
     },
     sign::key_source::PrivateKeySource,
     storage::{error::VaultError, vault::VaultStorage},
-    structs::{book::AddressRef, wallet::WalletEntry},
+    structs::{book::AddressRef, wallet::{PKType, WalletEntry}},
 };
 use bitcoin::{
     blockdata::{opcodes, script::Builder},
@@ -34,7 +34,7 @@ use std::io;
 use crate::structs::seed::SeedSource;
 use emerald_hwkey::ledger::manager::LedgerKey;
 use emerald_hwkey::ledger::app_bitcoin::{BitcoinApp, BitcoinApps, SignTx, UnsignedInput};
-use emerald_hwkey::ledger::traits::LedgerApp;
+use crate::sign::ledger::ensure_bitcoin_app;
 use hdpath::StandardHDPath;
 
 lazy_static! {
@@ -64,6 +64,9 @@ impl From<Blockchain> for Network {
 
 impl WalletEntry {
     pub fn sign_bitcoin(&self, tx: BitcoinTransferProposal) -> Result<Vec<u8>, VaultError> {
+        if self.key == PKType::Watch {
+            return Err(VaultError::PrivateKeyUnavailable);
+        }
         let signed = tx.seal()?;
         Ok(signed.serialize())
     }
@@ -222,11 +225,26 @@ pub enum BitcoinTxError {
     IncorrectFee,
     NoOutputs,
     NoInputs,
+    UnsupportedAddressType(AddressType),
 }
 
 /// Max fee, check during _optinal_ validation, is 0.05 BTC.
 const FEE_MAX: u64 = 5_000_000;
 
+/// Minimal amount of a change output. A smaller change costs more to spend than it's worth, so
+/// it's added to the fee instead.
+pub const DUST_LIMIT: u64 = 546;
+
+/// Inputs selected to send an amount, with the resulting fee and change
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CoinSelection {
+    pub inputs: Vec<InputReference>,
+    /// Total fee, including the dust change if it was folded into the fee
+    pub fee: u64,
+    /// Change to send back, or 0 if no change output is needed
+    pub change: u64,
+}
+
 impl BitcoinTransferProposal {
     fn unsigned(&self) -> Transaction {
         Transaction {
@@ -268,8 +286,13 @@ impl BitcoinTransferProposal {
             Network::Testnet => BitcoinApps::Testnet,
             _ => return Err(VaultError::IncorrectBlockchainError)
         };
-        if bitcoin_app.is_open() != Some(exp_app) {
-            return Err(VaultError::PublicKeyUnavailable)
+        ensure_bitcoin_app(&manager, &exp_app)?;
+        for seed in &self.seed {
+            if let SeedSource::Ledger(ledger) = &seed.source {
+                // the vault is not available here, so fingerprints read from a not yet verified
+                // device are not remembered, and it's just trusted for this transaction
+                ledger.verify(&manager)?;
+            }
         }
         let conf = SignTx {
             network: self.network,
@@ -314,11 +337,140 @@ impl BitcoinTransferProposal {
     }
 }
 
+/// Size of a Compact Size integer used to encode number of inputs or outputs
+fn varint_size(n: usize) -> u64 {
+    match n {
+        0..=0xfc => 1,
+        0xfd..=0xffff => 3,
+        0x10000..=0xffff_ffff => 5,
+        _ => 9,
+    }
+}
+
+/// Size of an input spending a single-key script, as `(non-witness bytes, witness bytes)`.
+/// Assumes the longest (72 bytes) DER signature and a compressed public key.
+fn input_size(address_type: AddressType) -> Result<(u64, u64), BitcoinTxError> {
+    // outpoint + sequence
+    let base = 32 + 4 + 4;
+    // items count + signature + public key
+    let witness = 1 + (1 + 72) + (1 + 33);
+    match address_type {
+        // script length + signature + public key
+        AddressType::P2PKH => Ok((base + 1 + (1 + 72) + (1 + 33), 0)),
+        // empty script
+        AddressType::P2WPKH => Ok((base + 1, witness)),
+        // script length + push of the redeem script `0 <20 bytes>`
+        AddressType::P2WPKHinP2SH => Ok((base + 1 + 23, witness)),
+        // size of a custom script is unknown
+        AddressType::P2SH | AddressType::P2WSH | AddressType::P2WSHinP2SH => {
+            Err(BitcoinTxError::UnsupportedAddressType(address_type))
+        }
+    }
+}
+
+/// Size of an output to the specified type of address
+fn output_size(address_type: AddressType) -> u64 {
+    // value + script length
+    let base = 8 + 1;
+    match address_type {
+        AddressType::P2PKH => base + 25,
+        AddressType::P2SH | AddressType::P2WPKHinP2SH | AddressType::P2WSHinP2SH => base + 23,
+        AddressType::P2WPKH => base + 22,
+        AddressType::P2WSH => base + 34,
+    }
+}
+
+/// Estimated virtual size (in vbytes) of a transaction spending `inputs` of the specified address
+/// type to `outputs` of the same type. For a Segwit transaction the witness data is counted with
+/// the 1/4 discount.
+pub fn estimate_vsize(
+    inputs: usize,
+    outputs: usize,
+    address_type: AddressType,
+) -> Result<u64, BitcoinTxError> {
+    let (input_base, input_witness) = input_size(address_type)?;
+    // version + lock time
+    let base = 4 + 4
+        + varint_size(inputs)
+        + varint_size(outputs)
+        + inputs as u64 * input_base
+        + outputs as u64 * output_size(address_type);
+    let weight = if input_witness > 0 {
+        // segwit marker and flag
+        base * 4 + 2 + inputs as u64 * input_witness
+    } else {
+        base * 4
+    };
+    Ok((weight + 3) / 4)
+}
+
+/// Estimate fee (in satoshi) for a transaction with the specified number of inputs and outputs,
+/// with a fee rate in satoshi per vbyte. Returns `UnsupportedAddressType` for script hash
+/// addresses, because the size of the spending script isn't known.
+pub fn estimate_fee(
+    inputs: usize,
+    outputs: usize,
+    fee_rate_sat_vb: u64,
+    address_type: AddressType,
+) -> Result<u64, BitcoinTxError> {
+    estimate_vsize(inputs, outputs, address_type).map(|vsize| vsize * fee_rate_sat_vb)
+}
+
+/// Select inputs to send `amount` to a single recipient, using the Largest-First strategy, i.e.
+/// adds the largest of available UTXOs until they cover the amount and the fee for the
+/// `fee_rate_sat_vb`. All inputs and outputs are expected to be of the specified address type.
+pub fn select_inputs(
+    utxo: &[InputReference],
+    amount: u64,
+    fee_rate_sat_vb: u64,
+    address_type: AddressType,
+) -> Result<CoinSelection, BitcoinTxError> {
+    if amount == 0 {
+        return Err(BitcoinTxError::NoOutputs);
+    }
+    if utxo.is_empty() {
+        return Err(BitcoinTxError::NoInputs);
+    }
+    let mut available = utxo.to_vec();
+    available.sort_by(|a, b| b.expected_value.cmp(&a.expected_value));
+
+    let mut inputs = Vec::new();
+    let mut total = 0u64;
+    for input in available {
+        total += input.expected_value;
+        inputs.push(input);
+
+        let fee = estimate_fee(inputs.len(), 1, fee_rate_sat_vb, address_type)?;
+        if total < amount + fee {
+            continue;
+        }
+        let fee_with_change = estimate_fee(inputs.len(), 2, fee_rate_sat_vb, address_type)?;
+        let selection = if total >= amount + fee_with_change + DUST_LIMIT {
+            CoinSelection {
+                inputs,
+                fee: fee_with_change,
+                change: total - amount - fee_with_change,
+            }
+        } else {
+            CoinSelection {
+                inputs,
+                fee: total - amount,
+                change: 0,
+            }
+        };
+        return Ok(selection);
+    }
+
+    let fee = estimate_fee(inputs.len(), 1, fee_rate_sat_vb, address_type)?;
+    Err(BitcoinTxError::InsufficientFunds(total, amount + fee))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         blockchain::{
             bitcoin::{
+                AddressType,
                 BitcoinTransferProposal,
                 InputReference,
                 InputScriptSource,
@@ -339,7 +491,9 @@ mod tests {
     use hdpath::{StandardHDPath, AccountHDPath};
     use std::{convert::TryFrom, process::id, str::FromStr};
     use uuid::Uuid;
-    use crate::sign::bitcoin::BitcoinTxError;
+    use crate::sign::bitcoin::{
+        estimate_fee, estimate_vsize, select_inputs, BitcoinTxError, CoinSelection,
+    };
     use tempdir::TempDir;
     use crate::storage::vault::VaultStorage;
     use crate::structs::seed::LedgerSource;
@@ -386,6 +540,7 @@ mod tests {
                 source: seed,
                 label: None,
                 created_at: Utc.timestamp_millis(0),
+                fingerprint: None,
             }],
             keys: KeyMapping::single(seed_id.clone(), "test".to_string()),
             input: vec![from],
@@ -441,6 +596,7 @@ mod tests {
                 source: seed,
                 label: None,
                 created_at: Utc.timestamp_millis(0),
+                fingerprint: None,
             }],
             keys: KeyMapping::single(seed_id.clone(), "test".to_string()),
             input: vec![from],
@@ -528,6 +684,7 @@ mod tests {
                 source: seed,
                 label: None,
                 created_at: Utc.timestamp_millis(0),
+                fingerprint: None,
             }],
             keys: KeyMapping::single(seed_id.clone(), "test".to_string()),
             input: vec![
@@ -609,6 +766,128 @@ mod tests {
         assert_eq!(proposal.validate(), Err(BitcoinTxError::LargeFee));
     }
 
+    #[test]
+    fn estimate_p2pkh_vsize() {
+        // 148 bytes per input and 34 bytes per output
+        assert_eq!(Ok(192), estimate_vsize(1, 1, AddressType::P2PKH));
+        assert_eq!(Ok(226), estimate_vsize(1, 2, AddressType::P2PKH));
+        assert_eq!(Ok(374), estimate_vsize(2, 2, AddressType::P2PKH));
+    }
+
+    #[test]
+    fn estimate_p2wpkh_vsize() {
+        // 68 vbytes per input and 31 bytes per output
+        assert_eq!(Ok(110), estimate_vsize(1, 1, AddressType::P2WPKH));
+        assert_eq!(Ok(141), estimate_vsize(1, 2, AddressType::P2WPKH));
+        assert_eq!(Ok(209), estimate_vsize(2, 2, AddressType::P2WPKH));
+        assert_eq!(Ok(166), estimate_vsize(1, 2, AddressType::P2WPKHinP2SH));
+    }
+
+    #[test]
+    fn estimate_fee_for_rate() {
+        assert_eq!(Ok(2260), estimate_fee(1, 2, 10, AddressType::P2PKH));
+        assert_eq!(Ok(1410), estimate_fee(1, 2, 10, AddressType::P2WPKH));
+        assert_eq!(Ok(0), estimate_fee(1, 2, 0, AddressType::P2WPKH));
+    }
+
+    #[test]
+    fn estimate_large_tx() {
+        // 253 inputs need 3 bytes for the count
+        assert_eq!(
+            Ok(4 + 4 + 3 + 1 + 253 * 148 + 34),
+            estimate_vsize(253, 1, AddressType::P2PKH)
+        );
+    }
+
+    #[test]
+    fn no_estimate_for_script_hash() {
+        assert_eq!(
+            Err(BitcoinTxError::UnsupportedAddressType(AddressType::P2WSH)),
+            estimate_fee(1, 2, 10, AddressType::P2WSH)
+        );
+        assert_eq!(
+            Err(BitcoinTxError::UnsupportedAddressType(AddressType::P2SH)),
+            estimate_fee(1, 2, 10, AddressType::P2SH)
+        );
+    }
+
+    fn create_utxo(value: u64, vout: u32) -> InputReference {
+        InputReference {
+            output: OutPoint::new(
+                Txid::from_str("16aa2d98e37e50c4c007a815a3cb8c20026a3df467781a7e97206a730cf4ef01")
+                    .unwrap(),
+                vout,
+            ),
+            script_source: InputScriptSource::HD(
+                Uuid::nil(),
+                StandardHDPath::try_from("m/84'/0'/0'/0/0").unwrap(),
+            ),
+            sequence: 0xfffffffd,
+            expected_value: value,
+        }
+    }
+
+    #[test]
+    fn select_exact_match() {
+        // fee for 1 input and 1 output is 110 vbytes * 10 sat
+        let utxo = vec![create_utxo(50_000, 0), create_utxo(101_100, 1)];
+        let act = select_inputs(&utxo, 100_000, 10, AddressType::P2WPKH);
+        assert_eq!(
+            Ok(CoinSelection {
+                inputs: vec![create_utxo(101_100, 1)],
+                fee: 1_100,
+                change: 0,
+            }),
+            act
+        );
+    }
+
+    #[test]
+    fn select_with_change() {
+        let utxo = vec![
+            create_utxo(60_000, 0),
+            create_utxo(80_000, 1),
+            create_utxo(10_000, 2),
+        ];
+        let act = select_inputs(&utxo, 100_000, 10, AddressType::P2WPKH).unwrap();
+        assert_eq!(vec![create_utxo(80_000, 1), create_utxo(60_000, 0)], act.inputs);
+        // 2 inputs and 2 outputs is 209 vbytes
+        assert_eq!(2_090, act.fee);
+        assert_eq!(140_000 - 100_000 - 2_090, act.change);
+    }
+
+    #[test]
+    fn select_adds_dust_change_to_fee() {
+        let utxo = vec![create_utxo(101_500, 0)];
+        let act = select_inputs(&utxo, 100_000, 10, AddressType::P2WPKH).unwrap();
+        assert_eq!(1, act.inputs.len());
+        assert_eq!(1_500, act.fee);
+        assert_eq!(0, act.change);
+    }
+
+    #[test]
+    fn select_insufficient_funds() {
+        let utxo = vec![create_utxo(50_000, 0), create_utxo(40_000, 1)];
+        let act = select_inputs(&utxo, 100_000, 10, AddressType::P2WPKH);
+        // 2 inputs and 1 output is 178 vbytes
+        assert_eq!(Err(BitcoinTxError::InsufficientFunds(90_000, 101_780)), act);
+
+        // not enough to pay the fee
+        let utxo = vec![create_utxo(100_500, 0)];
+        let act = select_inputs(&utxo, 100_000, 10, AddressType::P2WPKH);
+        assert_eq!(Err(BitcoinTxError::InsufficientFunds(100_500, 101_100)), act);
+    }
+
+    #[test]
+    fn select_nothing() {
+        let act = select_inputs(&[], 100_000, 10, AddressType::P2WPKH);
+        assert_eq!(Err(BitcoinTxError::NoInputs), act);
+
+        let utxo = vec![create_utxo(50_000, 0)];
+        let act = select_inputs(&utxo, 0, 10, AddressType::P2WPKH);
+        assert_eq!(Err(BitcoinTxError::NoOutputs), act);
+    }
+
     #[test]
     fn encode_basic_unsigned_tx() {
         let (entry, proposal) = create_proposal_1();
@@ -1,5 +1,4 @@
 use crate::{blockchain::chains::EthereumChainId, convert::json::keyfile::EthereumJsonV3File, sign::bip32::generate_key, storage::{error::VaultError, vault::VaultStorage}, structs::{
-    seed::SeedSource,
     wallet::{EntryId, PKType, Wallet, WalletEntry},
 }, EthereumPrivateKey, EthereumTransaction, EthereumSignature};
 use hdpath::StandardHDPath;
@@ -23,18 +22,19 @@ impl WalletEntry {
     fn sign_tx_with_hardware(
         &self,
         tx: EthereumTransaction,
-        _: Uuid, //not used yet
+        seed_id: Uuid,
         hd_path: StandardHDPath,
+        vault: &VaultStorage,
     ) -> Result<Vec<u8>, VaultError> {
         let hd_path = StandardHDPath::try_from(hd_path.to_string().as_str())
             .map_err(|_| VaultError::InvalidDataError("HDPath".to_string()))?;
 
-        //TODO verify actual device, right now vault just uses a first currently available device
         let manager = LedgerKey::new_connected().map_err(|_| VaultError::PrivateKeyUnavailable)?;
         let ethereum_app = EthereumApp::new(&manager);
         if ethereum_app.is_open().is_none() {
             return Err(VaultError::PrivateKeyUnavailable);
         }
+        vault.seeds().verify_ledger(seed_id, &manager)?;
         let chain_id = EthereumChainId::from(self.blockchain);
         let rlp = tx.to_rlp(Some(chain_id.as_chainid()));
         let sign = ethereum_app
@@ -55,7 +55,9 @@ impl WalletEntry {
         if self.is_hardware(vault)? {
             return match &self.key {
                 PKType::SeedHd(seed) => {
-                    Ok(self.sign_tx_with_hardware(tx, seed.seed_id, seed.hd_path.clone())?)
+                    let hd_path = seed.hd_path.address_at(0, 0)
+                        .map_err(|_| VaultError::InvalidDataError("HDPath".to_string()))?;
+                    Ok(self.sign_tx_with_hardware(tx, seed.seed_id, hd_path, vault)?)
                 }
                 _ => Err(VaultError::UnsupportedDataError("NOT_SEED".to_string())),
             };
@@ -93,6 +95,7 @@ impl WalletEntry {
                 EthereumJsonV3File::from_pk(label, key, password.expect("Password is not set"))
                     .map_err(|_| VaultError::InvalidPrivateKey)
             }
+            PKType::Watch => Err(VaultError::PrivateKeyUnavailable),
         }
     }
 }
@@ -286,6 +289,7 @@ mod tests {
             ).unwrap(),
             label: None,
             created_at: Utc::now(),
+            fingerprint: None,
         };
         let seed_id = vault.seeds().add(seed).unwrap();
 
@@ -295,7 +299,7 @@ mod tests {
             address: None, //0xC27fBF02FB577683593b1114180CA6E2c88510A0
             key: PKType::SeedHd(SeedRef {
                 seed_id,
-                hd_path: StandardHDPath::try_from("m/44'/60'/2'/0/52").unwrap(),
+                hd_path: StandardHDPath::try_from("m/44'/60'/2'/0/52").unwrap().into(),
             }),
             ..WalletEntry::default()
         };
@@ -325,9 +329,12 @@ mod tests {
             id: Uuid::new_v4(),
             source: SeedSource::Ledger(LedgerSource {
                 fingerprints: vec![],
+                device_model: None,
+                master_fingerprint: None,
             }),
             label: None,
             created_at: Utc::now(),
+            fingerprint: None,
         };
         let seed_id = vault.seeds().add(seed).unwrap();
 
@@ -337,7 +344,7 @@ mod tests {
             address: None,
             key: PKType::SeedHd(SeedRef {
                 seed_id,
-                hd_path: StandardHDPath::try_from("m/44'/60'/160720'/0/0").unwrap(),
+                hd_path: StandardHDPath::try_from("m/44'/60'/160720'/0/0").unwrap().into(),
             }),
             ..WalletEntry::default()
         };
@@ -14,6 +14,7 @@ use emerald_hwkey::ledger::app_ethereum::EthereumApp;
 use emerald_hwkey::ledger::traits::{LedgerApp, PubkeyAddressApp};
 use emerald_hwkey::ledger::app_bitcoin::{BitcoinApp, GetAddressOpts};
 use crate::sign::bip32::generate_pubkey;
+use zeroize::Zeroizing;
 
 pub enum PrivateKeySource {
     Base(SecretKey),
@@ -71,7 +72,7 @@ impl SeedSource {
             SeedSource::Bytes(bytes) => match password {
                 None => Err(VaultError::PasswordRequired),
                 Some(password) => {
-                    let seed_key = bytes.decrypt(password.as_str())?;
+                    let seed_key = Zeroizing::new(bytes.decrypt(password.as_str())?);
                     let key = generate_key(hd_path, &seed_key)?;
                     Ok(PrivateKeySource::Extended(key))
                 }
@@ -99,7 +100,7 @@ impl SeedSource {
             SeedSource::Bytes(bytes) => match password {
                 None => Err(VaultError::PasswordRequired),
                 Some(password) => {
-                    let seed_key = bytes.decrypt(password.as_str())?;
+                    let seed_key = Zeroizing::new(bytes.decrypt(password.as_str())?);
                     for hd_path in hd_path_all {
                         let pub_key = ExtendedPubKey {
                             network,
@@ -139,7 +140,7 @@ impl SeedSource {
             SeedSource::Bytes(bytes) => match password {
                 None => Err(VaultError::PasswordRequired),
                 Some(password) => {
-                    let seed_key = bytes.decrypt(password.as_str())?;
+                    let seed_key = Zeroizing::new(bytes.decrypt(password.as_str())?);
                     for hd_path in hd_path_all {
                         let pub_key = generate_pubkey(hd_path, &seed_key)?;
                         let address_type = AddressType::try_from(hd_path)?;
@@ -194,13 +195,26 @@ impl PKType {
         &self,
         vault: &VaultStorage,
         password: Option<String>,
+    ) -> Result<PrivateKeySource, VaultError> {
+        self.get_pk_at(vault, password, 0, 0)
+    }
+
+    /// Get Private Key for the address at `change/index`. For a key referencing an account on a seed
+    /// it derives the key for that address on the account, for other keys the index is ignored
+    /// because they reference a single address
+    pub fn get_pk_at(
+        &self,
+        vault: &VaultStorage,
+        password: Option<String>,
+        change: u32,
+        index: u32,
     ) -> Result<PrivateKeySource, VaultError> {
         match &self {
             PKType::PrivateKeyRef(pk) => {
                 let key = vault.keys().get(pk.clone())?;
                 let key = match password {
                     None => return Err(VaultError::PasswordRequired),
-                    Some(password) => key.decrypt(password.as_str())?,
+                    Some(password) => Zeroizing::new(key.decrypt(password.as_str())?),
                 };
                 let key = SecretKey::from_slice(key.as_slice())
                     .map_err(|e| VaultError::InvalidPrivateKey)?;
@@ -208,9 +222,11 @@ impl PKType {
             }
             PKType::SeedHd(seed) => {
                 let seed_details = vault.seeds().get(seed.seed_id.clone())?;
-                let hd_path = StandardHDPath::try_from(seed.hd_path.to_string().as_str())?;
+                let hd_path = seed.hd_path.address_at(change, index)
+                    .map_err(|_| VaultError::InvalidDataError("hd_path".to_string()))?;
                 seed_details.source.get_pk(password, &hd_path)
             }
+            PKType::Watch => Err(VaultError::PrivateKeyUnavailable),
         }
     }
 
@@ -234,6 +250,13 @@ mod tests {
     use crate::EthereumAddress;
     use bitcoin::Address;
     use crate::blockchain::bitcoin::XPub;
+    use crate::storage::{error::VaultError, vault::VaultStorage};
+    use crate::structs::crypto::Encrypted;
+    use crate::structs::pk::{EthereumPk3, PrivateKeyHolder, PrivateKeyType};
+    use crate::structs::wallet::PKType;
+    use chrono::Utc;
+    use tempdir::TempDir;
+    use uuid::Uuid;
 
     #[test]
     fn get_ethereum_addresses() {
@@ -342,4 +365,25 @@ mod tests {
         assert_eq!(addresses[0].0, AccountHDPath::from_str("m/84'/1'/0'").unwrap());
         assert_eq!(addresses[0].1.to_string(), "vpub5YGWRLD8AtynzsPMdPwsjXPUUqoxb6LkgByZzaJ9TS2FECgogxA3CszeC16oiz2Uc7rCcSM9U2Drmv6A9dqBS6YpSuhUEi6LmWtCkVQXc1F".to_string());
     }
+
+    #[test]
+    fn deny_pk_of_invalid_length() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let key_id = vault.keys().add(PrivateKeyHolder {
+            id: Uuid::new_v4(),
+            pk: PrivateKeyType::EthereumPk(EthereumPk3 {
+                address: None,
+                key: Encrypted::encrypt(vec![1u8; 31], "testtest").unwrap(),
+            }),
+            created_at: Utc::now(),
+        }).unwrap();
+
+        let act = PKType::PrivateKeyRef(key_id).get_pk(&vault, Some("testtest".to_string()));
+        assert_eq!(Some(VaultError::InvalidPrivateKey), act.err());
+
+        let act = PKType::PrivateKeyRef(key_id)
+            .get_ethereum_pk(&vault, Some("testtest".to_string()));
+        assert_eq!(Some(VaultError::InvalidPrivateKey), act.err());
+    }
 }
@@ -0,0 +1,378 @@
+//! # Helpers to inspect a connected Ledger device
+
+use crate::{
+    blockchain::EthereumAddress,
+    storage::error::VaultError,
+    structs::seed::{HDPathFingerprint, LedgerSource, SeedSource},
+};
+use emerald_hwkey::ledger::{
+    app_bitcoin::{BitcoinApp, BitcoinApps},
+    app_ethereum::EthereumApp,
+    manager::{AppDetails, LedgerKey},
+    traits::{LedgerApp, PubkeyAddressApp},
+};
+use bitcoin::util::bip32::Fingerprint;
+use bitcoin_hashes::{hash160, Hash};
+use hdpath::{CustomHDPath, StandardHDPath};
+use std::str::FromStr;
+
+/// Name of the "app" reported by the device when the dashboard is opened
+const DASHBOARD_NAME: &str = "BOLOS";
+
+pub trait LedgerDetails {
+    /// Get model of the device (ex. `Nano X`). The model is available only from the dashboard,
+    /// so it returns `None` if any app is opened or the device reports an unknown Target ID
+    fn get_device_model(&self) -> Result<Option<String>, VaultError>;
+}
+
+impl LedgerDetails for LedgerKey {
+    fn get_device_model(&self) -> Result<Option<String>, VaultError> {
+        if self.get_app_details()?.name != DASHBOARD_NAME {
+            return Ok(None);
+        }
+        let target_id = self.get_target_id()?;
+        Ok(device_model(target_id).map(|m| m.to_string()))
+    }
+}
+
+/// Find model of the device by the Target ID reported by the dashboard
+pub(crate) fn device_model(target_id: u32) -> Option<&'static str> {
+    match target_id {
+        0x31100002 | 0x31100003 | 0x31100004 => Some("Nano S"),
+        0x33000004 => Some("Nano X"),
+        0x33100004 => Some("Nano S Plus"),
+        _ => None,
+    }
+}
+
+/// Name of the Ledger app expected for the specified Bitcoin network
+pub fn bitcoin_app_name(app: &BitcoinApps) -> &'static str {
+    match app {
+        BitcoinApps::Mainnet => "Bitcoin",
+        BitcoinApps::Testnet => "Bitcoin Test",
+    }
+}
+
+/// Verifies that the app expected for the operation is opened on the device, and returns
+/// `VaultError::LedgerAppNotOpened` with the name of the expected app if another app is opened.
+/// Errors of communication with the device are returned as is
+pub fn ensure_bitcoin_app(manager: &LedgerKey, exp: &BitcoinApps) -> Result<(), VaultError> {
+    let details: AppDetails = manager.get_app_details()?;
+    check_bitcoin_app(details.name.as_str(), exp)
+}
+
+/// Check that the name of the opened app is the app expected for the Bitcoin network
+pub(crate) fn check_bitcoin_app(opened: &str, exp: &BitcoinApps) -> Result<(), VaultError> {
+    let exp_name = bitcoin_app_name(exp);
+    if opened == exp_name {
+        Ok(())
+    } else {
+        Err(VaultError::LedgerAppNotOpened(exp_name.to_string()))
+    }
+}
+
+/// HD Path used to fingerprint a Ledger when the Ethereum app is opened. The app doesn't give
+/// access to the master key, so it's fingerprinted by an address instead
+const FINGERPRINT_PATH_ETHEREUM: &str = "m/44'/60'/0'/0/0";
+
+/// Get BIP32 fingerprint of the master public key of the device, or `None` if the opened app
+/// doesn't give access to the master key. Only Bitcoin apps (Mainnet and Testnet) allow that
+fn get_master_fingerprint(manager: &LedgerKey) -> Result<Option<Fingerprint>, VaultError> {
+    let app = BitcoinApp::new(manager);
+    if app.is_open().is_none() {
+        return Ok(None);
+    }
+    let master = CustomHDPath::try_new(vec![])?;
+    let key = app.get_extkey_at(&master)?;
+    let hash = hash160::Hash::hash(&key.as_pubkey().serialize());
+    Ok(Some(Fingerprint::from(&hash[0..4])))
+}
+
+/// Get fingerprint of an address of the device, or `None` if the opened app cannot provide it
+fn get_address_fingerprint(
+    manager: &LedgerKey,
+    hd_path: &StandardHDPath,
+) -> Result<Option<HDPathFingerprint>, VaultError> {
+    if hd_path.coin_type() != 60 {
+        return Ok(None);
+    }
+    let app = EthereumApp::new(manager);
+    if app.is_open().is_none() {
+        return Ok(None);
+    }
+    let address = app.get_address(hd_path, false)?;
+    let address = EthereumAddress::from_str(address.address.as_str())
+        .map_err(|_| VaultError::InvalidDataError("address".to_string()))?;
+    Ok(Some(HDPathFingerprint::from_address(hd_path.clone(), &address)))
+}
+
+impl LedgerSource {
+    /// Create a source for the currently connected Ledger. With a Bitcoin app opened it keeps the
+    /// fingerprint of the master key, and with the Ethereum app the fingerprint of the first
+    /// address. The device model is remembered only if it's created while the dashboard is opened.
+    ///
+    /// Note that the model and the fingerprints are never available at the same time, so a source
+    /// created from the dashboard has the model but no fingerprints. Such source cannot be verified
+    /// until it's used with an app for the first time, and then the fingerprints read from the
+    /// device are remembered (see `verify`)
+    pub fn create_connected() -> Result<LedgerSource, VaultError> {
+        let manager = LedgerKey::new_connected().map_err(|_| VaultError::PublicKeyUnavailable)?;
+        let master_fingerprint = get_master_fingerprint(&manager)?;
+        let hd_path = StandardHDPath::from_str(FINGERPRINT_PATH_ETHEREUM).expect("Invalid fingerprint path");
+        let fingerprints = get_address_fingerprint(&manager, &hd_path)?
+            .into_iter()
+            .collect();
+        let device_model = manager.get_device_model()?;
+        Ok(LedgerSource {
+            fingerprints,
+            device_model,
+            master_fingerprint,
+        })
+    }
+
+    /// Verify that the connected device is the same one that was used to create the source.
+    /// Only fingerprints that can be checked with the currently opened app are compared, and if
+    /// any of them is different it returns `VaultError::WrongLedger`.
+    ///
+    /// The fingerprints available depend on the app (the master key with a Bitcoin app, an
+    /// address with the Ethereum app), so a source created with one app cannot be checked with
+    /// the other. In this case the device is trusted on the first use with that app, and it returns
+    /// a copy of the source with the fingerprints read from the device added, to be stored and
+    /// used for verification next time. Returns `None` if the source doesn't need to be updated
+    pub fn verify(&self, manager: &LedgerKey) -> Result<Option<LedgerSource>, VaultError> {
+        let master = get_master_fingerprint(manager)?;
+        let mut hd_paths: Vec<StandardHDPath> = self.fingerprints.iter()
+            .map(|f| f.hd_path.clone())
+            .collect();
+        let default_path = StandardHDPath::from_str(FINGERPRINT_PATH_ETHEREUM).expect("Invalid fingerprint path");
+        if !hd_paths.contains(&default_path) {
+            hd_paths.push(default_path);
+        }
+        let mut addresses = Vec::with_capacity(hd_paths.len());
+        for hd_path in hd_paths {
+            if let Some(fingerprint) = get_address_fingerprint(manager, &hd_path)? {
+                addresses.push(fingerprint);
+            }
+        }
+        self.verify_with(master, addresses)
+    }
+
+    /// Verify the source against the fingerprints read from the device with the opened app.
+    /// See `verify`
+    fn verify_with(
+        &self,
+        master: Option<Fingerprint>,
+        addresses: Vec<HDPathFingerprint>,
+    ) -> Result<Option<LedgerSource>, VaultError> {
+        let mut verified = false;
+        if let (Some(expected), Some(current)) = (&self.master_fingerprint, &master) {
+            if current != expected {
+                return Err(VaultError::WrongLedger);
+            }
+            verified = true;
+        }
+        for fingerprint in &self.fingerprints {
+            if let Some(current) = addresses.iter().find(|f| f.hd_path == fingerprint.hd_path) {
+                if current != fingerprint {
+                    return Err(VaultError::WrongLedger);
+                }
+                verified = true;
+            }
+        }
+        if verified {
+            return Ok(None);
+        }
+        let mut updated = self.clone();
+        if updated.master_fingerprint.is_none() {
+            updated.master_fingerprint = master;
+        }
+        for fingerprint in addresses {
+            if !updated.fingerprints.iter().any(|f| f.hd_path == fingerprint.hd_path) {
+                updated.fingerprints.push(fingerprint);
+            }
+        }
+        if updated == *self {
+            warn!("Ledger cannot be verified with the opened app");
+            Ok(None)
+        } else {
+            warn!("Ledger is not verified with the opened app, remember its fingerprints for next time");
+            Ok(Some(updated))
+        }
+    }
+}
+
+impl SeedSource {
+    /// Create a Ledger source for the currently connected device
+    pub fn create_ledger() -> Result<Self, VaultError> {
+        Ok(SeedSource::Ledger(LedgerSource::create_connected()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_device_model() {
+        assert_eq!(device_model(0x31000004), None);
+        assert_eq!(device_model(0x31100004), Some("Nano S"));
+        assert_eq!(device_model(0x31100002), Some("Nano S"));
+        assert_eq!(device_model(0x33000004), Some("Nano X"));
+        assert_eq!(device_model(0x33100004), Some("Nano S Plus"));
+        assert_eq!(device_model(0), None);
+    }
+
+    #[test]
+    fn bitcoin_app_names() {
+        assert_eq!(bitcoin_app_name(&BitcoinApps::Mainnet), "Bitcoin");
+        assert_eq!(bitcoin_app_name(&BitcoinApps::Testnet), "Bitcoin Test");
+    }
+
+    #[test]
+    fn accept_expected_bitcoin_app() {
+        assert_eq!(check_bitcoin_app("Bitcoin", &BitcoinApps::Mainnet), Ok(()));
+        assert_eq!(check_bitcoin_app("Bitcoin Test", &BitcoinApps::Testnet), Ok(()));
+    }
+
+    #[test]
+    fn reject_other_app() {
+        assert_eq!(
+            check_bitcoin_app("Bitcoin Test", &BitcoinApps::Mainnet),
+            Err(VaultError::LedgerAppNotOpened("Bitcoin".to_string()))
+        );
+        assert_eq!(
+            check_bitcoin_app("Bitcoin", &BitcoinApps::Testnet),
+            Err(VaultError::LedgerAppNotOpened("Bitcoin Test".to_string()))
+        );
+        assert_eq!(
+            check_bitcoin_app("Ethereum", &BitcoinApps::Mainnet),
+            Err(VaultError::LedgerAppNotOpened("Bitcoin".to_string()))
+        );
+        assert_eq!(
+            check_bitcoin_app(DASHBOARD_NAME, &BitcoinApps::Mainnet),
+            Err(VaultError::LedgerAppNotOpened("Bitcoin".to_string()))
+        );
+    }
+
+    fn ethereum_fingerprint(address: u8) -> HDPathFingerprint {
+        HDPathFingerprint::from_bytes(
+            StandardHDPath::from_str(FINGERPRINT_PATH_ETHEREUM).unwrap(),
+            &[address; 20],
+        )
+    }
+
+    #[test]
+    fn verify_bitcoin_source_with_ethereum_app() {
+        let master = Fingerprint::from(&[1u8, 2, 3, 4][..]);
+        let source = LedgerSource {
+            fingerprints: vec![],
+            device_model: None,
+            master_fingerprint: Some(master),
+        };
+
+        let updated = source.verify_with(None, vec![ethereum_fingerprint(1)]).unwrap();
+        let updated = updated.expect("fingerprint not added");
+        assert_eq!(Some(master), updated.master_fingerprint);
+        assert_eq!(vec![ethereum_fingerprint(1)], updated.fingerprints);
+
+        // next time it's verified by the added fingerprint
+        assert_eq!(Ok(None), updated.verify_with(None, vec![ethereum_fingerprint(1)]));
+        assert_eq!(Err(VaultError::WrongLedger), updated.verify_with(None, vec![ethereum_fingerprint(2)]));
+        // and still by the master key with a Bitcoin app
+        assert_eq!(Ok(None), updated.verify_with(Some(master), vec![]));
+    }
+
+    #[test]
+    fn verify_ethereum_source_with_bitcoin_app() {
+        let master = Fingerprint::from(&[1u8, 2, 3, 4][..]);
+        let source = LedgerSource {
+            fingerprints: vec![ethereum_fingerprint(1)],
+            device_model: None,
+            master_fingerprint: None,
+        };
+
+        let updated = source.verify_with(Some(master), vec![]).unwrap();
+        let updated = updated.expect("fingerprint not added");
+        assert_eq!(Some(master), updated.master_fingerprint);
+        assert_eq!(vec![ethereum_fingerprint(1)], updated.fingerprints);
+
+        // next time it's verified by the added master fingerprint
+        assert_eq!(Ok(None), updated.verify_with(Some(master), vec![]));
+        assert_eq!(
+            Err(VaultError::WrongLedger),
+            updated.verify_with(Some(Fingerprint::from(&[4u8, 3, 2, 1][..])), vec![])
+        );
+        assert_eq!(Ok(None), updated.verify_with(None, vec![ethereum_fingerprint(1)]));
+    }
+
+    #[test]
+    fn remember_fingerprints_for_dashboard_source() {
+        let master = Fingerprint::from(&[1u8, 2, 3, 4][..]);
+        let source = LedgerSource {
+            fingerprints: vec![],
+            device_model: Some("Nano X".to_string()),
+            master_fingerprint: None,
+        };
+
+        let updated = source.verify_with(Some(master), vec![]).unwrap();
+        let updated = updated.expect("fingerprint not added");
+        assert_eq!(Some(master), updated.master_fingerprint);
+        assert_eq!(Some("Nano X".to_string()), updated.device_model);
+
+        let updated = updated.verify_with(None, vec![ethereum_fingerprint(1)]).unwrap();
+        let updated = updated.expect("fingerprint not added");
+        assert_eq!(vec![ethereum_fingerprint(1)], updated.fingerprints);
+        assert_eq!(Ok(None), updated.verify_with(Some(master), vec![ethereum_fingerprint(1)]));
+    }
+
+    #[test]
+    fn pass_when_nothing_can_be_checked() {
+        let source = LedgerSource {
+            fingerprints: vec![ethereum_fingerprint(1)],
+            device_model: None,
+            master_fingerprint: None,
+        };
+        // ex. when the dashboard is opened
+        assert_eq!(Ok(None), source.verify_with(None, vec![]));
+    }
+
+    #[cfg(test_ledger_bitcoin)]
+    #[test]
+    fn fingerprint_bitcoin() {
+        let source = LedgerSource::create_connected().unwrap();
+        assert!(source.master_fingerprint.is_some());
+        // the Bitcoin app doesn't give Ethereum addresses
+        assert!(source.fingerprints.is_empty());
+        // the model is available only from the dashboard
+        assert_eq!(source.device_model, None);
+
+        let manager = LedgerKey::new_connected().unwrap();
+        assert_eq!(Ok(None), source.verify(&manager));
+    }
+
+    #[cfg(test_ledger_bitcoin)]
+    #[test]
+    fn fail_to_verify_different_ledger() {
+        let source = LedgerSource {
+            fingerprints: vec![],
+            device_model: None,
+            master_fingerprint: Some(Fingerprint::default()),
+        };
+        let manager = LedgerKey::new_connected().unwrap();
+        assert_eq!(source.verify(&manager), Err(VaultError::WrongLedger));
+    }
+
+    #[cfg(test_ledger_bitcoin)]
+    #[test]
+    fn get_bitcoin_app_details() {
+        let manager = LedgerKey::new_connected().unwrap();
+        let act = manager.get_app_details().unwrap();
+        assert_eq!(act.name, "Bitcoin".to_string());
+        assert!(!act.version.is_empty());
+        assert!(ensure_bitcoin_app(&manager, &BitcoinApps::Mainnet).is_ok());
+        assert_eq!(
+            ensure_bitcoin_app(&manager, &BitcoinApps::Testnet),
+            Err(VaultError::LedgerAppNotOpened("Bitcoin Test".to_string()))
+        );
+    }
+}
@@ -18,12 +18,18 @@ limitations under the License.
 
 pub mod addressbook;
 pub mod archive;
+pub mod discovery;
 pub mod error;
 pub mod entry;
+pub mod keystore;
+pub mod master;
+pub mod migrations;
 mod vault_ethereum;
 mod vault_bitcoin;
 pub mod vault;
+pub mod verify;
 
+use crate::storage::error::VaultError;
 use std::{
     env,
     path::{Path, PathBuf},
@@ -43,28 +49,68 @@ pub struct Storages {
     not(target_os = "ios"),
     not(target_os = "android")
 ))]
-pub fn default_path() -> PathBuf {
-    let mut config_dir = env::home_dir().expect("Expect path to home dir");
+pub fn default_path() -> Result<PathBuf, VaultError> {
+    let mut config_dir = home_dir(|name| env::var(name).ok())?;
     config_dir.push(".emerald");
-    config_dir
+    Ok(config_dir)
 }
 
 /// Default path (Mac OS X)
 #[cfg(target_os = "macos")]
-pub fn default_path() -> PathBuf {
-    let mut config_dir = env::home_dir().expect("Expect path to home dir");
+pub fn default_path() -> Result<PathBuf, VaultError> {
+    let mut config_dir = home_dir(|name| env::var(name).ok())?;
     config_dir.push("Library");
     config_dir.push("Emerald");
-    config_dir
+    Ok(config_dir)
+}
+
+/// Find home directory of the current user on a unix-like OS, which is specified by `HOME`
+///
+/// # Arguments:
+///
+/// * `env` - environment variables lookup
+///
+#[cfg(any(all(unix, not(target_os = "ios"), not(target_os = "android")), test))]
+fn home_dir<F>(env: F) -> Result<PathBuf, VaultError>
+    where F: Fn(&str) -> Option<String> {
+    match env("HOME").filter(|v| !v.is_empty()) {
+        Some(home) => Ok(PathBuf::from(home)),
+        None => Err(VaultError::FilesystemError("HOME is not set".to_string())),
+    }
 }
 
 /// Default path (Windows OS)
 #[cfg(target_os = "windows")]
-pub fn default_path() -> PathBuf {
-    let app_data_var = env::var("APPDATA").expect("Expect 'APPDATA' environment variable");
-    let mut config_dir = PathBuf::from(app_data_var);
+pub fn default_path() -> Result<PathBuf, VaultError> {
+    let mut config_dir = windows_app_data(|name| env::var(name).ok())?;
     config_dir.push(".emerald");
-    config_dir
+    Ok(config_dir)
+}
+
+/// Find Roaming AppData dir on Windows. Uses `APPDATA` if it's set, or `USERPROFILE\AppData\Roaming`
+/// otherwise (`APPDATA` may be missing when running as a service)
+///
+/// # Arguments:
+///
+/// * `env` - environment variables lookup
+///
+#[cfg(any(target_os = "windows", test))]
+fn windows_app_data<F>(env: F) -> Result<PathBuf, VaultError>
+    where F: Fn(&str) -> Option<String> {
+    if let Some(app_data) = env("APPDATA").filter(|v| !v.is_empty()) {
+        return Ok(PathBuf::from(app_data));
+    }
+    match env("USERPROFILE").filter(|v| !v.is_empty()) {
+        Some(profile) => {
+            let mut path = PathBuf::from(profile);
+            path.push("AppData");
+            path.push("Roaming");
+            Ok(path)
+        }
+        None => Err(VaultError::FilesystemError(
+            "Neither APPDATA nor USERPROFILE is set".to_string(),
+        )),
+    }
 }
 
 /// Build `chain` specific path for selected `folder`
@@ -81,3 +127,79 @@ pub fn build_path(base_path: &Path, chain: &str, folder: &str) -> PathBuf {
     path.push(folder);
     path
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_uses_app_data() {
+        let act = windows_app_data(|name| match name {
+            "APPDATA" => Some("C:\\Users\\test\\AppData\\Roaming".to_string()),
+            "USERPROFILE" => Some("C:\\Users\\other".to_string()),
+            _ => None,
+        });
+        assert_eq!(act, Ok(PathBuf::from("C:\\Users\\test\\AppData\\Roaming")));
+    }
+
+    #[test]
+    fn windows_uses_user_profile_without_app_data() {
+        let act = windows_app_data(|name| match name {
+            "USERPROFILE" => Some("C:\\Users\\test".to_string()),
+            _ => None,
+        }).unwrap();
+        assert!(act.starts_with("C:\\Users\\test"));
+        assert!(act.ends_with(PathBuf::from("AppData").join("Roaming")));
+    }
+
+    #[test]
+    fn windows_fails_without_env() {
+        let act = windows_app_data(|_| None);
+        assert!(act.is_err());
+        let act = windows_app_data(|_| Some("".to_string()));
+        assert!(act.is_err());
+    }
+
+    #[test]
+    fn uses_home_env() {
+        let act = home_dir(|name| match name {
+            "HOME" => Some("/home/test".to_string()),
+            _ => None,
+        });
+        assert_eq!(act, Ok(PathBuf::from("/home/test")));
+    }
+
+    #[test]
+    fn fails_without_home_env() {
+        assert!(home_dir(|_| None).is_err());
+        assert!(home_dir(|_| Some("".to_string())).is_err());
+    }
+
+    #[cfg(all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    ))]
+    #[test]
+    fn default_path_in_home() {
+        let act = default_path().unwrap();
+        assert!(act.ends_with(".emerald"));
+        assert!(act.starts_with(env::var("HOME").unwrap()));
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn default_path_in_library() {
+        let act = default_path().unwrap();
+        assert!(act.ends_with(PathBuf::from("Library").join("Emerald")));
+        assert!(act.starts_with(env::var("HOME").unwrap()));
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn default_path_in_app_data() {
+        let act = default_path().unwrap();
+        assert!(act.ends_with(".emerald"));
+    }
+}
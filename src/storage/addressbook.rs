@@ -37,6 +37,8 @@ const FORMAT: &str = "bookmark/base64";
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AddressbookStorage {
     path: PathBuf,
+    /// If true, any change of the addressbook is rejected with `VaultError::ReadOnly`
+    read_only: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -89,11 +91,20 @@ impl TryFrom<CsvRecord> for AddressBookmark {
 
 impl AddressbookStorage {
     pub fn from_path<P>(path: P) -> AddressbookStorage
+    where
+        P: AsRef<Path>,
+    {
+        AddressbookStorage::with_mode(path, false)
+    }
+
+    /// Addressbook at the path, which rejects any change if `read_only` is true
+    pub fn with_mode<P>(path: P, read_only: bool) -> AddressbookStorage
     where
         P: AsRef<Path>,
     {
         AddressbookStorage {
             path: PathBuf::from(path.as_ref()),
+            read_only,
         }
     }
 
@@ -158,7 +169,13 @@ impl VaultAccess<AddressBookmark> for AddressbookStorage {
     }
 
     fn add(&self, item: AddressBookmark) -> Result<Uuid, VaultError> {
+        if self.read_only {
+            return Err(VaultError::ReadOnly);
+        }
         let id = item.get_id();
+        if !item.details.address.is_valid_for(item.details.blockchain) {
+            return Err(VaultError::IncorrectBlockchainError);
+        }
 
         let first_time = !self.path.exists();
         let f = OpenOptions::new()
@@ -187,6 +204,9 @@ impl VaultAccess<AddressBookmark> for AddressbookStorage {
     }
 
     fn remove(&self, id: Uuid) -> Result<bool, VaultError> {
+        if self.read_only {
+            return Err(VaultError::ReadOnly);
+        }
         let all = self.get_all()?;
         let mut bak_path = self.path.clone();
         if !bak_path.set_extension(".bak") {
@@ -247,6 +267,13 @@ impl VaultAccess<AddressBookmark> for AddressbookStorage {
 
     fn update(&self, entry: AddressBookmark) -> Result<bool, VaultError> {
         //TODO atomic update, in one rewrite
+        // check everything that may reject the new value before the current one is removed
+        if self.read_only {
+            return Err(VaultError::ReadOnly);
+        }
+        if !entry.details.address.is_valid_for(entry.details.blockchain) {
+            return Err(VaultError::IncorrectBlockchainError);
+        }
         let id = entry.get_id();
         if self.remove(id)? {
             self.add(entry)?;
@@ -263,6 +290,7 @@ mod tests {
         blockchain::chains::Blockchain,
         storage::{
             addressbook::{AddressBookmark, AddressbookStorage},
+            error::VaultError,
             vault::VaultAccess,
         },
         structs::book::{AddressRef, BookmarkDetails},
@@ -383,6 +411,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reject_address_of_other_blockchain() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let book = AddressbookStorage::from_path(tmp_dir.into_path().join("addressbook.csv"));
+        let item = AddressBookmark {
+            id: Uuid::new_v4(),
+            details: BookmarkDetails {
+                blockchain: Blockchain::Bitcoin,
+                label: Some("Hello World!".to_string()),
+                description: None,
+                address: AddressRef::EthereumAddress(
+                    EthereumAddress::from_str("0x085fb4f24031eaedbc2b611aa528f22343eb52db")
+                        .unwrap(),
+                ),
+                created_at: Utc::now(),
+            },
+        };
+        let act = book.add(item);
+        assert_eq!(Err(VaultError::IncorrectBlockchainError), act);
+
+        let all = book.get_all().expect("get_all() failed");
+        assert!(all.is_empty());
+    }
+
+    #[test]
+    fn keeps_current_on_invalid_update() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let book = AddressbookStorage::from_path(tmp_dir.into_path().join("addressbook.csv"));
+        let item = AddressBookmark {
+            id: Uuid::new_v4(),
+            details: BookmarkDetails {
+                blockchain: Blockchain::Ethereum,
+                label: Some("Hello World!".to_string()),
+                description: None,
+                address: AddressRef::EthereumAddress(
+                    EthereumAddress::from_str("0x085fb4f24031eaedbc2b611aa528f22343eb52db")
+                        .unwrap(),
+                ),
+                created_at: Utc::now(),
+            },
+        };
+        let id = book.add(item.clone()).unwrap();
+
+        let mut invalid = item.clone();
+        invalid.details.blockchain = Blockchain::Bitcoin;
+        assert_eq!(Err(VaultError::IncorrectBlockchainError), book.update(invalid));
+
+        let act = book.get(id).expect("removed by update");
+        assert_eq!(Blockchain::Ethereum, act.details.blockchain);
+        assert_eq!(Some("Hello World!".to_string()), act.details.label);
+    }
+
+    #[test]
+    fn rejects_changes_in_readonly_mode() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let path = tmp_dir.into_path().join("addressbook.csv");
+        let item = AddressBookmark {
+            id: Uuid::new_v4(),
+            details: BookmarkDetails {
+                blockchain: Blockchain::Ethereum,
+                label: Some("Hello World!".to_string()),
+                description: None,
+                address: AddressRef::EthereumAddress(
+                    EthereumAddress::from_str("0x085fb4f24031eaedbc2b611aa528f22343eb52db")
+                        .unwrap(),
+                ),
+                created_at: Utc::now(),
+            },
+        };
+        let id = AddressbookStorage::from_path(&path).add(item.clone()).unwrap();
+
+        let book = AddressbookStorage::with_mode(&path, true);
+        assert_eq!(Err(VaultError::ReadOnly), book.add(AddressBookmark { id: Uuid::new_v4(), ..item.clone() }));
+        assert_eq!(Err(VaultError::ReadOnly), book.remove(id));
+        assert_eq!(Err(VaultError::ReadOnly), book.update(item));
+        assert_eq!(vec![id], book.list().unwrap());
+    }
+
     #[test]
     fn add_few_and_remove_all() {
         let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
@@ -0,0 +1,227 @@
+//! # Discovery of used accounts on a seed
+//!
+//! When a wallet is restored from a seed the vault doesn't know which accounts were used before.
+//! It checks accounts one by one, in the order of BIP-44, until it finds a number of accounts without
+//! any balance in a row, or until it reaches `MAX_ACCOUNTS`. The balance itself is provided by the caller, so the vault doesn't depend
+//! on any particular API to access the blockchain.
+
+use crate::{
+    blockchain::{
+        bitcoin::AddressType,
+        chains::{Blockchain, BlockchainType},
+    },
+    storage::{
+        error::VaultError,
+        vault::{VaultAccess, VaultStorage},
+    },
+    EthereumAddress,
+};
+use bitcoin::Address as BitcoinAddress;
+use hdpath::{AccountHDPath, Purpose, StandardHDPath};
+use uuid::Uuid;
+
+/// Number of accounts without balance in a row after which the discovery stops
+pub const ACCOUNT_GAP_LIMIT: u32 = 5;
+
+/// Max number of accounts checked by the discovery, if all of them have balance
+pub const MAX_ACCOUNTS: u32 = 100;
+
+fn account_hd_path(blockchain: Blockchain, account: u32) -> Result<AccountHDPath, VaultError> {
+    let (purpose, coin_type) = match blockchain.get_type() {
+        BlockchainType::Bitcoin => {
+            let base = AddressType::P2WPKH.get_hd_path(0, &blockchain.as_bitcoin_network());
+            (base.purpose().clone(), base.coin_type())
+        }
+        BlockchainType::Ethereum => (Purpose::Pubkey, blockchain.coin_type()),
+    };
+    AccountHDPath::try_new(purpose, coin_type, account)
+        .map_err(|_| VaultError::InvalidDataError("hd_path".to_string()))
+}
+
+impl VaultStorage {
+    /// Find accounts used on the seed. For each account it checks the first receive address
+    /// (`m/purpose'/coin'/account'/0/0`) with the `has_balance` function, and stops after
+    /// `ACCOUNT_GAP_LIMIT` accounts without balance in a row, but checks no more than `MAX_ACCOUNTS`
+    /// accounts in total.
+    ///
+    /// Returns accounts which have balance
+    ///
+    /// # Arguments
+    ///
+    /// * `seed_id` - id of the seed
+    /// * `blockchain` - blockchain to check, defines the HD Path of the accounts
+    /// * `seed_password` - password to decrypt the seed, not used for a Ledger
+    /// * `has_balance` - called with an account and the address on it, must return `true` if the address has a balance
+    pub fn discover_accounts<F>(
+        &self,
+        seed_id: Uuid,
+        blockchain: Blockchain,
+        seed_password: Option<String>,
+        mut has_balance: F,
+    ) -> Result<Vec<AccountHDPath>, VaultError>
+    where
+        F: FnMut(&AccountHDPath, &str) -> Result<bool, VaultError>,
+    {
+        let seed = self.seeds().get(seed_id)?;
+        let mut result = Vec::new();
+        let mut empty = 0;
+        let mut start = 0;
+        while start < MAX_ACCOUNTS {
+            let end = std::cmp::min(start + ACCOUNT_GAP_LIMIT, MAX_ACCOUNTS);
+            let accounts = (start..end)
+                .map(|n| account_hd_path(blockchain, n))
+                .collect::<Result<Vec<AccountHDPath>, _>>()?;
+            let hd_paths = accounts
+                .iter()
+                .map(|a| a.address_at(0, 0))
+                .collect::<Result<Vec<StandardHDPath>, _>>()
+                .map_err(|_| VaultError::InvalidDataError("hd_path".to_string()))?;
+            // the seed is decrypted once per batch of the accounts
+            let addresses: Vec<String> = match blockchain.get_type() {
+                BlockchainType::Bitcoin => seed
+                    .source
+                    .get_addresses::<BitcoinAddress>(seed_password.clone(), &hd_paths, blockchain)?
+                    .into_iter()
+                    .map(|(_, a)| a.to_string())
+                    .collect(),
+                BlockchainType::Ethereum => seed
+                    .source
+                    .get_addresses::<EthereumAddress>(seed_password.clone(), &hd_paths, blockchain)?
+                    .into_iter()
+                    .map(|(_, a)| a.to_string())
+                    .collect(),
+            };
+            if addresses.len() != accounts.len() {
+                return Err(VaultError::PublicKeyUnavailable);
+            }
+            for (account, address) in accounts.into_iter().zip(addresses) {
+                if has_balance(&account, address.as_str())? {
+                    result.push(account);
+                    empty = 0;
+                } else {
+                    empty += 1;
+                    if empty >= ACCOUNT_GAP_LIMIT {
+                        return Ok(result);
+                    }
+                }
+            }
+            start = end;
+        }
+        warn!("Stop discovery of accounts at max {} accounts", MAX_ACCOUNTS);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mnemonic::{Language, Mnemonic},
+        structs::seed::{Seed, SeedSource},
+    };
+    use std::str::FromStr;
+    use tempdir::TempDir;
+
+    fn create_seed(vault: &VaultStorage) -> Uuid {
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "quote ivory blast onion below kangaroo tonight spread awkward decide farm gun exact wood brown",
+        ).unwrap();
+        vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+            ..Seed::default()
+        }).unwrap()
+    }
+
+    #[test]
+    fn stops_after_gap_of_empty_accounts() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = create_seed(&vault);
+
+        let mut checked = Vec::new();
+        let act = vault.discover_accounts(
+            seed_id,
+            Blockchain::Bitcoin,
+            Some("test".to_string()),
+            |account, address| {
+                checked.push(account.account());
+                assert!(address.starts_with("bc1"));
+                Ok(account.account() == 0 || account.account() == 2)
+            },
+        ).unwrap();
+
+        assert_eq!(
+            vec![
+                AccountHDPath::from_str("m/84'/0'/0'").unwrap(),
+                AccountHDPath::from_str("m/84'/0'/2'").unwrap(),
+            ],
+            act
+        );
+        // account 2 is the last with balance, then 5 more are empty
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7], checked);
+    }
+
+    #[test]
+    fn nothing_found_on_empty_seed() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = create_seed(&vault);
+
+        let mut checked = 0;
+        let act = vault.discover_accounts(
+            seed_id,
+            Blockchain::Ethereum,
+            Some("test".to_string()),
+            |account, address| {
+                checked += 1;
+                assert_eq!(60, account.coin_type());
+                assert!(address.starts_with("0x"));
+                Ok(false)
+            },
+        ).unwrap();
+
+        assert!(act.is_empty());
+        assert_eq!(ACCOUNT_GAP_LIMIT, checked);
+    }
+
+    #[test]
+    fn stops_at_max_accounts() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = create_seed(&vault);
+
+        let mut checked = 0;
+        let act = vault.discover_accounts(
+            seed_id,
+            Blockchain::Ethereum,
+            Some("test".to_string()),
+            |_, _| {
+                checked += 1;
+                Ok(true)
+            },
+        ).unwrap();
+
+        assert_eq!(MAX_ACCOUNTS as usize, act.len());
+        assert_eq!(MAX_ACCOUNTS, checked);
+        assert_eq!(
+            AccountHDPath::from_str("m/44'/60'/99'").unwrap(),
+            act[act.len() - 1]
+        );
+    }
+
+    #[test]
+    fn stops_on_checker_error() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = create_seed(&vault);
+
+        let act = vault.discover_accounts(
+            seed_id,
+            Blockchain::Bitcoin,
+            Some("test".to_string()),
+            |_, _| Err(VaultError::PublicKeyUnavailable),
+        );
+        assert_eq!(Err(VaultError::PublicKeyUnavailable), act);
+    }
+}
@@ -1,4 +1,7 @@
 use crate::blockchain::bitcoin::XPub;
+use crate::blockchain::chains::Blockchain;
+use crate::storage::error::VaultError;
+use hdpath::Purpose;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AddEntryOptions {
@@ -23,3 +26,15 @@ impl AddEntryOptions {
         }
     }
 }
+
+/// Verify that the purpose of an HD Path is one of expected for the blockchain
+pub(crate) fn verify_purpose(blockchain: Blockchain, purpose: &Purpose) -> Result<(), VaultError> {
+    let value = purpose.as_value().as_number();
+    if blockchain.expected_purposes().contains(&value) {
+        Ok(())
+    } else {
+        Err(VaultError::UnsupportedDataError(
+            format!("HD Path purpose {}' is not supported for {:?}", value, blockchain)
+        ))
+    }
+}
@@ -22,6 +22,16 @@ pub enum VaultError {
     PublicKeyUnavailable,
     CryptoFailed(CryptoError),
     HWKeyFailed(HWKeyError),
+    /// Ledger is connected, but a different app is opened. Contains name of the expected app
+    LedgerAppNotOpened(String),
+    /// Connected Ledger is not the same device that was used for the seed
+    WrongLedger,
+    /// Seed has fingerprints of the Ledger, but none of them can be checked with the opened app
+    UnverifiedLedger,
+    /// Password doesn't satisfy the password policy
+    WeakPassword,
+    /// Vault is opened in read-only mode
+    ReadOnly,
 }
 
 impl std::convert::From<ConversionError> for VaultError {
@@ -91,7 +101,10 @@ impl std::convert::From<hex::FromHexError> for VaultError {
 
 impl std::convert::From<CryptoError> for VaultError {
     fn from(err: CryptoError) -> Self {
-        VaultError::CryptoFailed(err)
+        match err {
+            CryptoError::WeakPassword => VaultError::WeakPassword,
+            _ => VaultError::CryptoFailed(err),
+        }
     }
 }
 
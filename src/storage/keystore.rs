@@ -0,0 +1,238 @@
+//! # Ethereum JSON keyfiles in the format of a Geth `keystore/` dir
+
+use crate::{
+    blockchain::chains::Blockchain,
+    convert::json::keyfile::EthereumJsonV3File,
+    storage::{
+        error::VaultError,
+        vault::{VaultAccess, VaultStorage},
+    },
+    structs::wallet::PKType,
+    EthereumAddress,
+};
+use chrono::{DateTime, Utc};
+use std::{
+    collections::HashSet,
+    fs::OpenOptions,
+    io::{Cursor, ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+use zip::{write::FileOptions, ZipWriter};
+
+/// Source of the current time, to make filenames predictable in tests
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Current time from the system clock
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Filename for a keyfile, as `UTC--<created time>--<address>`
+pub fn generate_filename(address: &EthereumAddress) -> String {
+    generate_filename_with(&SystemClock, address)
+}
+
+/// Filename for a keyfile, as `UTC--<created time>--<address>`, with time taken from the clock
+pub fn generate_filename_with<C: Clock>(clock: &C, address: &EthereumAddress) -> String {
+    keystore_filename(clock.now(), hex::encode(address.as_ref()).as_str())
+}
+
+fn keystore_filename(time: DateTime<Utc>, suffix: &str) -> String {
+    format!("UTC--{}--{}", time.format("%Y-%m-%dT%H-%M-%S%.3fZ"), suffix)
+}
+
+/// Max number of suffixes to try for a filename which is already used
+const MAX_FILENAME_ATTEMPTS: usize = 1000;
+
+/// Write the keyfile into the dir (ex. Geth `keystore/`). It never overwrites an existing file, if
+/// the generated filename is already taken it adds a numeric suffix (`-1`, `-2`, etc).
+///
+/// Returns path to the created file
+pub fn write_keyfile<P: AsRef<Path>>(
+    dir: P,
+    json: &EthereumJsonV3File,
+) -> Result<PathBuf, VaultError> {
+    let content =
+        serde_json::to_vec(json).map_err(|e| VaultError::InvalidDataError(e.to_string()))?;
+    let filename = match json.address {
+        Some(address) => generate_filename(&address),
+        None => keystore_filename(Utc::now(), json.id.to_string().as_str()),
+    };
+    for n in 0..MAX_FILENAME_ATTEMPTS {
+        let path = if n == 0 {
+            dir.as_ref().join(&filename)
+        } else {
+            dir.as_ref().join(format!("{}-{}", filename, n))
+        };
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut f) => {
+                f.write_all(content.as_slice())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Err(VaultError::FilesystemError(format!(
+        "No available filename for {}",
+        filename
+    )))
+}
+
+impl VaultStorage {
+    /// Export all private keys used for the blockchain as JSON keyfiles packed into a zip archive.
+    /// Keys derived from a seed are not included, because they don't exist as separate keyfiles.
+    ///
+    /// Returns bytes of the zip archive
+    pub fn export_ethereum_zip(&self, blockchain: Blockchain) -> Result<Vec<u8>, VaultError> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let mut exported = HashSet::new();
+        for wallet in self.wallets().list_entries()? {
+            for entry in wallet.entries.iter().filter(|e| e.blockchain == blockchain) {
+                let pk_id = match entry.key {
+                    PKType::PrivateKeyRef(id) => id,
+                    PKType::SeedHd(_) | PKType::Watch => continue,
+                };
+                if !exported.insert(pk_id) {
+                    continue;
+                }
+                let pk = self.keys().get(pk_id)?;
+                let json = EthereumJsonV3File::from_wallet(entry.label.clone(), &pk)
+                    .map_err(|_| VaultError::InvalidPrivateKey)?;
+                let filename = match json.address {
+                    Some(address) => generate_filename(&address),
+                    None => keystore_filename(Utc::now(), pk_id.to_string().as_str()),
+                };
+                let content = serde_json::to_vec(&json)
+                    .map_err(|e| VaultError::InvalidDataError(e.to_string()))?;
+                zip.start_file(filename, FileOptions::default())
+                    .map_err(|e| VaultError::FilesystemError(e.to_string()))?;
+                zip.write_all(content.as_slice())?;
+            }
+        }
+        let result = zip
+            .finish()
+            .map_err(|e| VaultError::FilesystemError(e.to_string()))?;
+        Ok(result.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{structs::pk::PrivateKeyHolder, EthereumPrivateKey};
+    use chrono::TimeZone;
+    use std::{convert::TryFrom, io::Read, str::FromStr};
+    use tempdir::TempDir;
+
+    #[test]
+    fn filename_in_geth_format() {
+        let address = EthereumAddress::from_str("0x6412c428fc02902d137b60dc0bd0f6cd1255ea99").unwrap();
+        let filename = generate_filename(&address);
+        assert!(filename.starts_with("UTC--"));
+        assert!(filename.ends_with("Z--6412c428fc02902d137b60dc0bd0f6cd1255ea99"));
+    }
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn filename_with_fixed_clock() {
+        let clock = FixedClock(Utc.ymd(2020, 6, 20).and_hms_milli(3, 4, 5, 678));
+        let address = EthereumAddress::from_str("0x6412c428fc02902d137b60dc0bd0f6cd1255ea99").unwrap();
+        assert_eq!(
+            "UTC--2020-06-20T03-04-05.678Z--6412c428fc02902d137b60dc0bd0f6cd1255ea99",
+            generate_filename_with(&clock, &address)
+        );
+
+        let clock = FixedClock(Utc.ymd(2020, 6, 20).and_hms(3, 4, 5));
+        assert_eq!(
+            "UTC--2020-06-20T03-04-05.000Z--6412c428fc02902d137b60dc0bd0f6cd1255ea99",
+            generate_filename_with(&clock, &address)
+        );
+    }
+
+    #[test]
+    fn write_keyfiles_in_tight_loop() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let pk = EthereumPrivateKey::from_str(
+            "0xfac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd",
+        )
+        .unwrap();
+        let json = EthereumJsonV3File::from_pk(None, pk, "test".to_string()).unwrap();
+
+        let mut paths = Vec::new();
+        for _ in 0..5 {
+            paths.push(write_keyfile(tmp_dir.path(), &json).unwrap());
+        }
+        let unique: HashSet<PathBuf> = paths.iter().cloned().collect();
+        assert_eq!(5, unique.len());
+
+        let files = std::fs::read_dir(tmp_dir.path()).unwrap().count();
+        assert_eq!(5, files);
+        for p in paths {
+            let content = std::fs::read_to_string(p).unwrap();
+            assert!(EthereumJsonV3File::try_from(content).is_ok());
+        }
+    }
+
+    #[test]
+    fn export_keys_for_blockchain() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        vault
+            .create_new()
+            .raw_pk(
+                hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                    .unwrap(),
+                "test",
+                Blockchain::Ethereum,
+            )
+            .unwrap();
+        vault
+            .create_new()
+            .raw_pk(
+                hex::decode("3c9229289a6125f7fdf1885a77bb12c37a8d3b4962d936f7e3084dece32a3ca1")
+                    .unwrap(),
+                "test",
+                Blockchain::Ethereum,
+            )
+            .unwrap();
+        vault
+            .create_new()
+            .raw_pk(
+                hex::decode("00b413b37c71bfb92719d16e28d7329dea5befa0d0b8190742f89e55617991cf")
+                    .unwrap(),
+                "test",
+                Blockchain::EthereumClassic,
+            )
+            .unwrap();
+
+        let data = vault.export_ethereum_zip(Blockchain::Ethereum).unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(data)).unwrap();
+        assert_eq!(2, zip.len());
+
+        for i in 0..zip.len() {
+            let mut file = zip.by_index(i).unwrap();
+            assert!(file.name().starts_with("UTC--"));
+            let mut content = String::new();
+            file.read_to_string(&mut content).unwrap();
+            let json = EthereumJsonV3File::try_from(content).unwrap();
+            assert!(file.name().ends_with(hex::encode(json.address.unwrap().as_ref()).as_str()));
+            let pk = PrivateKeyHolder::try_from(&json).unwrap();
+            assert!(pk.decrypt("test").is_ok());
+        }
+    }
+}
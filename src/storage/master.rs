@@ -0,0 +1,307 @@
+//! # Vault-wide master password
+//!
+//! Instead of a separate password for each seed and private key, the vault may keep a random
+//! key-wrapping key encrypted with a single master password. Once the vault is unlocked with the
+//! master password, the key provides the password for each individual item.
+
+use crate::{
+    convert::error::ConversionError,
+    proto::crypto::Encrypted as proto_Encrypted,
+    storage::{
+        error::VaultError,
+        vault::{write_atomic, SingleFileEntry, VaultAccess, VaultStorage},
+    },
+    structs::{
+        crypto::Encrypted,
+        pk::{EthereumPk3, PrivateKeyHolder, PrivateKeyType},
+        seed::SeedSource,
+    },
+};
+use protobuf::{parse_from_bytes, Message};
+use rand::{prelude::Rng, thread_rng};
+use std::{collections::HashMap, convert::TryFrom, fs, path::PathBuf};
+use uuid::Uuid;
+use zeroize::Zeroizing;
+
+/// File with the key-wrapping key encrypted with the master password
+const MASTER_KEY_FILE: &str = "master.key";
+const MASTER_KEY_SIZE: usize = 32;
+
+/// Unlocked key-wrapping key of the vault
+pub struct MasterKey {
+    key: Zeroizing<Vec<u8>>,
+}
+
+impl MasterKey {
+    /// Password to encrypt and decrypt individual seeds and private keys of the vault
+    pub fn item_password(&self) -> Zeroizing<String> {
+        Zeroizing::new(hex::encode(self.key.as_slice()))
+    }
+}
+
+impl VaultStorage {
+    fn master_key_file(&self) -> PathBuf {
+        self.dir.join(MASTER_KEY_FILE)
+    }
+
+    /// Check if the vault is protected by a master password
+    pub fn has_master_password(&self) -> bool {
+        self.master_key_file().is_file()
+    }
+
+    /// Generate a new key-wrapping key and protect it with the master password. Fails if the vault
+    /// already has a master password, or if the password doesn't satisfy the password policy of
+    /// the vault
+    pub fn set_master_password(&self, master_password: &str) -> Result<MasterKey, VaultError> {
+        if self.is_read_only() {
+            return Err(VaultError::ReadOnly);
+        }
+        if self.has_master_password() {
+            return Err(VaultError::InvalidDataError("Master password is already set".to_string()));
+        }
+        let mut key = Zeroizing::new(vec![0u8; MASTER_KEY_SIZE]);
+        thread_rng()
+            .try_fill(key.as_mut_slice())
+            .map_err(|_| VaultError::UnrecognizedError)?;
+        let encrypted = match self.get_password_policy() {
+            Some(policy) => Encrypted::encrypt_with_policy(key.to_vec(), master_password, policy)?,
+            None => Encrypted::encrypt(key.to_vec(), master_password)?,
+        };
+        let data = proto_Encrypted::try_from(&encrypted)?
+            .write_to_bytes()
+            .map_err(|e| ConversionError::from(e))?;
+        write_atomic(self.master_key_file(), data)?;
+        Ok(MasterKey { key })
+    }
+
+    /// Unlock the vault with the master password
+    pub fn unlock(&self, master_password: &str) -> Result<MasterKey, VaultError> {
+        if !self.has_master_password() {
+            return Err(VaultError::DataNotFound);
+        }
+        let data = fs::read(self.master_key_file())?;
+        let encrypted = Encrypted::try_from(&parse_from_bytes::<proto_Encrypted>(data.as_slice())?)?;
+        let key = Zeroizing::new(encrypted.decrypt(master_password)?);
+        if key.len() != MASTER_KEY_SIZE {
+            return Err(VaultError::InvalidDataError("master_key".to_string()));
+        }
+        Ok(MasterKey { key })
+    }
+
+    /// Re-encrypt seeds and private keys from their individual passwords to the master key.
+    /// Items that are not in the `passwords` are kept as is.
+    ///
+    /// All items are re-encrypted before anything is written, so a wrong password doesn't leave
+    /// the vault partially migrated. Each item is then replaced atomically, and an item which is
+    /// already encrypted with the master key is skipped, i.e. if the migration was interrupted
+    /// it can be resumed by calling it again with the same arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `master` - unlocked master key
+    /// * `passwords` - current password for each seed or private key id to migrate
+    ///
+    /// Returns number of migrated items
+    pub fn migrate_to_master(
+        &self,
+        master: &MasterKey,
+        passwords: &HashMap<Uuid, String>,
+    ) -> Result<usize, VaultError> {
+        let item_password = master.item_password();
+        self.seeds().check_password(item_password.as_str())?;
+
+        let mut seeds = Vec::new();
+        for seed_id in self.seeds().list()? {
+            let password = match passwords.get(&seed_id) {
+                Some(p) => p,
+                None => continue,
+            };
+            let mut seed = self.seeds().get(seed_id)?;
+            if let SeedSource::Bytes(encrypted) = &seed.source {
+                if encrypted.decrypt(item_password.as_str()).map(Zeroizing::new).is_ok() {
+                    continue;
+                }
+                let value = Zeroizing::new(encrypted.decrypt(password.as_str())?);
+                seed.source = SeedSource::Bytes(Encrypted::encrypt(value.to_vec(), item_password.as_str())?);
+                seeds.push(seed);
+            }
+        }
+        let mut keys = Vec::new();
+        for pk_id in self.keys().list()? {
+            let password = match passwords.get(&pk_id) {
+                Some(p) => p,
+                None => continue,
+            };
+            let pk = self.keys().get(pk_id)?;
+            if pk.decrypt(item_password.as_str()).map(Zeroizing::new).is_ok() {
+                continue;
+            }
+            let value = Zeroizing::new(pk.decrypt(password.as_str())?);
+            let updated = match pk.pk {
+                PrivateKeyType::EthereumPk(ethereum) => PrivateKeyHolder {
+                    pk: PrivateKeyType::EthereumPk(EthereumPk3 {
+                        address: ethereum.address,
+                        key: Encrypted::encrypt(value.to_vec(), item_password.as_str())?,
+                    }),
+                    ..pk
+                },
+            };
+            keys.push(updated);
+        }
+
+        let count = seeds.len() + keys.len();
+        for seed in seeds {
+            self.seeds().update(seed)?;
+        }
+        for pk in keys {
+            self.keys().update(pk)?;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{crypto::password::PasswordPolicy, structs::seed::Seed};
+    use tempdir::TempDir;
+
+    #[test]
+    fn lock_and_unlock_with_two_items() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let seed_id = vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(b"test seed".to_vec(), "seed-password").unwrap(),
+            ..Seed::default()
+        }).unwrap();
+        let pk_id = vault.keys().add(
+            PrivateKeyHolder::create_ethereum_raw(
+                hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd").unwrap(),
+                "pk-password",
+            ).unwrap()
+        ).unwrap();
+
+        assert!(!vault.has_master_password());
+        let master = vault.set_master_password("master-password").unwrap();
+        assert!(vault.has_master_password());
+
+        let mut passwords = HashMap::new();
+        passwords.insert(seed_id, "seed-password".to_string());
+        passwords.insert(pk_id, "pk-password".to_string());
+        let migrated = vault.migrate_to_master(&master, &passwords).unwrap();
+        assert_eq!(2, migrated);
+        // lock
+        drop(master);
+
+        assert!(vault.unlock("wrong-password").is_err());
+        let master = vault.unlock("master-password").unwrap();
+        let item_password = master.item_password();
+
+        let seed = vault.seeds().get(seed_id).unwrap();
+        match seed.source {
+            SeedSource::Bytes(e) => {
+                assert_eq!(b"test seed".to_vec(), e.decrypt(item_password.as_str()).unwrap());
+                assert!(e.decrypt("seed-password").is_err());
+            }
+            _ => panic!("not bytes"),
+        }
+        let pk = vault.keys().get(pk_id).unwrap();
+        assert_eq!(
+            "fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd",
+            hex::encode(pk.decrypt(item_password.as_str()).unwrap())
+        );
+        assert!(pk.decrypt("pk-password").is_err());
+    }
+
+    #[test]
+    fn cannot_set_master_password_twice() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        assert!(vault.unlock("master-password").is_err());
+        assert!(vault.set_master_password("master-password").is_ok());
+        assert!(vault.set_master_password("master-password-2").is_err());
+        assert!(vault.unlock("master-password").is_ok());
+    }
+
+    #[test]
+    fn cannot_set_weak_master_password_with_policy() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap()
+            .with_password_policy(PasswordPolicy::default());
+
+        assert_eq!(Err(VaultError::WeakPassword), vault.set_master_password("master").map(|_| ()));
+        assert!(!vault.has_master_password());
+        assert!(vault.set_master_password("Correct-Horse-Battery-9").is_ok());
+        assert!(vault.has_master_password());
+    }
+
+    #[test]
+    fn doesnt_migrate_anything_with_wrong_password() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let seed_id = vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(b"test seed".to_vec(), "seed-password").unwrap(),
+            ..Seed::default()
+        }).unwrap();
+        let pk_id = vault.keys().add(
+            PrivateKeyHolder::create_ethereum_raw(
+                hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd").unwrap(),
+                "pk-password",
+            ).unwrap()
+        ).unwrap();
+        let master = vault.set_master_password("master-password").unwrap();
+
+        let mut passwords = HashMap::new();
+        passwords.insert(seed_id, "seed-password".to_string());
+        passwords.insert(pk_id, "wrong-password".to_string());
+        assert!(vault.migrate_to_master(&master, &passwords).is_err());
+
+        match vault.seeds().get(seed_id).unwrap().source {
+            SeedSource::Bytes(e) => assert_eq!(b"test seed".to_vec(), e.decrypt("seed-password").unwrap()),
+            _ => panic!("not bytes"),
+        }
+        assert!(vault.keys().get(pk_id).unwrap().decrypt("pk-password").is_ok());
+    }
+
+    #[test]
+    fn resumes_interrupted_migration() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let seed_id = vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(b"test seed".to_vec(), "seed-password").unwrap(),
+            ..Seed::default()
+        }).unwrap();
+        let pk_id = vault.keys().add(
+            PrivateKeyHolder::create_ethereum_raw(
+                hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd").unwrap(),
+                "pk-password",
+            ).unwrap()
+        ).unwrap();
+        let master = vault.set_master_password("master-password").unwrap();
+        let item_password = master.item_password();
+
+        // as if it was interrupted after the seed was written
+        let mut seed = vault.seeds().get(seed_id).unwrap();
+        seed.source = SeedSource::create_bytes(b"test seed".to_vec(), item_password.as_str()).unwrap();
+        vault.seeds().update(seed).unwrap();
+
+        let mut passwords = HashMap::new();
+        passwords.insert(seed_id, "seed-password".to_string());
+        passwords.insert(pk_id, "pk-password".to_string());
+        assert_eq!(1, vault.migrate_to_master(&master, &passwords).unwrap());
+        assert_eq!(0, vault.migrate_to_master(&master, &passwords).unwrap());
+
+        match vault.seeds().get(seed_id).unwrap().source {
+            SeedSource::Bytes(e) => assert_eq!(b"test seed".to_vec(), e.decrypt(item_password.as_str()).unwrap()),
+            _ => panic!("not bytes"),
+        }
+        assert_eq!(
+            "fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd",
+            hex::encode(vault.keys().get(pk_id).unwrap().decrypt(item_password.as_str()).unwrap())
+        );
+    }
+}
@@ -0,0 +1,404 @@
+//! # Versioned migrations of the vault data
+//!
+//! The vault keeps the version of its data format in a separate file. When a vault is opened,
+//! all migration steps newer than the stored version are applied in order, and the version is
+//! updated after each successful step, so an interrupted migration continues from the same place
+//! next time. If the same vault is opened concurrently, the migrations are applied only once.
+//! Wallets which cannot be read are skipped with a warning, same as when the vault is listed.
+//!
+//! A vault without seeds and wallets has nothing to migrate, and it's just marked with the current
+//! version, so data added later in the current format is never migrated again.
+
+use crate::{
+    blockchain::chains::{Blockchain, BlockchainType},
+    proto::wallet::{Wallet as proto_Wallet, WalletEntry as proto_WalletEntry},
+    storage::{
+        error::VaultError,
+        vault::{file_lock, write_atomic, SingleFileEntry, VaultAccess, VaultStorage},
+    },
+    structs::wallet::Wallet,
+};
+use protobuf::parse_from_bytes;
+use std::{convert::TryFrom, fs, path::Path};
+
+/// File with the version of the data format used by the vault
+const VERSION_FILE: &str = "vault.version";
+
+/// Current version of the data format
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// A single step of the migration, which upgrades the vault data to the specified version
+struct MigrationStep {
+    version: u32,
+    description: &'static str,
+    /// Apply the change, returns the number of updated items
+    apply: fn(&VaultStorage) -> Result<usize, VaultError>,
+}
+
+/// All migrations, ordered by version
+const MIGRATIONS: &[MigrationStep] = &[
+    MigrationStep {
+        version: 1,
+        description: "Set entry_seq of wallets to be after the last entry",
+        apply: fix_entry_seq,
+    },
+    MigrationStep {
+        version: 2,
+        description: "Store account path instead of address path for Bitcoin seed entries",
+        apply: bitcoin_account_path,
+    },
+];
+
+/// Read the data format version of the vault at the path. Returns `0` for a vault which never
+/// had a version, i.e. created before versioning
+pub fn read_version<P: AsRef<Path>>(dir: P) -> Result<u32, VaultError> {
+    let file = dir.as_ref().join(VERSION_FILE);
+    if !file.exists() {
+        return Ok(0);
+    }
+    let content = fs::read_to_string(file)?;
+    content
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| VaultError::InvalidDataError("vault_version".to_string()))
+}
+
+fn write_version<P: AsRef<Path>>(dir: P, version: u32) -> Result<(), VaultError> {
+    if read_version(dir.as_ref())? == version {
+        return Ok(());
+    }
+    write_atomic(dir.as_ref().join(VERSION_FILE), version.to_string())
+}
+
+/// Apply all migrations newer than the current version of the vault. Returns the resulting version.
+/// Fails with `VaultError::UnsupportedDataError` if the vault was written by a newer version of the library
+pub fn migrate(vault: &VaultStorage) -> Result<u32, VaultError> {
+    // hold the lock until all steps are applied, so a concurrent call waits for them and then
+    // reads the updated version instead of applying the same steps again
+    let lock = file_lock(vault.dir.join(VERSION_FILE));
+    let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
+    let current = read_version(&vault.dir)?;
+    if current > SCHEMA_VERSION {
+        return Err(VaultError::UnsupportedDataError(format!(
+            "Vault version {} is newer than supported {}",
+            current, SCHEMA_VERSION
+        )));
+    }
+    if current == SCHEMA_VERSION {
+        return Ok(current);
+    }
+    if vault.wallets().list()?.is_empty() && vault.seeds().list()?.is_empty() {
+        write_version(&vault.dir, SCHEMA_VERSION)?;
+        return Ok(SCHEMA_VERSION);
+    }
+    for step in MIGRATIONS.iter().filter(|s| s.version > current) {
+        let updated = (step.apply)(vault)?;
+        info!("Vault migrated to v{}: {}. Updated {} items", step.version, step.description, updated);
+        write_version(&vault.dir, step.version)?;
+    }
+    Ok(SCHEMA_VERSION)
+}
+
+/// Wallets created by older versions may have `entry_seq` not updated after adding an entry,
+/// which makes possible to reuse an id of a deleted entry
+fn fix_entry_seq(vault: &VaultStorage) -> Result<usize, VaultError> {
+    let wallets = vault.wallets();
+    let mut updated = 0;
+    for id in wallets.list()? {
+        let lock = wallets.get_lock_for(id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
+        let wallet = match wallets.get(id) {
+            Ok(wallet) => wallet,
+            Err(e) => {
+                warn!("Skip wallet {} which cannot be read: {:?}", id, e);
+                continue;
+            }
+        };
+        let min_seq = wallet.entries.iter().map(|e| e.id + 1).max().unwrap_or(0);
+        if wallet.entry_seq < min_seq {
+            let wallet = Wallet {
+                entry_seq: min_seq,
+                ..wallet
+            };
+            wallets.update(wallet)?;
+            updated += 1;
+        }
+    }
+    Ok(updated)
+}
+
+/// Check if the entry is a Bitcoin entry on a seed stored with the path to the first address of
+/// the account, as it was before the account path was introduced
+fn is_bitcoin_address_path(entry: &proto_WalletEntry) -> bool {
+    let is_bitcoin = Blockchain::try_from(entry.get_blockchain_id())
+        .map(|b| b.get_type() == BlockchainType::Bitcoin)
+        .unwrap_or(false);
+    is_bitcoin && entry.has_hd_path() && entry.get_hd_path().has_path()
+}
+
+/// Bitcoin entries on a seed were stored with the path to the first address of the account. It's
+/// converted to the account path when the wallet is read, and this step writes it back in the
+/// current format
+fn bitcoin_account_path(vault: &VaultStorage) -> Result<usize, VaultError> {
+    let wallets = vault.wallets();
+    let mut updated = 0;
+    for id in wallets.list()? {
+        let lock = wallets.get_lock_for(id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
+        let data = fs::read(wallets.get_filename_for(id))?;
+        let stored = match parse_from_bytes::<proto_Wallet>(data.as_slice()) {
+            Ok(stored) => stored,
+            Err(e) => {
+                warn!("Skip wallet {} which cannot be read: {:?}", id, e);
+                continue;
+            }
+        };
+        if stored.get_entries().iter().any(is_bitcoin_address_path) {
+            let wallet = match wallets.get(id) {
+                Ok(wallet) => wallet,
+                Err(e) => {
+                    warn!("Skip wallet {} which cannot be read: {:?}", id, e);
+                    continue;
+                }
+            };
+            wallets.update(wallet)?;
+            updated += 1;
+        }
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proto::seed::{HDPath as proto_HDPath, SeedHD as proto_SeedHD},
+        structs::{
+            seed::SeedRef,
+            wallet::{PKType, WalletEntry},
+        },
+    };
+    use hdpath::{AccountHDPath, StandardHDPath};
+    use protobuf::Message;
+    use std::{convert::TryInto, str::FromStr};
+    use tempdir::TempDir;
+    use uuid::Uuid;
+
+    /// Write a wallet in the format of older versions, with entry_seq not updated after entries
+    fn write_old_wallet<P: AsRef<Path>>(dir: P) -> Uuid {
+        let wallet = Wallet {
+            entries: vec![WalletEntry {
+                id: 3,
+                blockchain: Blockchain::Ethereum,
+                address: None,
+                key: PKType::PrivateKeyRef(Uuid::new_v4()),
+                ..WalletEntry::default()
+            }],
+            ..Wallet::default()
+        };
+        let id = wallet.id;
+        let data: Vec<u8> = wallet.try_into().unwrap();
+        let mut m = parse_from_bytes::<proto_Wallet>(data.as_slice()).unwrap();
+        m.set_entry_seq(0);
+        fs::write(dir.as_ref().join(format!("{}.wallet", id)), m.write_to_bytes().unwrap()).unwrap();
+        id
+    }
+
+    fn read_stored_seq<P: AsRef<Path>>(dir: P, id: Uuid) -> u32 {
+        let data = fs::read(dir.as_ref().join(format!("{}.wallet", id))).unwrap();
+        parse_from_bytes::<proto_Wallet>(data.as_slice()).unwrap().get_entry_seq()
+    }
+
+    #[test]
+    fn migrates_old_vault() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let wallet_id = write_old_wallet(tmp_dir.path());
+        assert_eq!(read_version(tmp_dir.path()).unwrap(), 0);
+        assert_eq!(read_stored_seq(tmp_dir.path(), wallet_id), 0);
+
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        assert_eq!(read_version(tmp_dir.path()).unwrap(), SCHEMA_VERSION);
+        assert_eq!(read_stored_seq(tmp_dir.path(), wallet_id), 4);
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        assert_eq!(wallet.entry_seq, 4);
+        assert_eq!(wallet.next_entry_id(), 4);
+    }
+
+    #[test]
+    fn doesnt_repeat_applied_steps() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let wallet_id = write_old_wallet(tmp_dir.path());
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        assert_eq!(read_stored_seq(tmp_dir.path(), wallet_id), 4);
+
+        // steps up to the stored version are never applied again
+        let wallet_file = tmp_dir.path().join(format!("{}.wallet", wallet_id));
+        let mut m = parse_from_bytes::<proto_Wallet>(fs::read(&wallet_file).unwrap().as_slice()).unwrap();
+        m.set_entry_seq(0);
+        fs::write(&wallet_file, m.write_to_bytes().unwrap()).unwrap();
+
+        assert_eq!(migrate(&vault), Ok(SCHEMA_VERSION));
+        assert_eq!(read_stored_seq(tmp_dir.path(), wallet_id), 0);
+    }
+
+    /// Write a wallet with a Bitcoin entry in the format of older versions, with the address path
+    /// instead of the account path
+    fn write_old_bitcoin_wallet<P: AsRef<Path>>(dir: P, seed_id: Uuid) -> Uuid {
+        let mut hd_path = proto_HDPath::new();
+        hd_path.set_purpose(84);
+        hd_path.set_coin(0);
+        hd_path.set_account(1);
+        hd_path.set_change(0);
+        hd_path.set_index(0);
+        let mut seed_hd = proto_SeedHD::new();
+        seed_hd.set_seed_id(seed_id.as_bytes().to_vec());
+        seed_hd.set_path(hd_path);
+        let mut entry = proto_WalletEntry::new();
+        entry.set_id(0);
+        entry.set_blockchain_id(Blockchain::Bitcoin as u32);
+        entry.set_hd_path(seed_hd);
+
+        let wallet = Wallet::default();
+        let id = wallet.id;
+        let data: Vec<u8> = wallet.try_into().unwrap();
+        let mut m = parse_from_bytes::<proto_Wallet>(data.as_slice()).unwrap();
+        m.mut_entries().push(entry);
+        m.set_entry_seq(1);
+        fs::write(dir.as_ref().join(format!("{}.wallet", id)), m.write_to_bytes().unwrap()).unwrap();
+        id
+    }
+
+    fn read_stored_entry<P: AsRef<Path>>(dir: P, id: Uuid) -> proto_WalletEntry {
+        let data = fs::read(dir.as_ref().join(format!("{}.wallet", id))).unwrap();
+        parse_from_bytes::<proto_Wallet>(data.as_slice()).unwrap().get_entries()[0].clone()
+    }
+
+    #[test]
+    fn migrates_bitcoin_entries_to_account_path() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let seed_id = Uuid::new_v4();
+        let wallet_id = write_old_bitcoin_wallet(tmp_dir.path(), seed_id);
+        write_version(tmp_dir.path(), 1).unwrap();
+        assert!(read_stored_entry(tmp_dir.path(), wallet_id).get_hd_path().has_path());
+
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        assert_eq!(read_version(tmp_dir.path()).unwrap(), 2);
+        let stored = read_stored_entry(tmp_dir.path(), wallet_id);
+        assert!(stored.get_hd_path().has_account());
+        assert!(!stored.get_hd_path().has_path());
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        assert_eq!(
+            PKType::SeedHd(SeedRef {
+                seed_id,
+                hd_path: AccountHDPath::from_str("m/84'/0'/1'").unwrap().into(),
+            }),
+            wallet.entries[0].key
+        );
+    }
+
+    #[test]
+    fn keeps_ethereum_entries_with_address_path() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let wallet = Wallet {
+            entries: vec![WalletEntry {
+                id: 0,
+                blockchain: Blockchain::Ethereum,
+                address: None,
+                key: PKType::SeedHd(SeedRef {
+                    seed_id: Uuid::new_v4(),
+                    hd_path: StandardHDPath::try_from("m/44'/60'/0'/0/1").unwrap().into(),
+                }),
+                ..WalletEntry::default()
+            }],
+            entry_seq: 1,
+            ..Wallet::default()
+        };
+        let wallet_id = wallet.id;
+        let data: Vec<u8> = wallet.try_into().unwrap();
+        fs::write(tmp_dir.path().join(format!("{}.wallet", wallet_id)), &data).unwrap();
+        write_version(tmp_dir.path(), 1).unwrap();
+
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        assert_eq!(read_version(tmp_dir.path()).unwrap(), 2);
+        assert_eq!(Ok(0), bitcoin_account_path(&vault));
+        assert_eq!(data, fs::read(tmp_dir.path().join(format!("{}.wallet", wallet_id))).unwrap());
+    }
+
+    #[test]
+    fn migrates_once_when_opened_concurrently() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let wallet_id = write_old_wallet(tmp_dir.path());
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let path = tmp_dir.path().to_path_buf();
+                std::thread::spawn(move || VaultStorage::create(path).map(|_| ()))
+            })
+            .collect();
+        for t in threads {
+            assert_eq!(Ok(()), t.join().expect("Thread failed"));
+        }
+
+        assert_eq!(read_version(tmp_dir.path()).unwrap(), SCHEMA_VERSION);
+        assert_eq!(read_stored_seq(tmp_dir.path(), wallet_id), 4);
+        let files = fs::read_dir(tmp_dir.path()).unwrap().count();
+        // the wallet and the version, without leftover temp files
+        assert_eq!(2, files);
+    }
+
+    #[test]
+    fn skips_corrupted_wallet() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let wallet_id = write_old_wallet(tmp_dir.path());
+        let corrupted = tmp_dir.path().join(format!("{}.wallet", Uuid::new_v4()));
+        fs::write(&corrupted, "not a wallet").unwrap();
+
+        let vault = VaultStorage::create(tmp_dir.path());
+        assert!(vault.is_ok());
+
+        assert_eq!(read_version(tmp_dir.path()).unwrap(), SCHEMA_VERSION);
+        assert_eq!(read_stored_seq(tmp_dir.path(), wallet_id), 4);
+        assert_eq!(fs::read_to_string(&corrupted).unwrap(), "not a wallet");
+    }
+
+    #[test]
+    fn marks_empty_vault_with_current_version() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        assert_eq!(read_version(tmp_dir.path()).unwrap(), SCHEMA_VERSION);
+        assert_eq!(migrate(&vault), Ok(SCHEMA_VERSION));
+        // only the version file
+        assert_eq!(1, fs::read_dir(tmp_dir.path()).unwrap().count());
+
+        // a wallet added later is already in the current format and must not be migrated
+        let wallet_id = vault.wallets().add(Wallet::default()).unwrap();
+        let wallet_file = tmp_dir.path().join(format!("{}.wallet", wallet_id));
+        let data = fs::read(&wallet_file).unwrap();
+        assert_eq!(migrate(&vault), Ok(SCHEMA_VERSION));
+        assert_eq!(data, fs::read(&wallet_file).unwrap());
+    }
+
+    #[test]
+    fn refuses_newer_vault() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        write_version(tmp_dir.path(), SCHEMA_VERSION + 1).unwrap();
+        let vault = VaultStorage::create(tmp_dir.path());
+        assert!(vault.is_err());
+        match vault.err().unwrap() {
+            VaultError::UnsupportedDataError(_) => {}
+            e => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn fails_on_invalid_version() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        fs::write(tmp_dir.path().join(VERSION_FILE), "latest").unwrap();
+        assert_eq!(
+            read_version(tmp_dir.path()),
+            Err(VaultError::InvalidDataError("vault_version".to_string()))
+        );
+    }
+}
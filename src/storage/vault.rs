@@ -1,30 +1,36 @@
 use crate::{
-    chains::Blockchain,
+    chains::{Blockchain, BlockchainType},
     convert::{error::ConversionError, json::keyfile::EthereumJsonV3File},
+    crypto::password::PasswordPolicy,
     sign::bip32::generate_key,
     storage::{
         addressbook::AddressbookStorage,
         archive::{Archive, ArchiveType},
         error::VaultError,
+        migrations,
     },
     structs::{
         book::AddressRef,
         pk::PrivateKeyHolder,
-        seed::{Seed, SeedRef, SeedSource},
+        seed::{LedgerSource, Seed, SeedRef, SeedSource},
         types::HasUuid,
-        wallet::{PKType, Wallet, WalletEntry},
+        wallet::{PKType, Wallet, WalletEntry, WalletSummary},
     },
     EthereumAddress,
     EthereumPrivateKey,
 };
+use emerald_hwkey::ledger::manager::LedgerKey;
 use hdpath::StandardHDPath;
 use regex::Regex;
 use std::{
     convert::{TryFrom, TryInto},
     fs,
+    fs::File,
+    io::Write,
     path::{Path, PathBuf},
     str::FromStr,
-    sync::Arc,
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
     time::SystemTime,
 };
 use uuid::Uuid;
@@ -43,15 +49,37 @@ pub struct VaultStorage {
     keys: Arc<dyn VaultAccessByFile<PrivateKeyHolder>>,
     wallets: Arc<dyn VaultAccessByFile<Wallet>>,
     seeds: Arc<dyn VaultAccessByFile<Seed>>,
+    read_only: bool,
 }
 
 struct StandardVaultFiles {
     dir: PathBuf,
     suffix: String,
+    /// If true, any change of the files is rejected with `VaultError::ReadOnly`
+    read_only: bool,
+    /// Policy for the passwords used to encrypt data stored in the files, if enforced
+    password_policy: Option<PasswordPolicy>,
 }
 
 /// Main interface to the Emerald Vault storage
 impl VaultStorage {
+    /// Check if the vault was opened with `open_readonly`
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Enforce the password policy for all passwords used to encrypt seeds, private keys and the
+    /// master key of the vault. An operation with a password that doesn't satisfy it fails with
+    /// `VaultError::WeakPassword`
+    pub fn with_password_policy(self, policy: PasswordPolicy) -> VaultStorage {
+        VaultStorage::with_mode(self.dir, self.read_only, Some(policy))
+    }
+
+    /// Password policy enforced by the vault, if any
+    pub fn get_password_policy(&self) -> Option<&PasswordPolicy> {
+        self.keys.get_password_policy()
+    }
+
     pub fn keys(&self) -> Arc<dyn VaultAccessByFile<PrivateKeyHolder>> {
         self.keys.clone()
     }
@@ -69,7 +97,7 @@ impl VaultStorage {
         }
     }
     pub fn addressbook(&self) -> AddressbookStorage {
-        AddressbookStorage::from_path(self.dir.clone().join("addressbook.csv"))
+        AddressbookStorage::with_mode(self.dir.clone().join("addressbook.csv"), self.read_only)
     }
 
     pub fn add_ethereum_entry(&self, wallet_id: Uuid) -> AddEthereumEntry {
@@ -89,14 +117,32 @@ impl VaultStorage {
         }
     }
 
-    ///Remove entry from a wallet.
+    ///Remove entry from a wallet. Ids of other entries are kept as is. If it was the last entry
+    ///using a seed account, the reservation of that account is removed from the wallet too.
     ///Returns Ok(true) if entry was found and removed, Ok(false) if entry wasn't found, and Err if an error happened
     pub fn remove_entry(&self, wallet_id: Uuid, entry_id: usize) -> Result<bool, VaultError> {
+        let lock = self.wallets.get_lock_for(wallet_id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
         let mut wallet = self.wallets().get(wallet_id)?;
         let pos = wallet.entries.iter().position(|e| e.id == entry_id);
         match pos {
             Some(pos) => {
                 let entry = wallet.entries.remove(pos);
+                if let PKType::SeedHd(seed) = &entry.key {
+                    if let Ok(account_id) = seed.get_account_id() {
+                        let used = wallet.entries.iter().any(|e| match &e.key {
+                            PKType::SeedHd(x) => {
+                                x.seed_id == seed.seed_id && x.get_account_id() == Ok(account_id)
+                            }
+                            _ => false,
+                        });
+                        if !used {
+                            wallet.reserved.retain(|r| {
+                                r.seed_id != seed.seed_id || r.account_id != account_id
+                            });
+                        }
+                    }
+                }
                 let updated = self.wallets.update(wallet)?;
                 match entry.key {
                     PKType::PrivateKeyRef(uuid) => {
@@ -116,7 +162,7 @@ impl VaultStorage {
                             self.keys.remove(uuid)?;
                         }
                     }
-                    PKType::SeedHd(_) => {}
+                    PKType::SeedHd(_) | PKType::Watch => {}
                 }
                 Ok(updated)
             }
@@ -124,10 +170,56 @@ impl VaultStorage {
         }
     }
 
+    /// Get Ethereum address of the entry. For a seed based entry the address is derived from the
+    /// seed, or requested from the connected Ledger, at the HD Path of the entry. For a private key
+    /// it returns the address stored with the entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet_id` - id of the wallet
+    /// * `entry_id` - id of the entry in the wallet
+    /// * `seed_password` - password to decrypt the seed, not used for a Ledger
+    pub fn get_address(
+        &self,
+        wallet_id: Uuid,
+        entry_id: usize,
+        seed_password: Option<String>,
+    ) -> Result<EthereumAddress, VaultError> {
+        let wallet = self.wallets.get(wallet_id)?;
+        let entry = wallet.get_entry(entry_id)?;
+        if entry.blockchain.get_type() != BlockchainType::Ethereum {
+            return Err(VaultError::IncorrectBlockchainError);
+        }
+        match &entry.key {
+            PKType::SeedHd(seed_ref) => {
+                let seed = self.seeds.get(seed_ref.seed_id)?;
+                let hd_path = seed_ref.hd_path.address_at(0, 0)
+                    .map_err(|_| VaultError::InvalidDataError("hd_path".to_string()))?;
+                let addresses = seed.source.get_addresses::<EthereumAddress>(
+                    seed_password,
+                    &vec![hd_path],
+                    entry.blockchain,
+                )?;
+                addresses
+                    .into_iter()
+                    .next()
+                    .map(|(_, address)| address)
+                    .ok_or(VaultError::PublicKeyUnavailable)
+            }
+            PKType::PrivateKeyRef(_) | PKType::Watch => match entry.address {
+                Some(AddressRef::EthereumAddress(address)) => Ok(address),
+                _ => Err(VaultError::PublicKeyUnavailable),
+            },
+        }
+    }
+
     /// Check the Vault directory and revert stale backups. I.e., restore from a situation when
     /// file was moved to backup, but update has failed because of some reasons, as a result there is no usable file,
     /// only backup. If both original file and backup exists, then backup file is going to be moved to archive
     pub fn revert_backups(&self) -> Result<usize, VaultError> {
+        if self.read_only {
+            return Err(VaultError::ReadOnly);
+        }
         lazy_static! {
             static ref BACKUP_RE: Regex = Regex::new(r"(?P<id>[0-9a-f]{8}\-[0-9a-f]{4}\-[0-9a-f]{4}\-[0-9a-f]{4}\-[0-9a-f]{12})\.(?P<suffix>[a-z]+).bak").unwrap();
         }
@@ -204,7 +296,7 @@ impl VaultStorage {
                 .iter()
                 .filter_map(|acc| match acc.key {
                     PKType::PrivateKeyRef(pk_id) => Some(pk_id),
-                    PKType::SeedHd(_) => None,
+                    PKType::SeedHd(_) | PKType::Watch => None,
                 })
                 .collect::<Vec<Uuid>>()
         };
@@ -245,6 +337,9 @@ impl VaultStorage {
     /// Removes a wallet with all related private keys exclusively used by that wallet. Seeds are
     /// kept untouched.
     pub fn remove_wallet(&self, id: Uuid) -> Result<bool, VaultError> {
+        if self.read_only {
+            return Err(VaultError::ReadOnly);
+        }
         let all = self.get_wallet_files(id, true)?;
         if all.is_empty() {
             return Ok(false);
@@ -265,6 +360,86 @@ impl VaultStorage {
         archive.finalize();
         Ok(errors == 0)
     }
+
+    /// Set the order of wallets as it's shown to the user. Wallets get positions in the same order
+    /// as the specified ids, and other wallets of the vault go after them, ordered by creation date.
+    /// New wallets are added without a position, i.e. to the end of the list
+    pub fn reorder_wallets(&self, ids: &[Uuid]) -> Result<(), VaultError> {
+        if self.read_only {
+            return Err(VaultError::ReadOnly);
+        }
+        let unique: HashSet<&Uuid> = ids.iter().collect();
+        if unique.len() != ids.len() {
+            return Err(VaultError::InvalidDataError("Duplicate wallet id".to_string()));
+        }
+        let mut all = self.wallets.list()?;
+        if ids.iter().any(|id| !all.contains(id)) {
+            return Err(VaultError::IncorrectIdError);
+        }
+        // lock all wallets, always in the same order, and read them before changing any, so the
+        // order is either updated for all wallets or not changed at all if some wallet is unreadable
+        all.sort();
+        let locks: Vec<Arc<Mutex<()>>> = all.iter().map(|id| self.wallets.get_lock_for(*id)).collect();
+        let _guards: Vec<_> = locks
+            .iter()
+            .map(|lock| lock.lock().unwrap_or_else(|e| e.into_inner()))
+            .collect();
+        let wallets = all
+            .iter()
+            .map(|id| self.wallets.get(*id))
+            .collect::<Result<Vec<Wallet>, VaultError>>()?;
+        for mut wallet in wallets {
+            let order = ids
+                .iter()
+                .position(|x| *x == wallet.id)
+                .map(|pos| pos as u32 + 1)
+                .unwrap_or(0);
+            if wallet.order != order {
+                wallet.order = order;
+                self.wallets.update(wallet)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write content to a temporary file in the same dir, and then atomically replace the target with
+/// it. Makes sure that the target is never left partially written. The temporary file has a unique
+/// name, so concurrent writers of the same target don't interfere.
+pub(crate) fn write_atomic<P: AsRef<Path>, C: AsRef<[u8]>>(file: P, content: C) -> Result<(), VaultError> {
+    let file = file.as_ref();
+    let file_name = file
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or(VaultError::FilesystemError("Invalid file name".to_string()))?;
+    let tmp_file = file.with_file_name(format!("{}.{}.tmp", file_name, Uuid::new_v4().to_simple()));
+    let written = File::create(&tmp_file)
+        .and_then(|mut f| {
+            f.write_all(content.as_ref())?;
+            f.sync_all()
+        })
+        .and_then(|_| fs::rename(&tmp_file, file));
+    if let Err(e) = written {
+        if tmp_file.exists() && fs::remove_file(&tmp_file).is_err() {
+            error!("Failed to remove temp file {:?}", tmp_file)
+        }
+        return Err(VaultError::from(e));
+    }
+    // the rename itself is durable only after the directory is synced
+    #[cfg(unix)]
+    sync_parent_dir(file)?;
+    Ok(())
+}
+
+/// Flush changes of the directory containing the file (i.e. created or renamed entries) to the disk
+#[cfg(unix)]
+fn sync_parent_dir(file: &Path) -> Result<(), VaultError> {
+    let dir = match file.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir)?.sync_all()?;
+    Ok(())
 }
 
 /// Safe update of a file, with making a .bak copy of the existing file, writing new content and
@@ -304,7 +479,7 @@ fn safe_update<P: AsRef<Path>, C: AsRef<[u8]>>(
             "Failed to create backup".to_string(),
         ));
     }
-    if fs::write(file, new_content).is_err() {
+    if write_atomic(file, new_content).is_err() {
         // FAILURE! Revert back!
         if fs::remove_file(file).is_err() {
             println!("Failed to remove {:?}", file)
@@ -364,6 +539,15 @@ fn try_vault_file(file: &Path, suffix: &str) -> Result<Uuid, ()> {
     }
 }
 
+/// Result of importing keystore files from a directory
+#[derive(Clone, Debug, Default)]
+pub struct ImportDirSummary {
+    /// Imported files, with the id of the created wallet and the address of the key
+    pub imported: Vec<(PathBuf, Uuid, Option<EthereumAddress>)>,
+    /// Files failed to import, with the reason
+    pub failed: Vec<(PathBuf, VaultError)>,
+}
+
 pub struct CreateWallet {
     keys: Arc<dyn VaultAccessByFile<PrivateKeyHolder>>,
     wallets: Arc<dyn VaultAccessByFile<Wallet>>,
@@ -400,6 +584,39 @@ impl CreateWallet {
         Ok(result)
     }
 
+    ///Create a new Wallet for each of Ethereum JSON Private Key files in the directory (ex. a `keystore/`
+    ///dir of Geth). A file that cannot be imported doesn't stop the import, but it's reported in
+    ///the `failed` list of the result.
+    pub fn ethereum_dir<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        blockchain: Blockchain,
+    ) -> Result<ImportDirSummary, VaultError> {
+        let mut files: Vec<PathBuf> = fs::read_dir(dir.as_ref())?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_file())
+            .collect();
+        files.sort();
+        let mut result = ImportDirSummary::default();
+        for f in files {
+            let imported = fs::read_to_string(&f)
+                .map_err(|e| VaultError::from(e))
+                .and_then(|content| {
+                    EthereumJsonV3File::try_from(content)
+                        .map_err(|_| VaultError::InvalidDataError("Not a JSON keyfile".to_string()))
+                })
+                .and_then(|json| {
+                    self.ethereum(&json, blockchain).map(|id| (id, json.address))
+                });
+            match imported {
+                Ok((id, address)) => result.imported.push((f, id, address)),
+                Err(e) => result.failed.push((f, e)),
+            }
+        }
+        Ok(result)
+    }
+
     ///Create a new Wallet with the the specified Private Key. All fields for the wallet are set
     ///with default or empty values.
     ///
@@ -410,6 +627,7 @@ impl CreateWallet {
         password: &str,
         blockchain: Blockchain,
     ) -> Result<Uuid, VaultError> {
+        self.keys.check_password(password)?;
         let pk = PrivateKeyHolder::create_ethereum_raw(pk, password)
             .map_err(|_| VaultError::InvalidDataError("Invalid PrivateKey".to_string()))?;
         let wallet = Wallet {
@@ -446,6 +664,8 @@ impl UpdateEntry {
     where
         F: FnMut(&mut WalletEntry) -> (),
     {
+        let lock = self.wallets.get_lock_for(self.wallet_id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
         let mut wallet = self.get_wallet()?;
         let pos = wallet.entries.iter().position(|e| e.id == self.entry_id);
         match pos {
@@ -469,6 +689,11 @@ impl UpdateEntry {
     pub fn set_receive_disabled(&self, disabled: bool) -> Result<bool, VaultError> {
         self.update(|e| e.receive_disabled = disabled)
     }
+
+    ///Update (set value or set none) of a label for an address of the entry, by the address index
+    pub fn set_address_label(&self, index: u32, label: Option<String>) -> Result<bool, VaultError> {
+        self.update(|e| e.set_address_label(index, label.clone()))
+    }
 }
 
 impl VaultStorage {
@@ -482,28 +707,83 @@ impl VaultStorage {
                 "Target path is not a dir".to_string(),
             ));
         }
-        Ok(VaultStorage {
+        let vault = VaultStorage::with_mode(path, false, None);
+        migrations::migrate(&vault)?;
+        Ok(vault)
+    }
+
+    /// Open an existing vault without a possibility to change it. Any attempt to add, update or
+    /// remove keys, seeds or wallets returns `VaultError::ReadOnly`. Doesn't apply migrations,
+    /// so the data must be already in the current format
+    pub fn open_readonly<P: AsRef<Path>>(path: P) -> Result<VaultStorage, VaultError> {
+        let path = path.as_ref().to_path_buf();
+        if !path.is_dir() {
+            return Err(VaultError::FilesystemError(
+                "Target path is not a dir".to_string(),
+            ));
+        }
+        Ok(VaultStorage::with_mode(path, true, None))
+    }
+
+    fn with_mode(path: PathBuf, read_only: bool, password_policy: Option<PasswordPolicy>) -> VaultStorage {
+        VaultStorage {
             dir: path.clone(),
+            read_only,
             keys: Arc::new(StandardVaultFiles {
                 dir: path.clone(),
                 suffix: "key".to_string(),
+                read_only,
+                password_policy: password_policy.clone(),
             }),
             wallets: Arc::new(StandardVaultFiles {
                 dir: path.clone(),
                 suffix: "wallet".to_string(),
+                read_only,
+                password_policy: password_policy.clone(),
             }),
             seeds: Arc::new(StandardVaultFiles {
                 dir: path.clone(),
                 suffix: "seed".to_string(),
+                read_only,
+                password_policy: password_policy.clone(),
             }),
-        })
+        }
     }
 }
 
+/// In-process lock for a file, shared by all users of the same path
+pub(crate) fn file_lock(file: PathBuf) -> Arc<Mutex<()>> {
+    lazy_static! {
+        static ref LOCKS: Mutex<HashMap<PathBuf, Arc<Mutex<()>>>> = Mutex::new(HashMap::new());
+    }
+    let mut locks = LOCKS.lock().unwrap_or_else(|e| e.into_inner());
+    locks.entry(file).or_insert_with(|| Arc::new(Mutex::new(()))).clone()
+}
+
 /// For entries that are stored in a single separate file
 pub trait SingleFileEntry {
     /// Get full filename for the entry by id
     fn get_filename_for(&self, id: Uuid) -> PathBuf;
+
+    /// Get lock for the file of the entry. Must be held for the whole read-modify-write cycle
+    /// (i.e. `get` and then `update`), otherwise a concurrent update may be lost
+    fn get_lock_for(&self, id: Uuid) -> Arc<Mutex<()>> {
+        file_lock(self.get_filename_for(id))
+    }
+
+    /// Policy for the passwords used to encrypt data stored in the entries, if enforced
+    fn get_password_policy(&self) -> Option<&PasswordPolicy> {
+        None
+    }
+
+    /// Check that the password satisfies the policy, if any. Must be called before encrypting
+    /// data to store in the entries
+    fn check_password(&self, password: &str) -> Result<(), VaultError> {
+        match self.get_password_policy() {
+            Some(policy) => policy.check(password).map_err(VaultError::from),
+            None => Ok(()),
+        }
+    }
 }
 
 impl SingleFileEntry for StandardVaultFiles {
@@ -512,6 +792,10 @@ impl SingleFileEntry for StandardVaultFiles {
         let fname = format!("{}.{}", id, self.suffix);
         return self.dir.join(fname);
     }
+
+    fn get_password_policy(&self) -> Option<&PasswordPolicy> {
+        self.password_policy.as_ref()
+    }
 }
 
 impl<P> VaultAccessByFile<P> for StandardVaultFiles
@@ -521,6 +805,89 @@ impl<P> VaultAccessByFile<P> for StandardVaultFiles
 {
 }
 
+impl dyn VaultAccessByFile<Seed> {
+    /// Update (set value or set none) of a label for the seed. The encrypted source is kept as is
+    pub fn update_label(&self, seed_id: Uuid, label: Option<String>) -> Result<(), VaultError> {
+        let lock = self.get_lock_for(seed_id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut seed = self.get(seed_id)?;
+        seed.label = label;
+        self.update(seed)?;
+        Ok(())
+    }
+
+    /// Find a seed with the same seed bytes. Only seeds that have a fingerprint can be found,
+    /// i.e. it cannot find a Ledger seed or a seed created by an older version
+    pub fn find_by_bytes(&self, seed: &[u8]) -> Result<Option<Uuid>, VaultError> {
+        let found = self
+            .list_entries()?
+            .into_iter()
+            .find(|s| match &s.fingerprint {
+                Some(f) => f.matches(seed),
+                None => false,
+            });
+        Ok(found.map(|s| s.id))
+    }
+
+    /// Import seed bytes encrypted with the password. If the same seed is already in the vault it
+    /// returns id of the existing seed instead of adding a copy
+    pub fn import_bytes(&self, seed: Vec<u8>, password: &str) -> Result<Uuid, VaultError> {
+        if let Some(existing) = self.find_by_bytes(seed.as_slice())? {
+            return Ok(existing);
+        }
+        self.check_password(password)?;
+        self.add(Seed::from_bytes(seed, password)?)
+    }
+
+    /// Add a seed for the currently connected Ledger, fingerprinted with the app opened on the
+    /// device. If a seed of the same device (i.e. with the same master fingerprint) is already in
+    /// the vault it returns id of the existing seed instead of adding a copy
+    pub fn import_ledger(&self) -> Result<Uuid, VaultError> {
+        let source = LedgerSource::create_connected()?;
+        if let Some(master_fingerprint) = source.master_fingerprint {
+            let found = self.list_entries()?.into_iter().find(|s| match &s.source {
+                SeedSource::Ledger(l) => l.master_fingerprint == Some(master_fingerprint),
+                _ => false,
+            });
+            if let Some(existing) = found {
+                return Ok(existing.id);
+            }
+        }
+        self.add(Seed {
+            source: SeedSource::Ledger(source),
+            ..Seed::default()
+        })
+    }
+
+    /// Verify that the connected Ledger is the device of the seed. If the seed cannot be verified
+    /// with the opened app, the fingerprints read from the device are remembered in the seed
+    /// to verify it next time. Does nothing for a non-Ledger seed
+    pub fn verify_ledger(&self, seed_id: Uuid, manager: &LedgerKey) -> Result<(), VaultError> {
+        let lock = self.get_lock_for(seed_id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut seed = self.get(seed_id)?;
+        let updated = match &seed.source {
+            SeedSource::Ledger(ledger) => ledger.verify(manager)?,
+            _ => None,
+        };
+        if let Some(ledger) = updated {
+            seed.source = SeedSource::Ledger(ledger);
+            if let Err(e) = self.update(seed) {
+                warn!("Failed to store Ledger fingerprints for seed {}: {:?}", seed_id, e);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl dyn VaultAccessByFile<Wallet> {
+    /// List all wallets with their entries summarized. Doesn't read or decrypt any keys
+    pub fn list_summaries(&self) -> Result<Vec<WalletSummary>, VaultError> {
+        let all = self.list_entries()?;
+        Ok(all.iter().map(|w| w.summary()).collect())
+    }
+}
+
 /// Access to Vault storage
 pub trait VaultAccess<P>
     where
@@ -557,6 +924,9 @@ impl<P> VaultAccess<P> for StandardVaultFiles
         Vec<u8>: std::convert::TryFrom<P>,
 {
     fn update(&self, entry: P) -> Result<bool, VaultError> {
+        if self.read_only {
+            return Err(VaultError::ReadOnly);
+        }
         let id = entry.get_id();
         let fname = self.get_filename_for(id.clone());
         if fname.exists() {
@@ -605,6 +975,9 @@ impl<P> VaultAccess<P> for StandardVaultFiles
     }
 
     fn add(&self, entry: P) -> Result<Uuid, VaultError> {
+        if self.read_only {
+            return Err(VaultError::ReadOnly);
+        }
         let id = entry.get_id();
         let f = self.get_filename_for(id.clone());
         if f.exists() {
@@ -614,12 +987,14 @@ impl<P> VaultAccess<P> for StandardVaultFiles
         let data: Vec<u8> = entry
             .try_into()
             .map_err(|_| ConversionError::InvalidProtobuf)?;
-        //        let data: Vec<u8> = Vec::try_from(pk)?;
-        fs::write(f, data.as_slice())?;
+        write_atomic(f, data.as_slice())?;
         Ok(id)
     }
 
     fn remove(&self, id: Uuid) -> Result<bool, VaultError> {
+        if self.read_only {
+            return Err(VaultError::ReadOnly);
+        }
         let f = self.get_filename_for(id.clone());
         if !f.exists() {
             return Ok(false);
@@ -644,12 +1019,39 @@ mod tests {
     use super::*;
     use crate::{
         convert::json::keyfile::EthereumJsonV3File,
-        structs::pk::{EthereumPk3, PrivateKeyHolder},
+        mnemonic::{Language, Mnemonic},
+        structs::{
+            pk::{EthereumPk3, PrivateKeyHolder},
+            wallet::ReservedPath,
+        },
         tests::{read_dir_fully, *},
     };
     use chrono::{TimeZone, Utc};
     use tempdir::TempDir;
 
+    #[test]
+    fn creates_nested_dir() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let path = tmp_dir.path().join("first").join("second");
+        assert!(!path.exists());
+
+        let vault = VaultStorage::create(&path).expect("Vault not created");
+        assert!(path.is_dir());
+
+        let seed_id = vault.seeds().add(Seed::default()).unwrap();
+        assert_eq!(vault.seeds().list().unwrap(), vec![seed_id]);
+    }
+
+    #[test]
+    fn cannot_create_on_file() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let path = tmp_dir.path().join("vault");
+        fs::write(&path, "test").unwrap();
+
+        let vault = VaultStorage::create(&path);
+        assert!(vault.is_err());
+    }
+
     #[test]
     fn try_vault_file_from_standard() {
         let act = try_vault_file(Path::new("3221aabc-b3ff-4235-829f-9599aba04cb5.key"), "key");
@@ -715,6 +1117,58 @@ mod tests {
         assert_eq!(seed, seed_act);
     }
 
+    #[test]
+    fn reads_in_readonly_mode() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let mut seed = Seed::generate(None, "testtest").unwrap();
+        seed.created_at = Utc.timestamp_millis(0);
+        let seed_id = vault.seeds.add(seed.clone()).unwrap();
+        let wallet_id = vault.wallets.add(Wallet::default()).unwrap();
+
+        let vault = VaultStorage::open_readonly(tmp_dir.path()).unwrap();
+        assert!(vault.is_read_only());
+        assert_eq!(vec![seed_id], vault.seeds.list().unwrap());
+        assert_eq!(seed, vault.seeds.get(seed_id).unwrap());
+        assert_eq!(vec![wallet_id], vault.wallets.list().unwrap());
+        assert!(vault.wallets.get(wallet_id).is_ok());
+    }
+
+    #[test]
+    fn rejects_changes_in_readonly_mode() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = vault.seeds.add(Seed::generate(None, "testtest").unwrap()).unwrap();
+        let wallet_id = vault.wallets.add(Wallet::default()).unwrap();
+
+        let vault = VaultStorage::open_readonly(tmp_dir.path()).unwrap();
+        assert_eq!(
+            vault.seeds.add(Seed::generate(None, "testtest").unwrap()),
+            Err(VaultError::ReadOnly)
+        );
+        assert_eq!(vault.seeds.remove(seed_id), Err(VaultError::ReadOnly));
+        let wallet = Wallet {
+            label: Some("test".to_string()),
+            ..vault.wallets.get(wallet_id).unwrap()
+        };
+        assert_eq!(vault.wallets.update(wallet), Err(VaultError::ReadOnly));
+        assert_eq!(vault.wallets.add(Wallet::default()), Err(VaultError::ReadOnly));
+        assert_eq!(vault.remove_wallet(wallet_id), Err(VaultError::ReadOnly));
+        assert_eq!(vault.addressbook().remove(Uuid::new_v4()), Err(VaultError::ReadOnly));
+
+        assert_eq!(vault.seeds.list().unwrap().len(), 1);
+        let wallet = vault.wallets.get(wallet_id).unwrap();
+        assert_eq!(wallet.label, None);
+    }
+
+    #[test]
+    fn cannot_open_readonly_missing_dir() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let path = tmp_dir.path().join("not-exists");
+        assert!(VaultStorage::open_readonly(&path).is_err());
+        assert!(!path.exists());
+    }
+
     #[test]
     fn deletes_seed() {
         let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
@@ -738,6 +1192,144 @@ mod tests {
         assert_eq!(0, all.len());
     }
 
+    #[test]
+    fn import_same_seed_once() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "quote ivory blast onion below kangaroo tonight spread awkward decide farm gun exact wood brown",
+        ).unwrap();
+        let other = Mnemonic::try_from(
+            Language::English,
+            "avoid midnight couch purchase truth segment sauce claim spell spring smoke renew term stem solve",
+        ).unwrap();
+
+        let id_1 = vault.seeds().import_bytes(phrase.seed(None), "test").unwrap();
+        let id_2 = vault.seeds().import_bytes(phrase.seed(None), "test-other").unwrap();
+        assert_eq!(id_1, id_2);
+        assert_eq!(1, vault.seeds().list().unwrap().len());
+
+        let id_3 = vault.seeds().import_bytes(other.seed(None), "test").unwrap();
+        assert_ne!(id_1, id_3);
+        assert_eq!(2, vault.seeds().list().unwrap().len());
+
+        assert_eq!(Some(id_1), vault.seeds().find_by_bytes(phrase.seed(None).as_slice()).unwrap());
+        assert_eq!(None, vault.seeds().find_by_bytes(phrase.seed(Some("pwd".to_string())).as_slice()).unwrap());
+    }
+
+    #[test]
+    fn enforces_password_policy() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap()
+            .with_password_policy(PasswordPolicy::default());
+        assert_eq!(Some(&PasswordPolicy::default()), vault.get_password_policy());
+
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "quote ivory blast onion below kangaroo tonight spread awkward decide farm gun exact wood brown",
+        ).unwrap();
+        let pk = hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd").unwrap();
+
+        assert_eq!(
+            Err(VaultError::WeakPassword),
+            vault.seeds().import_bytes(phrase.seed(None), "test")
+        );
+        assert_eq!(
+            Err(VaultError::WeakPassword),
+            vault.create_new().raw_pk(pk.clone(), "test", Blockchain::Ethereum)
+        );
+        assert_eq!(0, vault.seeds().list().unwrap().len());
+        assert_eq!(0, vault.keys().list().unwrap().len());
+        assert_eq!(0, vault.wallets().list().unwrap().len());
+
+        let wallet_id = vault.create_new()
+            .raw_pk(pk.clone(), "Correct-Horse-Battery-9", Blockchain::Ethereum)
+            .unwrap();
+        assert_eq!(
+            Err(VaultError::WeakPassword),
+            vault.add_ethereum_entry(wallet_id).raw_pk(pk.clone(), "test", Blockchain::EthereumClassic)
+        );
+        assert_eq!(1, vault.keys().list().unwrap().len());
+        assert_eq!(1, vault.wallets().get(wallet_id).unwrap().entries.len());
+
+        assert!(vault.seeds().import_bytes(phrase.seed(None), "Correct-Horse-Battery-9").is_ok());
+        assert_eq!(1, vault.seeds().list().unwrap().len());
+    }
+
+    #[test]
+    fn import_keyfiles_from_dir() {
+        let json = r#"
+            {
+                "version": 3,
+                "id": "305f4853-80af-4fa6-8619-6f285e83cf28",
+                "address": "6412c428fc02902d137b60dc0bd0f6cd1255ea99",
+                "name": "Hello",
+                "description": "World!!!!",
+                "visible": true,
+                "crypto": {
+                    "cipher": "aes-128-ctr",
+                    "cipherparams": {"iv": "e4610fb26bd43fa17d1f5df7a415f084"},
+                    "ciphertext": "dc50ab7bf07c2a793206683397fb15e5da0295cf89396169273c3f49093e8863",
+                    "kdf": "scrypt",
+                    "kdfparams": {
+                        "dklen": 32,
+                        "salt": "86c6a8857563b57be9e16ad7a3f3714f80b714bcf9da32a2788d695a194f3275",
+                        "n": 1024,
+                        "r": 8,
+                        "p": 1
+                    },
+                    "mac": "8dfedc1a92e2f2ca1c0c60cd40fabb8fb6ce7c05faf056281eb03e0a9996ecb0"
+                }
+            }
+        "#;
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let keystore = tmp_dir.path().join("keystore");
+        fs::create_dir(&keystore).unwrap();
+        fs::write(
+            keystore.join("UTC--2017-03-17T10-52-08.229Z--6412c428fc02902d137b60dc0bd0f6cd1255ea99"),
+            json,
+        ).unwrap();
+        fs::write(keystore.join("notes.txt"), "not a keyfile").unwrap();
+
+        let vault = VaultStorage::create(tmp_dir.path().join("vault")).unwrap();
+        let summary = vault
+            .create_new()
+            .ethereum_dir(&keystore, Blockchain::Ethereum)
+            .unwrap();
+
+        assert_eq!(1, summary.imported.len());
+        assert_eq!(
+            Some(EthereumAddress::from_str("0x6412c428fc02902d137b60dc0bd0f6cd1255ea99").unwrap()),
+            summary.imported[0].2
+        );
+        assert_eq!(1, summary.failed.len());
+        assert_eq!(keystore.join("notes.txt"), summary.failed[0].0);
+
+        let wallets = vault.wallets.list().unwrap();
+        assert_eq!(vec![summary.imported[0].1], wallets);
+        assert_eq!(1, vault.keys.list().unwrap().len());
+    }
+
+    #[test]
+    fn updates_seed_label() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let seed = Seed::generate(None, "testtest").unwrap();
+        let id = vault.seeds().add(seed.clone()).unwrap();
+        assert_eq!(None, vault.seeds().get(id).unwrap().label);
+
+        vault.seeds().update_label(id, Some("Savings".to_string())).unwrap();
+        let seed_act = vault.seeds().get(id).unwrap();
+        assert_eq!(Some("Savings".to_string()), seed_act.label);
+        assert_eq!(seed.source, seed_act.source);
+
+        vault.seeds().update_label(id, None).unwrap();
+        assert_eq!(None, vault.seeds().get(id).unwrap().label);
+    }
+
     #[test]
     fn order_seeds_by_date_and_id() {
         let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
@@ -853,6 +1445,143 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_wallet_summaries() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let wallet_1 = vault
+            .wallets
+            .add(Wallet {
+                label: Some("Main".to_string()),
+                created_at: Utc.timestamp_millis(0),
+                ..Wallet::default()
+            })
+            .unwrap();
+        let wallet_2 = vault
+            .wallets
+            .add(Wallet {
+                created_at: Utc.timestamp_millis(1577876400000),
+                ..Wallet::default()
+            })
+            .unwrap();
+
+        vault
+            .add_ethereum_entry(wallet_1.clone())
+            .raw_pk(
+                hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                    .unwrap(),
+                "test",
+                Blockchain::Ethereum,
+            )
+            .unwrap();
+        vault
+            .add_ethereum_entry(wallet_1.clone())
+            .raw_pk(
+                hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                    .unwrap(),
+                "test",
+                Blockchain::EthereumClassic,
+            )
+            .unwrap();
+
+        let summaries = vault.wallets().list_summaries().unwrap();
+        assert_eq!(2, summaries.len());
+
+        assert_eq!(wallet_1, summaries[0].id);
+        assert_eq!(Some("Main".to_string()), summaries[0].label);
+        assert_eq!(2, summaries[0].entries_count());
+        let wallet = vault.wallets.get(wallet_1).unwrap();
+        assert_eq!(Blockchain::Ethereum, summaries[0].entries[0].blockchain);
+        assert_eq!(Blockchain::EthereumClassic, summaries[0].entries[1].blockchain);
+        assert!(summaries[0].entries[0].address.is_some());
+        assert_eq!(wallet.entries[0].address, summaries[0].entries[0].address);
+
+        assert_eq!(wallet_2, summaries[1].id);
+        assert_eq!(None, summaries[1].label);
+        assert_eq!(0, summaries[1].entries_count());
+    }
+
+    #[test]
+    fn reorder_wallets() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let add = |ts: i64| {
+            vault
+                .wallets
+                .add(Wallet {
+                    created_at: Utc.timestamp_millis(ts),
+                    ..Wallet::default()
+                })
+                .unwrap()
+        };
+        let wallet_1 = add(1577876400000);
+        let wallet_2 = add(1577876500000);
+        let wallet_3 = add(1577876600000);
+
+        let ids = |vault: &VaultStorage| -> Vec<Uuid> {
+            vault.wallets().list_summaries().unwrap().iter().map(|w| w.id).collect()
+        };
+        assert_eq!(vec![wallet_1, wallet_2, wallet_3], ids(&vault));
+
+        vault.reorder_wallets(&[wallet_3, wallet_1, wallet_2]).unwrap();
+        assert_eq!(vec![wallet_3, wallet_1, wallet_2], ids(&vault));
+        assert_eq!(1, vault.wallets.get(wallet_3).unwrap().order);
+
+        // a new wallet goes to the end
+        let wallet_4 = add(1577876000000);
+        assert_eq!(vec![wallet_3, wallet_1, wallet_2, wallet_4], ids(&vault));
+
+        // not specified wallets go after the ordered ones
+        vault.reorder_wallets(&[wallet_2]).unwrap();
+        assert_eq!(vec![wallet_2, wallet_4, wallet_1, wallet_3], ids(&vault));
+        assert_eq!(0, vault.wallets.get(wallet_3).unwrap().order);
+    }
+
+    #[test]
+    fn cannot_reorder_unknown_wallets() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let wallet_1 = vault.wallets.add(Wallet::default()).unwrap();
+
+        assert_eq!(
+            vault.reorder_wallets(&[wallet_1, Uuid::new_v4()]),
+            Err(VaultError::IncorrectIdError)
+        );
+        assert_eq!(
+            vault.reorder_wallets(&[wallet_1, wallet_1]),
+            Err(VaultError::InvalidDataError("Duplicate wallet id".to_string()))
+        );
+        assert_eq!(0, vault.wallets.get(wallet_1).unwrap().order);
+    }
+
+    #[test]
+    fn doesnt_reorder_if_any_wallet_unreadable() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let wallet_1 = vault.wallets.add(Wallet::default()).unwrap();
+        let wallet_2 = vault.wallets.add(Wallet::default()).unwrap();
+        let corrupted = Uuid::new_v4();
+        fs::write(tmp_dir.path().join(format!("{}.wallet", corrupted)), "not a wallet").unwrap();
+
+        assert!(vault.reorder_wallets(&[wallet_2, wallet_1]).is_err());
+        assert_eq!(0, vault.wallets.get(wallet_1).unwrap().order);
+        assert_eq!(0, vault.wallets.get(wallet_2).unwrap().order);
+    }
+
+    #[test]
+    fn cannot_reorder_in_readonly_mode() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let wallet_1 = vault.wallets.add(Wallet::default()).unwrap();
+        let wallet_2 = vault.wallets.add(Wallet::default()).unwrap();
+
+        let vault = VaultStorage::open_readonly(tmp_dir.path()).unwrap();
+        assert_eq!(Err(VaultError::ReadOnly), vault.reorder_wallets(&[wallet_2, wallet_1]));
+        assert_eq!(0, vault.wallets.get(wallet_1).unwrap().order);
+    }
+
     #[test]
     fn uses_different_entry_ids() {
         let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
@@ -895,6 +1624,89 @@ mod tests {
         assert_eq!(1, id2);
     }
 
+    #[test]
+    fn keeps_entries_added_concurrently() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let wallet = Wallet::default();
+        let wallet_id = vault.wallets.add(wallet).unwrap();
+
+        let threads: Vec<_> = vec![Blockchain::Ethereum, Blockchain::EthereumClassic]
+            .into_iter()
+            .map(|blockchain| {
+                let path = tmp_dir.path().to_path_buf();
+                std::thread::spawn(move || {
+                    let vault = VaultStorage::create(path).unwrap();
+                    for _ in 0..3 {
+                        vault
+                            .add_ethereum_entry(wallet_id)
+                            .raw_pk(
+                                hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                                    .unwrap(),
+                                "test",
+                                blockchain,
+                            )
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for t in threads {
+            t.join().expect("Thread failed");
+        }
+
+        let wallet = vault.wallets.get(wallet_id).unwrap();
+        assert_eq!(6, wallet.entries.len());
+        assert_eq!(6, wallet.entry_seq);
+        assert_eq!(
+            3,
+            wallet.entries.iter().filter(|e| e.blockchain == Blockchain::Ethereum).count()
+        );
+        let mut ids: Vec<usize> = wallet.entries.iter().map(|e| e.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(6, ids.len());
+    }
+
+    #[test]
+    fn get_address_for_seed_entry() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "often impact pistol seminar park example foil urge bird balance reopen uphold enforce protect pear",
+        ).unwrap();
+        let seed_id = vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+            ..Seed::default()
+        }).unwrap();
+        let wallet_id = vault.wallets.add(Wallet::default()).unwrap();
+        let entry_id = vault
+            .add_ethereum_entry(wallet_id)
+            .seed_hd(
+                seed_id,
+                StandardHDPath::from_str("m/44'/60'/0'/0/7").unwrap(),
+                Blockchain::Ethereum,
+                Some("test".to_string()),
+                None,
+            )
+            .unwrap();
+
+        let act = vault.get_address(wallet_id, entry_id, Some("test".to_string()));
+        assert_eq!(
+            Ok(EthereumAddress::from_str("0x677009BB7C387fA265c1697772e6FA3772548e87").unwrap()),
+            act
+        );
+
+        let act = vault.get_address(wallet_id, entry_id, None);
+        assert_eq!(Err(VaultError::PasswordRequired), act);
+
+        let act = vault.get_address(wallet_id, entry_id + 1, Some("test".to_string()));
+        assert_eq!(Err(VaultError::DataNotFound), act);
+    }
+
     #[test]
     fn start_entry_id_from_seq() {
         let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
@@ -1000,6 +1812,81 @@ mod tests {
         assert_eq!(act, "test 2")
     }
 
+    #[test]
+    fn write_atomic_replaces_file() {
+        let tmp_dir = TempDir::new("emerald-vault-test")
+            .expect("Dir not created")
+            .into_path();
+        let f = tmp_dir
+            .clone()
+            .join("e779c975-6791-47a3-a4d6-d0e976d02820.key");
+        write_atomic(&f, "test 1").unwrap();
+        assert_eq!(fs::read_to_string(&f).unwrap(), "test 1");
+        write_atomic(&f, "test 2").unwrap();
+        assert_eq!(fs::read_to_string(&f).unwrap(), "test 2");
+        // no temp files left
+        assert_eq!(fs::read_dir(&tmp_dir).unwrap().count(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn syncs_parent_dir() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let f = tmp_dir.path().join("e779c975-6791-47a3-a4d6-d0e976d02820.key");
+        assert!(sync_parent_dir(&f).is_ok());
+        // relative to the current dir
+        assert!(sync_parent_dir(Path::new("e779c975-6791-47a3-a4d6-d0e976d02820.key")).is_ok());
+        assert!(sync_parent_dir(&tmp_dir.path().join("none").join("test.key")).is_err());
+    }
+
+    #[test]
+    fn interrupted_write_doesnt_touch_target() {
+        let tmp_dir = TempDir::new("emerald-vault-test")
+            .expect("Dir not created")
+            .into_path();
+        let f = tmp_dir
+            .clone()
+            .join("e779c975-6791-47a3-a4d6-d0e976d02820.key");
+        write_atomic(&f, "test 1").unwrap();
+        // as if it crashed in the middle of writing next version
+        let f_tmp = tmp_dir
+            .clone()
+            .join("e779c975-6791-47a3-a4d6-d0e976d02820.key.0f0c1ee4e8a84a8f9c0ab3a1f1e1c0a2.tmp");
+        fs::write(&f_tmp, "tes").unwrap();
+
+        assert_eq!(fs::read_to_string(&f).unwrap(), "test 1");
+        assert_eq!(try_vault_file(&f_tmp, "key"), Err(()));
+
+        // next write ignores the leftover
+        write_atomic(&f, "test 2").unwrap();
+        assert_eq!(fs::read_to_string(&f).unwrap(), "test 2");
+        assert_eq!(fs::read_to_string(&f_tmp).unwrap(), "tes");
+    }
+
+    #[test]
+    fn write_atomic_concurrently() {
+        let tmp_dir = TempDir::new("emerald-vault-test")
+            .expect("Dir not created")
+            .into_path();
+        let f = tmp_dir
+            .clone()
+            .join("e779c975-6791-47a3-a4d6-d0e976d02820.key");
+        let threads: Vec<_> = (0..4)
+            .map(|i| {
+                let f = f.clone();
+                std::thread::spawn(move || {
+                    for j in 0..25 {
+                        write_atomic(&f, format!("test {}-{}", i, j)).unwrap();
+                    }
+                })
+            })
+            .collect();
+        threads.into_iter().for_each(|t| t.join().unwrap());
+        assert!(fs::read_to_string(&f).unwrap().starts_with("test "));
+        // no temp files left
+        assert_eq!(fs::read_dir(&tmp_dir).unwrap().count(), 1);
+    }
+
     #[test]
     fn safe_update_when_bak_exists() {
         let tmp_dir = TempDir::new("emerald-vault-test")
@@ -1106,6 +1993,7 @@ mod tests {
         let in_vault = read_dir_fully(tmp_dir.clone())
             .iter()
             .filter(|x| x.path().is_file())
+            .filter(|x| x.path().extension().map_or(false, |e| e == "key"))
             .count();
         assert_eq!(in_vault, 0);
     }
@@ -1504,6 +2392,68 @@ mod tests {
         assert_eq!(2, wallet.entry_seq);
     }
 
+    #[test]
+    fn remove_seed_entry_prunes_reserved() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "quote ivory blast onion below kangaroo tonight spread awkward decide farm gun exact wood brown",
+        ).unwrap();
+        let seed_id = vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+            ..Seed::default()
+        }).unwrap();
+        let wallet_id = vault.wallets.add(Wallet::default()).unwrap();
+
+        let add = |hd_path: &str| {
+            vault
+                .add_ethereum_entry(wallet_id)
+                .seed_hd(
+                    seed_id,
+                    StandardHDPath::from_str(hd_path).unwrap(),
+                    Blockchain::Ethereum,
+                    Some("test".to_string()),
+                    None,
+                )
+                .unwrap()
+        };
+        let id1 = add("m/44'/60'/0'/0/0");
+        let id2 = add("m/44'/60'/0'/0/1");
+        let id3 = add("m/44'/60'/3'/0/0");
+
+        let wallet = vault.wallets.get(wallet_id).unwrap();
+        assert_eq!(
+            vec![
+                ReservedPath { seed_id, account_id: 0 },
+                ReservedPath { seed_id, account_id: 3 },
+            ],
+            wallet.reserved
+        );
+
+        let removed = vault.remove_entry(wallet_id, id3);
+        assert_eq!(Ok(true), removed);
+        let wallet = vault.wallets.get(wallet_id).unwrap();
+        assert_eq!(2, wallet.entries.len());
+        assert_eq!(
+            vec![ReservedPath { seed_id, account_id: 0 }],
+            wallet.reserved
+        );
+
+        // account 0 is still used by the second entry
+        let removed = vault.remove_entry(wallet_id, id1);
+        assert_eq!(Ok(true), removed);
+        let wallet = vault.wallets.get(wallet_id).unwrap();
+        assert_eq!(1, wallet.entries.len());
+        assert_eq!(id2, wallet.entries[0].id);
+        assert_eq!(3, wallet.entry_seq);
+        assert_eq!(
+            vec![ReservedPath { seed_id, account_id: 0 }],
+            wallet.reserved
+        );
+    }
+
     #[test]
     fn set_entry_label() {
         let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
@@ -1546,6 +2496,19 @@ mod tests {
         assert_eq!(Ok(true), result);
         let wallet = vault.wallets.get(wallet_id).unwrap();
         assert_eq!(None, wallet.entries[0].label);
+
+        let result = vault
+            .update_entry(wallet_id, id1)
+            .set_address_label(2, Some("savings".to_string()));
+        assert_eq!(Ok(true), result);
+        let wallet = vault.wallets.get(wallet_id).unwrap();
+        assert_eq!(Some("savings"), wallet.entries[0].get_address_label(2));
+        assert_eq!(None, wallet.entries[0].label);
+
+        let result = vault.update_entry(wallet_id, id1).set_address_label(2, None);
+        assert_eq!(Ok(true), result);
+        let wallet = vault.wallets.get(wallet_id).unwrap();
+        assert!(wallet.entries[0].labels.is_empty());
     }
 
     #[test]
@@ -1,13 +1,13 @@
 use std::sync::Arc;
-use crate::storage::vault::VaultAccessByFile;
-use crate::structs::seed::{Seed, SeedSource, SeedRef};
+use crate::storage::vault::{SingleFileEntry, VaultAccessByFile};
+use crate::structs::seed::{Seed, SeedSource, SeedRef, SeedPath};
 use crate::structs::wallet::{Wallet, WalletEntry, PKType};
 use uuid::Uuid;
 use crate::storage::error::VaultError;
 use hdpath::{StandardHDPath, AccountHDPath, PathValue, HDPath};
 use crate::blockchain::chains::{Blockchain, BlockchainType};
 use bitcoin::util::bip32::{ExtendedPrivKey, ExtendedPubKey, DerivationPath};
-use crate::blockchain::bitcoin::{AddressType, XPub};
+use crate::blockchain::bitcoin::{AddressType, XPrv, XPub};
 use crate::sign::bitcoin::DEFAULT_SECP256K1;
 use crate::structs::book::AddressRef;
 use emerald_hwkey::{
@@ -23,7 +23,13 @@ use emerald_hwkey::{
     }
 };
 use std::borrow::Borrow;
-use crate::storage::entry::AddEntryOptions;
+use crate::storage::entry::{verify_purpose, AddEntryOptions};
+use crate::sign::ledger::bitcoin_app_name;
+use zeroize::Zeroizing;
+use crate::storage::vault::VaultStorage;
+use bitcoin::Network;
+use std::convert::TryFrom;
+use std::str::FromStr;
 
 pub struct AddBitcoinEntry {
     seeds: Arc<dyn VaultAccessByFile<Seed>>,
@@ -31,9 +37,9 @@ pub struct AddBitcoinEntry {
     wallet_id: Uuid,
 }
 
-fn get_address(blockchain: &Blockchain, address_type: AddressType, account: u32, seed: Vec<u8>) -> Result<XPub, VaultError> {
+fn get_address(blockchain: &Blockchain, address_type: AddressType, account: u32, seed: &[u8]) -> Result<XPub, VaultError> {
     let network = blockchain.as_bitcoin_network();
-    let master = ExtendedPrivKey::new_master(network.clone(), seed.as_slice())
+    let master = ExtendedPrivKey::new_master(network.clone(), seed)
         .map_err(|_| VaultError::InvalidPrivateKey)?;
     if !PathValue::is_ok(account) {
         return Err(VaultError::PrivateKeyUnavailable)
@@ -70,18 +76,21 @@ impl AddBitcoinEntry {
         if blockchain.get_type() != BlockchainType::Bitcoin {
             return Err(VaultError::IncorrectBlockchainError)
         }
+        verify_purpose(blockchain, hd_path.purpose())?;
+        if hd_path.coin_type() != blockchain.coin_type() {
+            return Err(VaultError::UnsupportedDataError(
+                format!("HD Path coin type {}' is not supported for {:?}", hd_path.coin_type(), blockchain)
+            ))
+        }
         let seed = self.seeds.get(seed_id)?;
-        let address_type = AddressType::P2WPKH;
+        let address_type = AddressType::try_from(&hd_path)?;
         let account = address_type.get_hd_path(hd_path.account(), &blockchain.as_bitcoin_network());
-        if account.purpose() != hd_path.purpose() {
-            return Err(VaultError::UnsupportedDataError("Invalid HD Path purpose for address".to_string()))
-        }
         let xpub = match seed.source {
             SeedSource::Bytes(seed) => {
                 match &opts.seed_password {
                     Some(seed_password) => {
-                        let seed = seed.decrypt(seed_password.as_str())?;
-                        Some(get_address(&blockchain, address_type, account.account(), seed)?)
+                        let seed = Zeroizing::new(seed.decrypt(seed_password.as_str())?);
+                        Some(get_address(&blockchain, address_type, account.account(), &seed)?)
                     },
                     None => return Err(VaultError::PasswordRequired)
                 }
@@ -91,15 +100,21 @@ impl AddBitcoinEntry {
                 if let Ok(manager) = manager {
                     let bitcoin_app = BitcoinApp::new(&manager);
                     let exp_app = match blockchain {
-                        Blockchain::Bitcoin => Some(BitcoinApps::Mainnet),
-                        Blockchain::BitcoinTestnet => Some(BitcoinApps::Testnet),
-                        _ => None
+                        Blockchain::Bitcoin => BitcoinApps::Mainnet,
+                        Blockchain::BitcoinTestnet => BitcoinApps::Testnet,
+                        _ => return Err(VaultError::IncorrectBlockchainError)
                     };
-                    if exp_app.is_none() || bitcoin_app.is_open() != exp_app {
+                    let exp_name = bitcoin_app_name(&exp_app);
+                    if bitcoin_app.is_open() != Some(exp_app) {
+                        // it's fine to use a provided xpub when the app is not opened,
+                        // otherwise let the user know which app is expected
+                        if opts.xpub.is_none() {
+                            return Err(VaultError::LedgerAppNotOpened(exp_name.to_string()))
+                        }
                         None
                     } else {
                         let xpub = bitcoin_app.get_xpub(&account, blockchain.as_bitcoin_network())?;
-                        Some(XPub::standard(xpub))
+                        Some(XPub { value: xpub, address_type })
                     }
                 } else {
                     None
@@ -129,15 +144,18 @@ impl AddBitcoinEntry {
 
         let address_ref = AddressRef::ExtendedPub(xpub);
 
+        let lock = self.wallets.get_lock_for(self.wallet_id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
         let mut wallet = self.wallets.get(self.wallet_id.clone())?;
         let id = wallet.next_entry_id();
+        wallet.reserve(seed_id, account.account());
         wallet.entries.push(WalletEntry {
             id,
             blockchain,
             address: Some(address_ref),
             key: PKType::SeedHd(SeedRef {
                 seed_id: seed_id.clone(),
-                hd_path: account.address_at(0, 0).expect("Generate first address for account"),
+                hd_path: SeedPath::Account(account),
             }),
             ..WalletEntry::default()
         });
@@ -147,16 +165,72 @@ impl AddBitcoinEntry {
     }
 }
 
+impl VaultStorage {
+    /// Export account-level extended private key (xprv, yprv, zprv, etc depending on the address type
+    /// of the path) for a seed. Not available for a Ledger based seed.
+    pub fn export_xprv(
+        &self,
+        seed_id: Uuid,
+        account: &AccountHDPath,
+        seed_password: &str,
+    ) -> Result<String, VaultError> {
+        let seed = self.seeds().get(seed_id)?;
+        let seed = match seed.source {
+            SeedSource::Bytes(encrypted) => Zeroizing::new(encrypted.decrypt(seed_password)?),
+            SeedSource::Ledger(_) => return Err(VaultError::PrivateKeyUnavailable),
+        };
+        let address_type = AddressType::try_from(account)?;
+        let network = match account.coin_type() {
+            0 => Network::Bitcoin,
+            1 => Network::Testnet,
+            other => return Err(VaultError::UnsupportedDataError(format!("Coin type {}", other))),
+        };
+        let master = ExtendedPrivKey::new_master(network, seed.as_slice())
+            .map_err(|_| VaultError::InvalidPrivateKey)?;
+        let account_dp: DerivationPath = account.clone().into();
+        let xprv = master.derive_priv(&DEFAULT_SECP256K1, &account_dp)
+            .map_err(|_| VaultError::PrivateKeyUnavailable)?;
+        Ok(XPrv { value: xprv, address_type }.to_string())
+    }
+
+    /// Create a watch-only wallet for an account-level xpub (xpub, ypub, zpub, etc). The wallet entry
+    /// derives addresses from the xpub, but can't sign because there is no private key for it.
+    ///
+    /// Returns id of the created wallet
+    pub fn add_watch_xpub(&self, xpub: &str, blockchain: Blockchain) -> Result<Uuid, VaultError> {
+        if blockchain.get_type() != BlockchainType::Bitcoin {
+            return Err(VaultError::IncorrectBlockchainError)
+        }
+        let xpub = XPub::from_str(xpub)?;
+        if xpub.value.network != blockchain.as_bitcoin_network() {
+            return Err(VaultError::IncorrectBlockchainError)
+        }
+        if !xpub.is_account() {
+            return Err(VaultError::UnsupportedDataError("Not an account xpub".to_string()))
+        }
+        let wallet = Wallet {
+            entries: vec![WalletEntry {
+                id: 0,
+                blockchain,
+                address: Some(AddressRef::ExtendedPub(xpub)),
+                key: PKType::Watch,
+                ..WalletEntry::default()
+            }],
+            entry_seq: 1,
+            ..Wallet::default()
+        };
+        self.wallets().add(wallet)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempdir::TempDir;
-    use crate::storage::vault::VaultStorage;
     use crate::mnemonic::{Mnemonic, Language};
-    use std::convert::TryFrom;
     use crate::structs::wallet::ReservedPath;
-    use std::str::FromStr;
     use crate::structs::seed::LedgerSource;
+    use crate::blockchain::bitcoin::{BitcoinTransferProposal, KeyMapping};
 
     #[test]
     fn adds_seed_entry() {
@@ -205,6 +279,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn stores_account_and_derives_key_for_index() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "avoid midnight couch purchase truth segment sauce claim spell spring smoke renew term stem solve",
+        ).unwrap();
+        let seed_id = vault.seeds().add(
+            Seed {
+                source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+                ..Default::default()
+            }
+        ).unwrap();
+        let wallet_id = vault.wallets().add(Wallet {
+            ..Default::default()
+        }).unwrap();
+
+        vault.add_bitcoin_entry(wallet_id.clone()).seed_hd(
+            seed_id,
+            AccountHDPath::from_str("m/84'/0'/3'").unwrap(),
+            Blockchain::Bitcoin,
+            AddEntryOptions::with_seed_password("test"),
+        ).expect("entry not created");
+
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        let entry = &wallet.entries[0];
+        assert_eq!(
+            PKType::SeedHd(SeedRef {
+                seed_id,
+                hd_path: SeedPath::Account(AccountHDPath::from_str("m/84'/0'/3'").unwrap()),
+            }),
+            entry.key
+        );
+
+        for (change, index) in vec![(0, 0), (0, 1), (0, 17), (1, 0), (1, 4)] {
+            let key = entry.key
+                .get_pk_at(&vault, Some("test".to_string()), change, index)
+                .unwrap()
+                .into_bitcoin_key(&Network::Bitcoin);
+            let address = bitcoin::Address::p2wpkh(
+                &key.public_key(&DEFAULT_SECP256K1),
+                Network::Bitcoin,
+            ).unwrap();
+            let expected = entry.addresses(change == 1, index, 1).unwrap();
+            assert_eq!(expected[0].1, address.to_string(), "{}/{}", change, index);
+        }
+    }
+
+    #[test]
+    fn reserves_account_once() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "avoid midnight couch purchase truth segment sauce claim spell spring smoke renew term stem solve",
+        ).unwrap();
+        let seed_id = vault.seeds().add(
+            Seed {
+                source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+                ..Default::default()
+            }
+        ).unwrap();
+        let wallet_id = vault.wallets().add(Wallet {
+            ..Default::default()
+        }).unwrap();
+
+        let entry_1 = vault.add_bitcoin_entry(wallet_id.clone()).seed_hd(
+            seed_id,
+            AccountHDPath::from_str("m/84'/0'/3'").unwrap(),
+            Blockchain::Bitcoin,
+            AddEntryOptions::with_seed_password("test"),
+        ).expect("entry not created");
+        let entry_2 = vault.add_bitcoin_entry(wallet_id.clone()).seed_hd(
+            seed_id,
+            AccountHDPath::from_str("m/84'/0'/3'").unwrap(),
+            Blockchain::Bitcoin,
+            AddEntryOptions::with_seed_password("test"),
+        ).expect("entry not created");
+
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        assert_eq!(2, wallet.entries.len());
+        assert_ne!(entry_1, entry_2);
+        assert_eq!(
+            vec![ReservedPath { seed_id, account_id: 3 }],
+            wallet.reserved
+        );
+    }
+
     #[test]
     fn adds_seed_entry_testnet() {
         let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
@@ -260,7 +423,7 @@ mod tests {
         let vault = VaultStorage::create(tmp_dir.path()).unwrap();
 
         let seed_id = vault.seeds().add(Seed {
-            source: SeedSource::Ledger(LedgerSource { fingerprints: vec![] }),
+            source: SeedSource::Ledger(LedgerSource::default()),
             ..Default::default()
         }).unwrap();
 
@@ -304,7 +467,7 @@ mod tests {
         let vault = VaultStorage::create(tmp_dir.path()).unwrap();
 
         let seed_id = vault.seeds().add(Seed {
-            source: SeedSource::Ledger(LedgerSource { fingerprints: vec![] }),
+            source: SeedSource::Ledger(LedgerSource::default()),
             ..Default::default()
         }).unwrap();
 
@@ -348,7 +511,7 @@ mod tests {
         let vault = VaultStorage::create(tmp_dir.path()).unwrap();
 
         let seed_id = vault.seeds().add(Seed {
-            source: SeedSource::Ledger(LedgerSource { fingerprints: vec![] }),
+            source: SeedSource::Ledger(LedgerSource::default()),
             ..Default::default()
         }).unwrap();
 
@@ -360,7 +523,7 @@ mod tests {
 
         let added = vault.add_bitcoin_entry(wallet_id.clone()).seed_hd(
             seed_id,
-            AccountHDPath::from_str("m/84'/0'/3'").unwrap(),
+            AccountHDPath::from_str("m/84'/1'/3'").unwrap(),
             Blockchain::BitcoinTestnet,
             AddEntryOptions {
                 xpub: Some(
@@ -381,7 +544,7 @@ mod tests {
         let vault = VaultStorage::create(tmp_dir.path()).unwrap();
 
         let seed_id = vault.seeds().add(Seed {
-            source: SeedSource::Ledger(LedgerSource { fingerprints: vec![] }),
+            source: SeedSource::Ledger(LedgerSource::default()),
             ..Default::default()
         }).unwrap();
 
@@ -404,4 +567,199 @@ mod tests {
 
         assert_eq!(added.err(), Some(VaultError::InvalidDataError("Different xpub".to_string())));
     }
+
+    #[test]
+    fn rejects_ethereum_path() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "avoid midnight couch purchase truth segment sauce claim spell spring smoke renew term stem solve",
+        ).unwrap();
+        let seed_id = vault.seeds().add(
+            Seed {
+                source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+                ..Default::default()
+            }
+        ).unwrap();
+        let wallet_id = vault.wallets().add(Wallet {
+            ..Default::default()
+        }).unwrap();
+
+        let act = vault.add_bitcoin_entry(wallet_id.clone()).seed_hd(
+            seed_id,
+            AccountHDPath::from_str("m/44'/60'/0'").unwrap(),
+            Blockchain::Bitcoin,
+            AddEntryOptions::with_seed_password("test"),
+        );
+        assert_eq!(
+            Err(VaultError::UnsupportedDataError("HD Path coin type 60' is not supported for Bitcoin".to_string())),
+            act
+        );
+
+        let act = vault.add_bitcoin_entry(wallet_id.clone()).seed_hd(
+            seed_id,
+            AccountHDPath::from_str("m/45'/0'/0'").unwrap(),
+            Blockchain::Bitcoin,
+            AddEntryOptions::with_seed_password("test"),
+        );
+        assert_eq!(
+            Err(VaultError::UnsupportedDataError("HD Path purpose 45' is not supported for Bitcoin".to_string())),
+            act
+        );
+
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        assert!(wallet.entries.is_empty());
+    }
+
+    #[test]
+    fn accepts_only_segwit_path_of_the_blockchain() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "avoid midnight couch purchase truth segment sauce claim spell spring smoke renew term stem solve",
+        ).unwrap();
+        let seed_id = vault.seeds().add(
+            Seed {
+                source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+                ..Default::default()
+            }
+        ).unwrap();
+        let wallet_id = vault.wallets().add(Wallet {
+            ..Default::default()
+        }).unwrap();
+
+        for (path, blockchain, error) in vec![
+            ("m/44'/0'/0'", Blockchain::Bitcoin, "HD Path purpose 44' is not supported for Bitcoin"),
+            ("m/49'/0'/0'", Blockchain::Bitcoin, "HD Path purpose 49' is not supported for Bitcoin"),
+            ("m/84'/1'/0'", Blockchain::Bitcoin, "HD Path coin type 1' is not supported for Bitcoin"),
+            ("m/84'/0'/0'", Blockchain::BitcoinTestnet, "HD Path coin type 0' is not supported for BitcoinTestnet"),
+        ] {
+            let act = vault.add_bitcoin_entry(wallet_id.clone()).seed_hd(
+                seed_id,
+                AccountHDPath::from_str(path).unwrap(),
+                blockchain,
+                AddEntryOptions::with_seed_password("test"),
+            );
+            assert_eq!(Err(VaultError::UnsupportedDataError(error.to_string())), act);
+        }
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        assert!(wallet.entries.is_empty());
+
+        for (path, blockchain) in vec![
+            ("m/84'/0'/0'", Blockchain::Bitcoin),
+            ("m/84'/1'/0'", Blockchain::BitcoinTestnet),
+        ] {
+            let entry_id = vault.add_bitcoin_entry(wallet_id.clone()).seed_hd(
+                seed_id,
+                AccountHDPath::from_str(path).unwrap(),
+                blockchain,
+                AddEntryOptions::with_seed_password("test"),
+            ).expect("entry not created");
+            let wallet = vault.wallets().get(wallet_id).unwrap();
+            match &wallet.get_entry(entry_id).unwrap().address {
+                Some(AddressRef::ExtendedPub(xpub)) => assert_eq!(AddressType::P2WPKH, xpub.address_type),
+                _ => panic!("not xpub"),
+            }
+        }
+    }
+
+    #[test]
+    fn export_xprv_for_seed() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "quote ivory blast onion below kangaroo tonight spread awkward decide farm gun exact wood brown",
+        ).unwrap();
+        let seed_id = vault.seeds().add(
+            Seed {
+                source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+                ..Default::default()
+            }
+        ).unwrap();
+
+        let act = vault.export_xprv(seed_id, &AccountHDPath::from_str("m/84'/1'/0'").unwrap(), "test").unwrap();
+        assert!(act.starts_with("vprv"));
+
+        // switch to standard tprv version to parse it with the bitcoin lib
+        let mut data = bitcoin::util::base58::from_check(act.as_str()).unwrap();
+        data[0..4].copy_from_slice(&[0x04, 0x35, 0x83, 0x94]);
+        let xprv = ExtendedPrivKey::from_str(
+            bitcoin::util::base58::check_encode_slice(data.as_slice()).as_str()
+        ).unwrap();
+        let xprv = XPrv { value: xprv, address_type: AddressType::P2WPKH };
+        assert_eq!(xprv.to_string(), act);
+        assert_eq!(
+            xprv.xpub().to_string(),
+            "vpub5Yxb4hoHAGV32y67pPDQCbPFUbB9w95gkR1nCxv92t2axDYWeNV4xzo1wxgz8A1S5QGWusHzCP969uaBbt4hjV8CT3PKe7tfic4v9RMbFc4".to_string()
+        );
+    }
+
+    #[test]
+    fn no_xprv_for_ledger() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = vault.seeds().add(Seed {
+            source: SeedSource::Ledger(LedgerSource::default()),
+            ..Default::default()
+        }).unwrap();
+
+        let act = vault.export_xprv(seed_id, &AccountHDPath::from_str("m/84'/0'/0'").unwrap(), "test");
+        assert_eq!(Err(VaultError::PrivateKeyUnavailable), act);
+    }
+
+    #[test]
+    fn watch_only_xpub() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+
+        let wallet_id = vault.add_watch_xpub(
+            "zpub6rebv42D4si3ibWtrRoeS3qvEaRWBuLfwq1SXZt6UMVU9CH8snBWeFFMSMvWsv5WFGVRhqr8gg2AR751SrKteeX9bq57HbTyQvqPznSpZex",
+            Blockchain::Bitcoin,
+        ).unwrap();
+
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        assert_eq!(1, wallet.entries.len());
+        let entry = wallet.entries[0].clone();
+        assert_eq!(PKType::Watch, entry.key);
+        assert_eq!(
+            vec![(0, "bc1q8redwn9d9qr0nkp7ah367u56ufxjprf0lvp7an".to_string())],
+            entry.addresses(false, 0, 1).unwrap()
+        );
+
+        assert_eq!(
+            Some(VaultError::PrivateKeyUnavailable),
+            entry.key.get_pk(&vault, Some("test".to_string())).err()
+        );
+        let proposal = BitcoinTransferProposal {
+            network: Network::Bitcoin,
+            seed: vec![],
+            keys: KeyMapping::single(Uuid::new_v4(), "test".to_string()),
+            input: vec![],
+            output: vec![],
+            change: entry.clone(),
+            expected_fee: 0,
+        };
+        assert_eq!(Err(VaultError::PrivateKeyUnavailable), entry.sign_bitcoin(proposal));
+    }
+
+    #[test]
+    fn no_watch_xpub_for_other_blockchain() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let xpub = "zpub6rebv42D4si3ibWtrRoeS3qvEaRWBuLfwq1SXZt6UMVU9CH8snBWeFFMSMvWsv5WFGVRhqr8gg2AR751SrKteeX9bq57HbTyQvqPznSpZex";
+
+        assert_eq!(
+            Err(VaultError::IncorrectBlockchainError),
+            vault.add_watch_xpub(xpub, Blockchain::BitcoinTestnet)
+        );
+        assert_eq!(
+            Err(VaultError::IncorrectBlockchainError),
+            vault.add_watch_xpub(xpub, Blockchain::Ethereum)
+        );
+        assert!(vault.add_watch_xpub("zpub6rebv42D4si3ibWtrRoeS3qvEaRWBuLfwq", Blockchain::Bitcoin).is_err());
+        assert!(vault.wallets().list().unwrap().is_empty());
+    }
 }
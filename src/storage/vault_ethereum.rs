@@ -1,6 +1,6 @@
 use std::convert::TryFrom;
 
-use hdpath::StandardHDPath;
+use hdpath::{Purpose, StandardHDPath};
 use uuid::Uuid;
 
 use crate::blockchain::{
@@ -9,8 +9,9 @@ use crate::blockchain::{
 };
 use crate::convert::error::ConversionError;
 use crate::sign::bip32::generate_key;
+use crate::storage::entry::verify_purpose;
 use crate::storage::error::VaultError;
-use crate::storage::vault::{VaultAccessByFile};
+use crate::storage::vault::{SingleFileEntry, VaultAccessByFile};
 use crate::structs::book::AddressRef;
 use crate::structs::seed::{Seed, SeedRef, SeedSource};
 use crate::structs::wallet::{PKType, Wallet, WalletEntry};
@@ -18,12 +19,14 @@ use std::sync::Arc;
 use crate::structs::pk::PrivateKeyHolder;
 use crate::convert::json::keyfile::EthereumJsonV3File;
 use std::time::SystemTime;
+use std::collections::HashMap;
 use crate::structs::types::HasUuid;
 use crate::blockchain::chains::BlockchainType;
 use emerald_hwkey::ledger::manager::LedgerKey;
 use std::str::FromStr;
 use emerald_hwkey::ledger::app_ethereum::EthereumApp;
 use emerald_hwkey::ledger::traits::LedgerApp;
+use zeroize::Zeroizing;
 
 pub struct AddEthereumEntry {
     keys: Arc<dyn VaultAccessByFile<PrivateKeyHolder>>,
@@ -50,6 +53,8 @@ impl AddEthereumEntry {
         json: &EthereumJsonV3File,
         blockchain: Blockchain,
     ) -> Result<usize, VaultError> {
+        let lock = self.wallets.get_lock_for(self.wallet_id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
         let mut wallet = self.wallets.get(self.wallet_id.clone())?;
         let mut pk = PrivateKeyHolder::try_from(json)?;
         let pk_id = pk.generate_id();
@@ -63,6 +68,7 @@ impl AddEthereumEntry {
             receive_disabled: false,
             label: json.name.clone(),
             created_at: SystemTime::now().into(),
+            labels: HashMap::new(),
         });
         wallet.entry_seq = id + 1;
         self.wallets.update(wallet.clone())?;
@@ -75,7 +81,10 @@ impl AddEthereumEntry {
         password: &str,
         blockchain: Blockchain,
     ) -> Result<usize, VaultError> {
+        let lock = self.wallets.get_lock_for(self.wallet_id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
         let mut wallet = self.wallets.get(self.wallet_id.clone())?;
+        self.keys.check_password(password)?;
         let pk = PrivateKeyHolder::create_ethereum_raw(pk, password)
             .map_err(|_| VaultError::InvalidDataError("Invalid PrivateKey".to_string()))?;
         let pk_id = pk.get_id();
@@ -97,6 +106,25 @@ impl AddEthereumEntry {
         Ok(id)
     }
 
+    /// Add an entry for the first address (`m/44'/coin_type'/account'/0/0`) of an account on the seed,
+    /// where the coin type is the SLIP-44 value of the blockchain, i.e. `60` for Ethereum and `61`
+    /// for Ethereum Classic
+    pub fn seed_account(
+        &self,
+        seed_id: Uuid,
+        account: u32,
+        blockchain: Blockchain,
+        password: Option<String>,
+        expected_address: Option<EthereumAddress>,
+    ) -> Result<usize, VaultError> {
+        if blockchain.get_type() != BlockchainType::Ethereum {
+            return Err(VaultError::IncorrectBlockchainError)
+        }
+        let hd_path = StandardHDPath::try_new(Purpose::Pubkey, blockchain.coin_type(), account, 0, 0)
+            .map_err(|_| VaultError::InvalidDataError("account".to_string()))?;
+        self.seed_hd(seed_id, hd_path, blockchain, password, expected_address)
+    }
+
     pub fn seed_hd(
         &self,
         seed_id: Uuid,
@@ -108,13 +136,14 @@ impl AddEthereumEntry {
         if blockchain.get_type() != BlockchainType::Ethereum {
             return Err(VaultError::IncorrectBlockchainError)
         }
+        verify_purpose(blockchain, hd_path.purpose())?;
         let seed = self.seeds.get(seed_id)?;
         let address = match seed.source {
             SeedSource::Bytes(seed) => {
                 if password.is_none() {
                     return Err(VaultError::PasswordRequired);
                 }
-                let seed = seed.decrypt(password.unwrap().as_str())?;
+                let seed = Zeroizing::new(seed.decrypt(password.unwrap().as_str())?);
                 let key = generate_key(&hd_path, seed.as_slice())?;
                 let ephemeral_pk = EthereumPrivateKey::try_from(key)?;
                 Some(ephemeral_pk.to_address())
@@ -139,6 +168,8 @@ impl AddEthereumEntry {
             ));
         }
 
+        let lock = self.wallets.get_lock_for(self.wallet_id);
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
         let mut wallet = self.wallets.get(self.wallet_id.clone())?;
         let id = wallet.next_entry_id();
         wallet.entries.push(WalletEntry {
@@ -153,7 +184,7 @@ impl AddEthereumEntry {
                     VaultError::ConversionError(ConversionError::InvalidFieldValue(
                         "hd_path".to_string(),
                     ))
-                })?,
+                })?.into(),
             }),
             ..WalletEntry::default()
         });
@@ -174,6 +205,7 @@ mod tests {
     use chrono::{TimeZone, Utc};
     use tempdir::TempDir;
     use crate::storage::vault::VaultStorage;
+    use crate::mnemonic::{Language, Mnemonic};
 
     #[test]
     fn add_single_pk() {
@@ -264,4 +296,143 @@ mod tests {
         let list = vault_pk.list().unwrap();
         assert_eq!(0, list.len());
     }
+
+    #[test]
+    fn rejects_bitcoin_path() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "often impact pistol seminar park example foil urge bird balance reopen uphold enforce protect pear",
+        ).unwrap();
+        let seed_id = vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+            ..Seed::default()
+        }).unwrap();
+        let wallet_id = vault.wallets().add(Wallet::default()).unwrap();
+
+        let act = vault.add_ethereum_entry(wallet_id).seed_hd(
+            seed_id,
+            StandardHDPath::from_str("m/84'/0'/0'/0/0").unwrap(),
+            Blockchain::Ethereum,
+            Some("test".to_string()),
+            None,
+        );
+        assert_eq!(
+            Err(VaultError::UnsupportedDataError("HD Path purpose 84' is not supported for Ethereum".to_string())),
+            act
+        );
+
+        let act = vault.add_ethereum_entry(wallet_id).seed_hd(
+            seed_id,
+            StandardHDPath::from_str("m/49'/61'/0'/0/0").unwrap(),
+            Blockchain::EthereumClassic,
+            Some("test".to_string()),
+            None,
+        );
+        assert_eq!(
+            Err(VaultError::UnsupportedDataError("HD Path purpose 49' is not supported for EthereumClassic".to_string())),
+            act
+        );
+
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        assert!(wallet.entries.is_empty());
+    }
+
+    #[test]
+    fn uses_coin_type_of_blockchain() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "often impact pistol seminar park example foil urge bird balance reopen uphold enforce protect pear",
+        ).unwrap();
+        let seed_id = vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+            ..Seed::default()
+        }).unwrap();
+        let wallet_id = vault.wallets().add(Wallet::default()).unwrap();
+
+        let eth_id = vault.add_ethereum_entry(wallet_id).seed_account(
+            seed_id, 0, Blockchain::Ethereum, Some("test".to_string()), None,
+        ).unwrap();
+        let etc_id = vault.add_ethereum_entry(wallet_id).seed_account(
+            seed_id, 0, Blockchain::EthereumClassic, Some("test".to_string()), None,
+        ).unwrap();
+
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        let eth = wallet.get_entry(eth_id).unwrap();
+        let etc = wallet.get_entry(etc_id).unwrap();
+        assert_eq!(
+            PKType::SeedHd(SeedRef {
+                seed_id,
+                hd_path: StandardHDPath::from_str("m/44'/60'/0'/0/0").unwrap().into(),
+            }),
+            eth.key
+        );
+        assert_eq!(
+            PKType::SeedHd(SeedRef {
+                seed_id,
+                hd_path: StandardHDPath::from_str("m/44'/61'/0'/0/0").unwrap().into(),
+            }),
+            etc.key
+        );
+
+        assert_eq!(
+            Some(AddressRef::EthereumAddress(
+                EthereumAddress::from_str("0x54b6785921762808D36DB528bB1d446A91633205").unwrap()
+            )),
+            eth.address
+        );
+        let etc_exp = vault.seeds().get(seed_id).unwrap().source.get_addresses::<EthereumAddress>(
+            Some("test".to_string()),
+            &vec![StandardHDPath::from_str("m/44'/61'/0'/0/0").unwrap()],
+            Blockchain::EthereumClassic,
+        ).unwrap();
+        assert_eq!(Some(AddressRef::EthereumAddress(etc_exp[0].1)), etc.address);
+        assert_ne!(eth.address, etc.address);
+    }
+
+    #[test]
+    fn derives_on_etc_testnets() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "often impact pistol seminar park example foil urge bird balance reopen uphold enforce protect pear",
+        ).unwrap();
+        let seed_id = vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+            ..Seed::default()
+        }).unwrap();
+        let wallet_id = vault.wallets().add(Wallet::default()).unwrap();
+
+        let mordor_id = vault.add_ethereum_entry(wallet_id).seed_account(
+            seed_id, 0, Blockchain::MordorTestnet, Some("test".to_string()), None,
+        ).unwrap();
+        let kotti_id = vault.add_ethereum_entry(wallet_id).seed_account(
+            seed_id, 0, Blockchain::KottiTestnet, Some("test".to_string()), None,
+        ).unwrap();
+
+        let wallet = vault.wallets().get(wallet_id).unwrap();
+        let mordor = wallet.get_entry(mordor_id).unwrap();
+        let kotti = wallet.get_entry(kotti_id).unwrap();
+        assert_eq!(Blockchain::MordorTestnet, mordor.blockchain);
+        assert_eq!(Blockchain::KottiTestnet, kotti.blockchain);
+        for entry in &[&mordor, &kotti] {
+            assert_eq!(
+                PKType::SeedHd(SeedRef {
+                    seed_id,
+                    hd_path: StandardHDPath::from_str("m/44'/1'/0'/0/0").unwrap().into(),
+                }),
+                entry.key
+            );
+            assert_eq!(
+                Some(AddressRef::EthereumAddress(
+                    EthereumAddress::from_str("0xFE87a12E83bdEe35F062666469273F1eFcd0f7f3").unwrap()
+                )),
+                entry.address
+            );
+        }
+    }
 }
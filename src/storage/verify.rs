@@ -0,0 +1,306 @@
+//! # Integrity check of the vault
+//!
+//! Finds files that cannot be read and references between wallets, seeds and keys which are
+//! broken, usually after manual editing of the vault files or restoring from a partial backup.
+
+use crate::{
+    storage::{
+        error::VaultError,
+        vault::{SingleFileEntry, VaultAccess, VaultAccessByFile, VaultStorage},
+    },
+    structs::{
+        seed::Seed,
+        types::HasUuid,
+        wallet::{PKType, Wallet},
+    },
+};
+use std::{collections::HashSet, path::PathBuf, sync::Arc};
+use uuid::Uuid;
+
+/// A problem found by the integrity check
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyIssue {
+    /// File exists in the vault, but cannot be read or parsed
+    InvalidFile(PathBuf),
+    /// Wallet entry references a seed which doesn't exist in the vault
+    MissingSeed {
+        wallet_id: Uuid,
+        entry_id: usize,
+        seed_id: Uuid,
+    },
+    /// Wallet entry references a private key which doesn't exist in the vault
+    MissingKey {
+        wallet_id: Uuid,
+        entry_id: usize,
+        key_id: Uuid,
+    },
+    /// Wallet reserves a seed account which is not used by any of its entries
+    UnusedReserved {
+        wallet_id: Uuid,
+        seed_id: Uuid,
+        account_id: u32,
+    },
+    /// Private key is not referenced by any wallet entry
+    OrphanedKey(Uuid),
+}
+
+/// Result of the integrity check
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct VerifyReport {
+    pub issues: Vec<VerifyIssue>,
+}
+
+impl VerifyReport {
+    /// True if no problems were found
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Read all items of the storage, and report files that cannot be read
+fn read_all<P, S>(storage: &Arc<S>, report: &mut VerifyReport) -> Result<Vec<P>, VaultError>
+where
+    P: HasUuid + Ord,
+    S: VaultAccessByFile<P> + ?Sized,
+{
+    let mut result = Vec::new();
+    for id in storage.list()? {
+        match storage.get(id) {
+            Ok(item) => result.push(item),
+            Err(_) => report
+                .issues
+                .push(VerifyIssue::InvalidFile(storage.get_filename_for(id))),
+        }
+    }
+    Ok(result)
+}
+
+impl VaultStorage {
+    /// Check integrity of the vault. Every wallet, seed and private key must be readable, every
+    /// wallet entry must reference an existing seed or key, every reserved account must be used by
+    /// an entry, and every private key must be used by a wallet. Doesn't change anything in the
+    /// vault, and returns a report with all problems found
+    pub fn verify(&self) -> Result<VerifyReport, VaultError> {
+        let mut report = VerifyReport::default();
+
+        let wallets: Vec<Wallet> = read_all(&self.wallets(), &mut report)?;
+        let seeds: HashSet<Uuid> = read_all::<Seed, _>(&self.seeds(), &mut report)?
+            .iter()
+            .map(|s| s.get_id())
+            .collect();
+        let keys: HashSet<Uuid> = read_all(&self.keys(), &mut report)?
+            .iter()
+            .map(|k| k.get_id())
+            .collect();
+
+        let mut used_keys = HashSet::new();
+        for wallet in &wallets {
+            for entry in &wallet.entries {
+                match &entry.key {
+                    PKType::SeedHd(seed) => {
+                        if !seeds.contains(&seed.seed_id) {
+                            report.issues.push(VerifyIssue::MissingSeed {
+                                wallet_id: wallet.id,
+                                entry_id: entry.id,
+                                seed_id: seed.seed_id,
+                            });
+                        }
+                    }
+                    PKType::PrivateKeyRef(key_id) => {
+                        used_keys.insert(*key_id);
+                        if !keys.contains(key_id) {
+                            report.issues.push(VerifyIssue::MissingKey {
+                                wallet_id: wallet.id,
+                                entry_id: entry.id,
+                                key_id: *key_id,
+                            });
+                        }
+                    }
+                    PKType::Watch => {}
+                }
+            }
+            for reserved in &wallet.reserved {
+                let used = wallet.entries.iter().any(|e| match &e.key {
+                    PKType::SeedHd(seed) => {
+                        seed.seed_id == reserved.seed_id
+                            && seed.get_account_id() == Ok(reserved.account_id)
+                    }
+                    _ => false,
+                });
+                if !used {
+                    report.issues.push(VerifyIssue::UnusedReserved {
+                        wallet_id: wallet.id,
+                        seed_id: reserved.seed_id,
+                        account_id: reserved.account_id,
+                    });
+                }
+            }
+        }
+
+        let mut orphaned: Vec<&Uuid> = keys.difference(&used_keys).collect();
+        orphaned.sort();
+        orphaned
+            .into_iter()
+            .for_each(|id| report.issues.push(VerifyIssue::OrphanedKey(*id)));
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        chains::Blockchain,
+        mnemonic::{Language, Mnemonic},
+        structs::{
+            seed::SeedSource,
+            wallet::ReservedPath,
+        },
+    };
+    use hdpath::StandardHDPath;
+    use std::{fs, str::FromStr};
+    use tempdir::TempDir;
+
+    fn add_seed(vault: &VaultStorage) -> Uuid {
+        let phrase = Mnemonic::try_from(
+            Language::English,
+            "quote ivory blast onion below kangaroo tonight spread awkward decide farm gun exact wood brown",
+        ).unwrap();
+        vault.seeds().add(Seed {
+            source: SeedSource::create_bytes(phrase.seed(None), "test").unwrap(),
+            ..Seed::default()
+        }).unwrap()
+    }
+
+    #[test]
+    fn ok_for_consistent_vault() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = add_seed(&vault);
+        let wallet_id = vault.wallets().add(Wallet::default()).unwrap();
+        vault
+            .add_ethereum_entry(wallet_id)
+            .seed_hd(
+                seed_id,
+                StandardHDPath::from_str("m/44'/60'/0'/0/0").unwrap(),
+                Blockchain::Ethereum,
+                Some("test".to_string()),
+                None,
+            )
+            .unwrap();
+        vault
+            .add_ethereum_entry(wallet_id)
+            .raw_pk(
+                hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                    .unwrap(),
+                "test",
+                Blockchain::Ethereum,
+            )
+            .unwrap();
+
+        let report = vault.verify().unwrap();
+        assert!(report.is_ok(), "Issues: {:?}", report.issues);
+    }
+
+    #[test]
+    fn reports_missing_seed() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = add_seed(&vault);
+        let wallet_id = vault.wallets().add(Wallet::default()).unwrap();
+        let entry_id = vault
+            .add_ethereum_entry(wallet_id)
+            .seed_hd(
+                seed_id,
+                StandardHDPath::from_str("m/44'/60'/0'/0/0").unwrap(),
+                Blockchain::Ethereum,
+                Some("test".to_string()),
+                None,
+            )
+            .unwrap();
+        fs::remove_file(vault.seeds().get_filename_for(seed_id)).unwrap();
+
+        let report = vault.verify().unwrap();
+        assert!(!report.is_ok());
+        assert_eq!(
+            report.issues,
+            vec![VerifyIssue::MissingSeed {
+                wallet_id,
+                entry_id,
+                seed_id,
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_orphaned_keys() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let wallet_id = vault.wallets().add(Wallet::default()).unwrap();
+        let entry_id = vault
+            .add_ethereum_entry(wallet_id)
+            .raw_pk(
+                hex::decode("fac192ceb5fd772906bea3e118a69e8bbb5cc24229e20d8766fd298291bba6bd")
+                    .unwrap(),
+                "test",
+                Blockchain::Ethereum,
+            )
+            .unwrap();
+        let key_id = match vault.wallets().get(wallet_id).unwrap().entries[0].key {
+            PKType::PrivateKeyRef(id) => id,
+            _ => panic!("Not a key"),
+        };
+        // replace reference to the key with a missing one
+        let missing_id = Uuid::new_v4();
+        let mut wallet = vault.wallets().get(wallet_id).unwrap();
+        wallet.entries[0].key = PKType::PrivateKeyRef(missing_id);
+        vault.wallets().update(wallet).unwrap();
+
+        let report = vault.verify().unwrap();
+        assert_eq!(
+            report.issues,
+            vec![
+                VerifyIssue::MissingKey {
+                    wallet_id,
+                    entry_id,
+                    key_id: missing_id,
+                },
+                VerifyIssue::OrphanedKey(key_id),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_unused_reserved() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = add_seed(&vault);
+        let wallet_id = vault.wallets().add(Wallet {
+            reserved: vec![ReservedPath { seed_id, account_id: 2 }],
+            ..Wallet::default()
+        }).unwrap();
+
+        let report = vault.verify().unwrap();
+        assert_eq!(
+            report.issues,
+            vec![VerifyIssue::UnusedReserved {
+                wallet_id,
+                seed_id,
+                account_id: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_file() {
+        let tmp_dir = TempDir::new("emerald-vault-test").expect("Dir not created");
+        let vault = VaultStorage::create(tmp_dir.path()).unwrap();
+        let seed_id = Uuid::new_v4();
+        let seed_file = vault.seeds().get_filename_for(seed_id);
+        fs::write(&seed_file, "not a seed").unwrap();
+
+        let report = vault.verify().unwrap();
+        assert_eq!(report.issues, vec![VerifyIssue::InvalidFile(seed_file)]);
+    }
+}
@@ -1,9 +1,14 @@
 use crate::{
-    blockchain::{bitcoin::XPub, chains::Blockchain},
+    blockchain::{
+        bitcoin::XPub,
+        chains::{Blockchain, BlockchainType},
+    },
+    storage::error::VaultError,
     EthereumAddress,
 };
 use chrono::{DateTime, Utc};
 use bitcoin::Address as BitcoinAddress;
+use std::str::FromStr;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct BookmarkDetails {
@@ -21,3 +26,75 @@ pub enum AddressRef {
     ExtendedPub(XPub),
     BitcoinAddress(BitcoinAddress)
 }
+
+impl AddressRef {
+    /// Parse an address for the blockchain. Accepts a hex address for Ethereum, and an address or
+    /// an xpub for Bitcoin. Fails if the address is invalid or belongs to another network
+    pub fn parse(blockchain: Blockchain, value: &str) -> Result<AddressRef, VaultError> {
+        let invalid = || VaultError::InvalidDataError(format!("Invalid address for {:?}", blockchain));
+        let value = value.trim();
+        let address = match blockchain.get_type() {
+            BlockchainType::Ethereum => {
+                AddressRef::EthereumAddress(EthereumAddress::from_str(value).map_err(|_| invalid())?)
+            }
+            BlockchainType::Bitcoin => match XPub::from_str(value) {
+                Ok(xpub) => AddressRef::ExtendedPub(xpub),
+                Err(_) => AddressRef::BitcoinAddress(BitcoinAddress::from_str(value).map_err(|_| invalid())?),
+            },
+        };
+        if !address.is_valid_for(blockchain) {
+            return Err(invalid());
+        }
+        Ok(address)
+    }
+
+    /// Check if the address can be used on the blockchain
+    pub fn is_valid_for(&self, blockchain: Blockchain) -> bool {
+        match self {
+            AddressRef::EthereumAddress(_) => blockchain.get_type() == BlockchainType::Ethereum,
+            AddressRef::ExtendedPub(xpub) => {
+                blockchain.get_type() == BlockchainType::Bitcoin
+                    && xpub.value.network == blockchain.as_bitcoin_network()
+            }
+            AddressRef::BitcoinAddress(address) => {
+                blockchain.get_type() == BlockchainType::Bitcoin
+                    && address.network == blockchain.as_bitcoin_network()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ethereum_address() {
+        let act = AddressRef::parse(Blockchain::EthereumClassic, "0x085fb4f24031eaedbc2b611aa528f22343eb52db");
+        assert_eq!(
+            Ok(AddressRef::EthereumAddress(EthereumAddress::from_str("0x085fb4f24031eaedbc2b611aa528f22343eb52db").unwrap())),
+            act
+        );
+        assert!(AddressRef::parse(Blockchain::Ethereum, "0x085fb4f24031eaedbc2b611aa528f22343eb52").is_err());
+        assert!(AddressRef::parse(Blockchain::Ethereum, "bc1qtjdjzmu30f32u8swgu3r7tf9u03t72r8pevmaw").is_err());
+    }
+
+    #[test]
+    fn parse_bitcoin_address() {
+        let act = AddressRef::parse(Blockchain::Bitcoin, "bc1qtjdjzmu30f32u8swgu3r7tf9u03t72r8pevmaw");
+        assert_eq!(
+            Ok(AddressRef::BitcoinAddress(BitcoinAddress::from_str("bc1qtjdjzmu30f32u8swgu3r7tf9u03t72r8pevmaw").unwrap())),
+            act
+        );
+        let act = AddressRef::parse(Blockchain::Bitcoin, "zpub6rebv42D4si3ibWtrRoeS3qvEaRWBuLfwq1SXZt6UMVU9CH8snBWeFFMSMvWsv5WFGVRhqr8gg2AR751SrKteeX9bq57HbTyQvqPznSpZex");
+        match act {
+            Ok(AddressRef::ExtendedPub(_)) => {}
+            _ => panic!("not xpub"),
+        }
+
+        // mainnet address on testnet
+        assert!(AddressRef::parse(Blockchain::BitcoinTestnet, "bc1qtjdjzmu30f32u8swgu3r7tf9u03t72r8pevmaw").is_err());
+        assert!(AddressRef::parse(Blockchain::Bitcoin, "0x085fb4f24031eaedbc2b611aa528f22343eb52db").is_err());
+        assert!(AddressRef::parse(Blockchain::Bitcoin, "bc1qtjdjzmu30f32u8swgu3r7tf9u03t72r8pevma").is_err());
+    }
+}
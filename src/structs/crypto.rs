@@ -38,6 +38,7 @@ pub struct Encrypted {
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Cipher {
     Aes128Ctr(Aes128CtrCipher),
+    Aes256Gcm(Aes256GcmCipher),
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -47,29 +48,51 @@ pub struct Aes128CtrCipher {
     pub mac: MacType,
 }
 
+/// AES-256 in GCM mode, which is authenticated by itself and doesn't need a separate MAC
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Aes256GcmCipher {
+    /// encrypted message followed by the authentication tag
+    pub encrypted: Vec<u8>,
+    /// 96 bit nonce
+    pub iv: Vec<u8>,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum MacType {
     Web3(Vec<u8>),
 }
 
+/// Returned as MAC for a cipher which doesn't have a separate MAC
+static NO_MAC: Vec<u8> = Vec::new();
+
 impl Encrypted {
+    /// MAC of the encrypted message. It's empty if the cipher authenticates the message by itself
+    /// (i.e. AES-256-GCM), use `get_separate_mac` to distinguish such cipher
     pub fn get_mac(&self) -> &Vec<u8> {
+        self.get_separate_mac().unwrap_or(&NO_MAC)
+    }
+
+    /// MAC of the encrypted message, or None if the cipher authenticates it by itself
+    pub fn get_separate_mac(&self) -> Option<&Vec<u8>> {
         match &self.cipher {
             Cipher::Aes128Ctr(v) => match &v.mac {
-                MacType::Web3(x) => x,
+                MacType::Web3(x) => Some(x),
             },
+            Cipher::Aes256Gcm(_) => None,
         }
     }
 
     pub fn get_iv(&self) -> &Vec<u8> {
         match &self.cipher {
             Cipher::Aes128Ctr(v) => &v.iv,
+            Cipher::Aes256Gcm(v) => &v.iv,
         }
     }
 
     pub fn get_message(&self) -> &Vec<u8> {
         match &self.cipher {
             Cipher::Aes128Ctr(v) => &v.encrypted,
+            Cipher::Aes256Gcm(v) => &v.encrypted,
         }
     }
 }
@@ -3,8 +3,9 @@ use crate::{
     structs::{crypto::Encrypted, types::HasUuid},
     EthereumAddress,
 };
+use bitcoin::util::bip32::Fingerprint;
 use chrono::{DateTime, Utc};
-use hdpath::StandardHDPath;
+use hdpath::{AccountHDPath, StandardHDPath};
 use sha2::Digest;
 use std::convert::TryFrom;
 use uuid::Uuid;
@@ -20,6 +21,16 @@ pub struct Seed {
     pub label: Option<String>,
     ///creation date of the seed
     pub created_at: DateTime<Utc>,
+    ///fingerprint of the seed bytes, if known
+    pub fingerprint: Option<SeedFingerprint>,
+}
+
+/// Salted hash of the seed master public key. It's stored unencrypted, so a duplicate of the seed
+/// can be found without asking for the password
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SeedFingerprint {
+    pub salt: [u8; 16],
+    pub hash: Bytes256,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -31,6 +42,12 @@ pub enum SeedSource {
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LedgerSource {
     pub fingerprints: Vec<HDPathFingerprint>,
+    /// Model of the Ledger device used with the seed (ex. `Nano X`), if known.
+    /// Ledger doesn't expose a serial number, so the model is the only device detail stored
+    pub device_model: Option<String>,
+    /// BIP32 fingerprint of the master public key of the device, if it was available when the
+    /// seed was created
+    pub master_fingerprint: Option<Fingerprint>,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -47,11 +64,66 @@ pub enum FingerprintType {
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct SeedRef {
     pub seed_id: Uuid,
-    pub hd_path: StandardHDPath,
+    pub hd_path: SeedPath,
+}
+
+/// HD Path used by an entry on a seed
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SeedPath {
+    /// Full path to a single address, i.e. `m/purpose'/coin_type'/account'/change/address_index`.
+    /// Used by Ethereum entries
+    Address(StandardHDPath),
+    /// Account path, i.e. `m/purpose'/coin_type'/account'`. The address path is resolved when the
+    /// entry is used. Used by Bitcoin entries
+    Account(AccountHDPath),
+}
+
+impl From<StandardHDPath> for SeedPath {
+    fn from(value: StandardHDPath) -> Self {
+        SeedPath::Address(value)
+    }
+}
+
+impl From<AccountHDPath> for SeedPath {
+    fn from(value: AccountHDPath) -> Self {
+        SeedPath::Account(value)
+    }
+}
+
+impl SeedPath {
+    /// Account part of the path
+    pub fn account_hd_path(&self) -> AccountHDPath {
+        match self {
+            SeedPath::Address(hd_path) => AccountHDPath::from(hd_path),
+            SeedPath::Account(account) => account.clone(),
+        }
+    }
+
+    /// Full path of the address. For an account it's the address at `change/index` on the account,
+    /// for a single address it's always the path of that address
+    pub fn address_at(&self, change: u32, index: u32) -> Result<StandardHDPath, ()> {
+        match self {
+            SeedPath::Address(hd_path) => Ok(hd_path.clone()),
+            SeedPath::Account(account) => account.address_at(change, index).map_err(|_| ()),
+        }
+    }
+
+    /// Full path of the address, if it references a single address
+    pub fn as_address(&self) -> Option<&StandardHDPath> {
+        match self {
+            SeedPath::Address(hd_path) => Some(hd_path),
+            SeedPath::Account(_) => None,
+        }
+    }
 }
 
 impl HDPathFingerprint {
     pub fn from_address(hd_path: StandardHDPath, address: &EthereumAddress) -> HDPathFingerprint {
+        HDPathFingerprint::from_bytes(hd_path, address.as_ref())
+    }
+
+    /// Fingerprint for an address represented as bytes (ex. bitcoin script pubkey)
+    pub fn from_bytes(hd_path: StandardHDPath, address: &[u8]) -> HDPathFingerprint {
         let hash = sha2::Sha256::digest(address);
         let f = Bytes256::try_from(hash.as_slice()).unwrap();
         HDPathFingerprint {
@@ -79,7 +151,7 @@ impl SeedRef {
     /// To do so the HDPath must be valid and starts with 3 hardened values (purpose'/coin_type'/account'),
     /// otherwise the method returns Err
     pub fn get_account_id(&self) -> Result<u32, ()> {
-        Ok(self.hd_path.account())
+        Ok(self.hd_path.account_hd_path().account())
     }
 }
 
@@ -92,6 +164,7 @@ impl Default for Seed {
             ),
             label: None,
             created_at: Utc::now(),
+            fingerprint: None,
         }
     }
 }
@@ -100,40 +173,73 @@ ord_by_date_id!(Seed);
 
 impl Default for LedgerSource {
     fn default() -> Self {
-        LedgerSource { fingerprints: vec![] }
+        LedgerSource {
+            fingerprints: vec![],
+            device_model: None,
+            master_fingerprint: None,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::structs::seed::SeedRef;
-    use hdpath::StandardHDPath;
-    use std::convert::TryFrom;
+    use crate::structs::seed::{SeedPath, SeedRef};
+    use hdpath::{AccountHDPath, StandardHDPath};
+    use std::{convert::TryFrom, str::FromStr};
 
     #[test]
     fn account_id_for_standard_hdpath() {
         let seed = SeedRef {
             seed_id: Default::default(),
-            hd_path: StandardHDPath::try_from("m/44'/0'/0'/0/0").unwrap(),
+            hd_path: StandardHDPath::try_from("m/44'/0'/0'/0/0").unwrap().into(),
         };
         assert_eq!(Ok(0), seed.get_account_id());
 
         let seed = SeedRef {
             seed_id: Default::default(),
-            hd_path: StandardHDPath::try_from("m/44'/60'/0'/0/0").unwrap(),
+            hd_path: StandardHDPath::try_from("m/44'/60'/0'/0/0").unwrap().into(),
         };
         assert_eq!(Ok(0), seed.get_account_id());
 
         let seed = SeedRef {
             seed_id: Default::default(),
-            hd_path: StandardHDPath::try_from("m/44'/60'/3'/0/0").unwrap(),
+            hd_path: StandardHDPath::try_from("m/44'/60'/3'/0/0").unwrap().into(),
         };
         assert_eq!(Ok(3), seed.get_account_id());
 
         let seed = SeedRef {
             seed_id: Default::default(),
-            hd_path: StandardHDPath::try_from("m/44'/0'/1234'/0/0").unwrap(),
+            hd_path: StandardHDPath::try_from("m/44'/0'/1234'/0/0").unwrap().into(),
         };
         assert_eq!(Ok(1234), seed.get_account_id());
     }
+
+    #[test]
+    fn account_id_for_account_hdpath() {
+        let seed = SeedRef {
+            seed_id: Default::default(),
+            hd_path: AccountHDPath::from_str("m/84'/0'/7'").unwrap().into(),
+        };
+        assert_eq!(Ok(7), seed.get_account_id());
+    }
+
+    #[test]
+    fn resolve_address_on_account() {
+        let account = SeedPath::Account(AccountHDPath::from_str("m/84'/0'/7'").unwrap());
+        assert_eq!(
+            Ok(StandardHDPath::from_str("m/84'/0'/7'/1/15").unwrap()),
+            account.address_at(1, 15)
+        );
+        assert_eq!(None, account.as_address());
+
+        let address = SeedPath::Address(StandardHDPath::from_str("m/44'/60'/0'/0/3").unwrap());
+        assert_eq!(
+            Ok(StandardHDPath::from_str("m/44'/60'/0'/0/3").unwrap()),
+            address.address_at(1, 15)
+        );
+        assert_eq!(
+            AccountHDPath::from_str("m/44'/60'/0'").unwrap(),
+            address.account_hd_path()
+        );
+    }
 }
@@ -1,23 +1,76 @@
 use chrono::{DateTime, TimeZone, Utc};
+use std::cmp::Ordering;
+use uuid::Uuid;
 
 lazy_static! {
     pub static ref ZERO_TS: DateTime<Utc> = Utc.timestamp_millis(100);
 }
 
+/// Compare items by creation date, and by id if the date is the same. Items without a date
+/// (i.e. created before the date was stored) go first
+pub fn cmp_by_date_id(
+    created_at: &DateTime<Utc>,
+    id: &Uuid,
+    other_created_at: &DateTime<Utc>,
+    other_id: &Uuid,
+) -> Ordering {
+    let zero = &*ZERO_TS;
+    if created_at.eq(zero) && other_created_at.ne(zero) {
+        Ordering::Less
+    } else if created_at.ne(zero) && other_created_at.eq(zero) {
+        Ordering::Greater
+    } else if created_at.eq(other_created_at) {
+        id.cmp(other_id)
+    } else {
+        created_at.cmp(other_created_at)
+    }
+}
+
 #[macro_export]
 macro_rules! ord_by_date_id {
     ($name:ident) => {
         impl std::cmp::Ord for $name {
             fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-                let zero = &crate::structs::utils::ZERO_TS;
-                if self.created_at.eq(zero) && other.created_at.ne(zero) {
-                    std::cmp::Ordering::Less
-                } else if self.created_at.ne(zero) && other.created_at.eq(zero) {
-                    std::cmp::Ordering::Greater
-                } else if self.created_at.eq(&other.created_at) {
-                    self.id.cmp(&other.id)
-                } else {
-                    self.created_at.cmp(&other.created_at)
+                crate::structs::utils::cmp_by_date_id(
+                    &self.created_at,
+                    &self.id,
+                    &other.created_at,
+                    &other.id,
+                )
+            }
+        }
+
+        impl std::cmp::PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    };
+}
+
+/// Same as `ord_by_date_id`, but items with a non-zero position in the specified field go first,
+/// ordered by that position
+#[macro_export]
+macro_rules! ord_by_position_date_id {
+    ($name:ident, $position:ident) => {
+        impl std::cmp::Ord for $name {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                match (self.$position, other.$position) {
+                    (0, 0) => crate::structs::utils::cmp_by_date_id(
+                        &self.created_at,
+                        &self.id,
+                        &other.created_at,
+                        &other.id,
+                    ),
+                    (0, _) => std::cmp::Ordering::Greater,
+                    (_, 0) => std::cmp::Ordering::Less,
+                    (a, b) if a != b => a.cmp(&b),
+                    _ => crate::structs::utils::cmp_by_date_id(
+                        &self.created_at,
+                        &self.id,
+                        &other.created_at,
+                        &other.id,
+                    ),
                 }
             }
         }
@@ -89,4 +142,37 @@ mod tests {
         assert_eq!(value1.cmp(&value2), Ordering::Less);
         assert_eq!(value2.cmp(&value1), Ordering::Greater);
     }
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct TestPositioned {
+        pub id: Uuid,
+        pub created_at: DateTime<Utc>,
+        pub position: u32,
+    }
+
+    ord_by_position_date_id!(TestPositioned, position);
+
+    #[test]
+    fn ord_by_position_first() {
+        let value1 = TestPositioned {
+            id: Default::default(),
+            created_at: Utc.timestamp_millis(200),
+            position: 1,
+        };
+        let value2 = TestPositioned {
+            id: Default::default(),
+            created_at: Utc.timestamp_millis(100),
+            position: 2,
+        };
+        let value3 = TestPositioned {
+            id: Default::default(),
+            created_at: Utc.timestamp_millis(0),
+            position: 0,
+        };
+
+        assert_eq!(value1.cmp(&value2), Ordering::Less);
+        assert_eq!(value2.cmp(&value1), Ordering::Greater);
+        assert_eq!(value2.cmp(&value3), Ordering::Less);
+        assert_eq!(value3.cmp(&value1), Ordering::Greater);
+    }
 }
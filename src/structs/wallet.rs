@@ -8,11 +8,11 @@ use crate::{
         types::HasUuid,
     },
 };
-use bitcoin::util::bip32::ExtendedPubKey;
+use bitcoin::{util::bip32::ExtendedPubKey, Address};
 use chrono::{DateTime, Utc};
 use hdpath::{StandardHDPath, AccountHDPath};
 use regex::Regex;
-use std::{convert::TryFrom, str::FromStr};
+use std::{collections::HashMap, convert::TryFrom, str::FromStr};
 use uuid::Uuid;
 use num::range;
 use crate::blockchain::addresses::{AddressFromPub, AddressCast};
@@ -26,6 +26,9 @@ pub struct Wallet {
     pub reserved: Vec<ReservedPath>,
     ///creation date of the wallet
     pub created_at: DateTime<Utc>,
+    ///Position of the wallet in the list, as set by the user. 0 if not set, such wallets go after
+    ///the ordered ones
+    pub order: u32,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -63,12 +66,17 @@ pub struct WalletEntry {
     pub receive_disabled: bool,
     ///Creation date of the entry
     pub created_at: DateTime<Utc>,
+    ///User assigned labels of individual addresses derived by the entry, by address index
+    pub labels: HashMap<u32, String>,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum PKType {
     PrivateKeyRef(Uuid),
     SeedHd(SeedRef),
+    /// Watch-only entry, without access to a private key. Addresses are derived from the public
+    /// key stored as the entry address
+    Watch,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -84,6 +92,27 @@ pub enum AddressRole {
     Default
 }
 
+/// Short description of a wallet, without any key material
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WalletSummary {
+    pub id: Uuid,
+    pub label: Option<String>,
+    pub entries: Vec<WalletEntrySummary>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WalletEntrySummary {
+    pub id: usize,
+    pub blockchain: Blockchain,
+    pub address: Option<AddressRef>,
+}
+
+impl WalletSummary {
+    pub fn entries_count(&self) -> usize {
+        self.entries.len()
+    }
+}
+
 impl HasUuid for Wallet {
     fn get_id(&self) -> Uuid {
         self.id
@@ -112,6 +141,34 @@ impl Wallet {
             value
         }
     }
+
+    /// Summary of the wallet and its entries
+    pub fn summary(&self) -> WalletSummary {
+        WalletSummary {
+            id: self.id,
+            label: self.label.clone(),
+            entries: self
+                .entries
+                .iter()
+                .map(|e| WalletEntrySummary {
+                    id: e.id,
+                    blockchain: e.blockchain,
+                    address: e.address.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Reserve an account on the seed for the wallet, if it's not reserved yet.
+    /// Returns true if a new reservation was added
+    pub fn reserve(&mut self, seed_id: Uuid, account_id: u32) -> bool {
+        let r = ReservedPath { seed_id, account_id };
+        if self.reserved.contains(&r) {
+            return false;
+        }
+        self.reserved.push(r);
+        true
+    }
 }
 
 impl Default for Wallet {
@@ -123,11 +180,12 @@ impl Default for Wallet {
             entry_seq: 0,
             reserved: vec![],
             created_at: Utc::now(),
+            order: 0,
         }
     }
 }
 
-ord_by_date_id!(Wallet);
+ord_by_position_date_id!(Wallet, order);
 
 impl Default for WalletEntry {
     fn default() -> Self {
@@ -139,6 +197,7 @@ impl Default for WalletEntry {
             receive_disabled: false,
             label: None,
             created_at: Utc::now(),
+            labels: HashMap::new(),
         }
     }
 }
@@ -213,17 +272,59 @@ impl WalletEntry {
                     SeedSource::Bytes(_) => Ok(false),
                 }
             }
-            PKType::PrivateKeyRef(_) => Ok(false),
+            PKType::PrivateKeyRef(_) | PKType::Watch => Ok(false),
+        }
+    }
+
+    /// Label of the address with the specified index, if it's set
+    pub fn get_address_label(&self, index: u32) -> Option<&str> {
+        self.labels.get(&index).map(|l| l.as_str())
+    }
+
+    /// Set label of the address with the specified index. `None` or an empty label removes it
+    pub fn set_address_label(&mut self, index: u32, label: Option<String>) {
+        match label {
+            Some(label) if !label.is_empty() => {
+                self.labels.insert(index, label);
+            }
+            _ => {
+                self.labels.remove(&index);
+            }
         }
     }
 
     pub fn account_hd(&self) -> Option<AccountHDPath> {
         match &self.key {
-            PKType::SeedHd(seed) => Some(AccountHDPath::from(&seed.hd_path)),
-            PKType::PrivateKeyRef(_) => None
+            PKType::SeedHd(seed) => Some(seed.hd_path.account_hd_path()),
+            PKType::PrivateKeyRef(_) | PKType::Watch => None
         }
     }
 
+    /// Derive addresses of a Bitcoin entry from its account-level xpub, as pairs of address index
+    /// and the address encoded according to the address type of the xpub (ex. bech32 for P2WPKH)
+    ///
+    /// # Arguments
+    ///
+    /// * `change` - true to derive change addresses (`.../1/index`), otherwise receive addresses (`.../0/index`)
+    /// * `from` - first address index
+    /// * `count` - number of addresses to derive
+    pub fn addresses(&self, change: bool, from: u32, count: u32) -> Result<Vec<(u32, String)>, VaultError> {
+        match &self.address {
+            Some(AddressRef::ExtendedPub(xpub)) if xpub.is_account() => {}
+            _ => return Err(VaultError::PublicKeyUnavailable)
+        };
+        let role = if change { AddressRole::Change } else { AddressRole::Receive };
+        let count = from.saturating_add(count) - from;
+        let addresses = self.get_addresses::<Address>(role, from, count)?;
+        // addresses are in the order of indexes, and only hardened indexes, i.e. the tail of
+        // the range, may be skipped
+        Ok(addresses
+            .into_iter()
+            .zip(from..)
+            .map(|(a, n)| (n, a.address.to_string()))
+            .collect())
+    }
+
     pub fn get_addresses<T>(&self, role: AddressRole, start: u32, limit: u32) -> Result<Vec<EntryAddress<T>>, VaultError>
         where T: AddressFromPub<T> + AddressCast<T> {
         if limit == 0 {
@@ -308,7 +409,7 @@ mod tests {
         EthereumTransaction,
     };
     use chrono::Utc;
-    use hdpath::StandardHDPath;
+    use hdpath::{AccountHDPath, StandardHDPath};
     use std::{convert::TryFrom, str::FromStr};
     use tempdir::TempDir;
     use uuid::Uuid;
@@ -317,6 +418,18 @@ mod tests {
     use bitcoin::Address;
     use crate::structs::book::AddressRef;
     use crate::convert::error::ConversionError;
+    use crate::storage::error::VaultError;
+
+    #[test]
+    fn reserve_account_once() {
+        let mut wallet = Wallet::default();
+        let seed_id = Uuid::new_v4();
+        assert!(wallet.reserve(seed_id, 3));
+        assert!(!wallet.reserve(seed_id, 3));
+        assert!(wallet.reserve(seed_id, 4));
+        assert!(wallet.reserve(Uuid::new_v4(), 3));
+        assert_eq!(3, wallet.reserved.len());
+    }
 
     #[test]
     fn encode_decode_role() {
@@ -350,9 +463,12 @@ mod tests {
             id: Uuid::new_v4(),
             source: SeedSource::Ledger(LedgerSource {
                 fingerprints: vec![],
+                device_model: None,
+                master_fingerprint: None,
             }),
             label: None,
             created_at: Utc::now(),
+            fingerprint: None,
         };
         let seed_id = vault.seeds().add(seed).unwrap();
 
@@ -362,7 +478,7 @@ mod tests {
             address: None,
             key: PKType::SeedHd(SeedRef {
                 seed_id,
-                hd_path: StandardHDPath::try_from("m/44'/60'/160720'/0/0").unwrap(),
+                hd_path: StandardHDPath::try_from("m/44'/60'/160720'/0/0").unwrap().into(),
             }),
             ..WalletEntry::default()
         };
@@ -383,7 +499,7 @@ mod tests {
             _ => panic!("Not Seed HDPath"),
         };
         assert_eq!(
-            seed_ref.hd_path.to_string(),
+            seed_ref.hd_path.as_address().unwrap().to_string(),
             "m/44'/60'/160720'/0/0".to_string()
         );
         assert_eq!(seed_ref.seed_id, seed_id);
@@ -417,7 +533,7 @@ mod tests {
             )),
             key: PKType::SeedHd(SeedRef {
                 seed_id: Uuid::new_v4(),
-                hd_path: StandardHDPath::from_str("m/84'/0'/4'/0/0").unwrap(),
+                hd_path: AccountHDPath::from_str("m/84'/0'/4'").unwrap().into(),
             }),
             ..Default::default()
         };
@@ -468,6 +584,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_xpub_addresses_bitcoin() {
+        let entry = WalletEntry {
+            blockchain: Blockchain::Bitcoin,
+            address: Some(AddressRef::ExtendedPub(
+                XPub::from_str("zpub6rebv42D4si3ibWtrRoeS3qvEaRWBuLfwq1SXZt6UMVU9CH8snBWeFFMSMvWsv5WFGVRhqr8gg2AR751SrKteeX9bq57HbTyQvqPznSpZex").unwrap()
+            )),
+            ..Default::default()
+        };
+
+        let act = entry.addresses(false, 3, 2).unwrap();
+        assert_eq!(
+            vec![
+                (3, "bc1q4zxhcd25qqpxrdrf6d3p0qtg3vcjavajujw8rd".to_string()),
+                (4, "bc1qzzve7js08mhsewg2jy6kkkj7fs298k9kz2snhs".to_string()),
+            ],
+            act
+        );
+
+        let act = entry.addresses(true, 0, 1).unwrap();
+        assert_eq!(
+            vec![(0, "bc1q07937xm8m57yg9kq5u5569ajcvzgptlr42g8za".to_string())],
+            act
+        );
+
+        assert_eq!(Ok(vec![]), entry.addresses(false, 0, 0));
+    }
+
+    #[test]
+    fn list_xpub_addresses_bitcoin_testnet() {
+        let entry = WalletEntry {
+            blockchain: Blockchain::BitcoinTestnet,
+            address: Some(AddressRef::ExtendedPub(
+                // seed: dream frog grape this park hungry quarter elbow fluid acid rack knee brown anxiety jewel
+                XPub::from_str("vpub5ZTSU3hrtSgLtVuBKQrKbKw76TJu8Ndy2apQ138CyiEhVmYpjEGbKFGsrv4Yu1jUNA4pDRJWvctdFvRNvsCVrJfVBHHpnygzAXd71f5pdUC").unwrap()
+            )),
+            ..Default::default()
+        };
+
+        let act = entry.addresses(false, 0, 5).unwrap();
+        assert_eq!(5, act.len());
+        assert_eq!(
+            (2, "tb1q7v6nnp057hdlwtu6uzqedd43q9zqc5w82sar5w".to_string()),
+            act[2]
+        );
+
+        let act = entry.addresses(true, 7, 1).unwrap();
+        assert_eq!(
+            vec![(7, "tb1q2p4yhftnwe4ft0nztadeqtn9wzequpwzv3puz0".to_string())],
+            act
+        );
+    }
+
+    #[test]
+    fn no_xpub_addresses_for_ethereum() {
+        let entry = WalletEntry {
+            blockchain: Blockchain::Ethereum,
+            address: Some(AddressRef::EthereumAddress(
+                EthereumAddress::from_str("0x7Bd9D156C6624b4D9a429cf81b91a9B500bDE2C7").unwrap()
+            )),
+            ..Default::default()
+        };
+        assert_eq!(Err(VaultError::PublicKeyUnavailable), entry.addresses(false, 0, 1));
+    }
+
     #[test]
     fn get_std_addresses_ethereum() {
         let entry = WalletEntry {
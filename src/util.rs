@@ -14,10 +14,11 @@ WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 See the License for the specific language governing permissions and
 limitations under the License.
 */
-pub use crate::crypto::util::{keccak256, KECCAK256_BYTES};
+pub use crate::crypto::util::{keccak256, keccak256_hex, KECCAK256_BYTES};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use chrono::prelude::Utc;
 use hex::FromHex;
+use num::bigint::BigUint;
 use std::io::Cursor;
 
 pub fn none_if_empty(s: &str) -> Option<String> {
@@ -28,6 +29,22 @@ pub fn none_if_empty(s: &str) -> Option<String> {
     }
 }
 
+/// Compare two byte slices in constant time, i.e. the time doesn't depend on position of the
+/// first different byte. Should be used to compare secrets, such as MAC
+///
+/// # Arguments
+///
+/// * `a` - first value
+/// * `b` - second value
+///
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    diff == 0
+}
+
 /// Convert byte array into `u64`
 ///
 /// # Arguments
@@ -122,6 +139,72 @@ pub fn trim_bytes(data: &[u8]) -> &[u8] {
     &data[n..data.len()]
 }
 
+/// Encode a number as JSON RPC `QUANTITY`, i.e. 0x-prefixed hex without leading zeroes
+/// (ex. `0x0`, `0x400`)
+///
+/// # Arguments
+///
+/// * `x` - value to encode
+///
+pub fn encode_quantity(x: u64) -> String {
+    format!("0x{:x}", x)
+}
+
+/// Encode a big-endian unsigned number (ex. a 32 bytes value) as JSON RPC `QUANTITY`
+///
+/// # Arguments
+///
+/// * `data` - big-endian value to encode
+///
+pub fn encode_quantity_bytes(data: &[u8]) -> String {
+    let value = hex::encode(trim_bytes(data));
+    let value = value.trim_start_matches('0');
+    if value.is_empty() {
+        "0x0".to_string()
+    } else {
+        format!("0x{}", value)
+    }
+}
+
+/// Encode bytes as JSON RPC `DATA`, i.e. 0x-prefixed hex with two hex digits per byte
+///
+/// # Arguments
+///
+/// * `data` - bytes to encode
+///
+pub fn encode_data(data: &[u8]) -> String {
+    format!("0x{}", hex::encode(data))
+}
+
+/// Format an amount in the smallest units (ex. Wei) as a decimal value of the main unit
+/// (ex. Ether), without trailing zeroes in the fractional part. I.e. `1000000000000000000` Wei
+/// with 18 decimals is `1`, and `1500000` with 6 decimals is `1.5`
+///
+/// # Arguments
+///
+/// * `wei` - amount in the smallest units
+/// * `decimals` - number of decimals of the main unit (18 for Ether)
+///
+pub fn wei_to_ether(wei: &BigUint, decimals: u32) -> String {
+    let value = wei.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return value;
+    }
+    let value = if value.len() <= decimals {
+        format!("{}{}", "0".repeat(decimals - value.len() + 1), value)
+    } else {
+        value
+    };
+    let (int_part, fraction) = value.split_at(value.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        int_part.to_string()
+    } else {
+        format!("{}.{}", int_part, fraction)
+    }
+}
+
 /// Counts bytes required to hold `x` value
 ///
 /// # Arguments
@@ -355,4 +438,66 @@ mod tests {
 
         assert!(re.is_match(&timestamp()));
     }
+
+    #[test]
+    fn should_compare_in_constant_time() {
+        assert!(constant_time_eq(&[], &[]));
+        assert!(constant_time_eq(&[1, 2, 3], &[1, 2, 3]));
+        assert!(!constant_time_eq(&[1, 2, 3], &[1, 2, 4]));
+        assert!(!constant_time_eq(&[1, 2, 3], &[0, 2, 3]));
+        assert!(!constant_time_eq(&[1, 2, 3], &[1, 2]));
+        assert!(!constant_time_eq(&[0xff; 32], &[0x00; 32]));
+    }
+
+    #[test]
+    fn should_encode_quantity() {
+        assert_eq!(encode_quantity(0), "0x0");
+        assert_eq!(encode_quantity(1), "0x1");
+        assert_eq!(encode_quantity(1024), "0x400");
+        assert_eq!(encode_quantity(0xffffffffffffffff), "0xffffffffffffffff");
+    }
+
+    #[test]
+    fn should_encode_quantity_bytes() {
+        assert_eq!(encode_quantity_bytes(&[0u8; 32]), "0x0");
+        assert_eq!(encode_quantity_bytes(&[]), "0x0");
+        assert_eq!(encode_quantity_bytes(&[0x00, 0x01]), "0x1");
+        assert_eq!(encode_quantity_bytes(&[0x04, 0x00]), "0x400");
+        assert_eq!(
+            encode_quantity_bytes(&to_32bytes("0de0b6b3a7640000")),
+            "0xde0b6b3a7640000"
+        );
+    }
+
+    #[test]
+    fn should_encode_data() {
+        assert_eq!(encode_data(&[]), "0x");
+        assert_eq!(encode_data(&[0]), "0x00");
+        assert_eq!(encode_data(&[0x04, 0x00]), "0x0400");
+    }
+
+    #[test]
+    fn should_format_wei_as_ether() {
+        assert_eq!(wei_to_ether(&BigUint::from(0u32), 18), "0");
+        assert_eq!(wei_to_ether(&BigUint::from(1u32), 18), "0.000000000000000001");
+        assert_eq!(wei_to_ether(&BigUint::from(1_000_000_000_000_000_000u64), 18), "1");
+        assert_eq!(wei_to_ether(&BigUint::from(1_500_000_000_000_000_000u64), 18), "1.5");
+        assert_eq!(wei_to_ether(&BigUint::from(123u32), 0), "123");
+    }
+
+    #[test]
+    fn should_format_token_with_6_decimals() {
+        assert_eq!(wei_to_ether(&BigUint::from(1_000_000u32), 6), "1");
+        assert_eq!(wei_to_ether(&BigUint::from(1_234_500u32), 6), "1.2345");
+        assert_eq!(wei_to_ether(&BigUint::from(50u32), 6), "0.00005");
+    }
+
+    #[test]
+    fn should_format_large_wei() {
+        let value = BigUint::parse_bytes(b"115792089237316195423570985008687907853269984665640564039457584007913129639935", 10).unwrap();
+        assert_eq!(
+            wei_to_ether(&value, 18),
+            "115792089237316195423570985008687907853269984665640564039457.584007913129639935"
+        );
+    }
 }